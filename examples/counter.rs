@@ -0,0 +1,73 @@
+//! Elm-style counter example
+//!
+//! This example demonstrates the `Program` architecture, where all state
+//! lives in a model that is only changed by messages.
+
+use vibeui::events::Key;
+use vibeui::{App, Color, Command, Element, Event, Program, Result, Style};
+
+struct Counter;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Increment,
+    Decrement,
+    Reset,
+    Quit,
+}
+
+impl Program for Counter {
+    type Model = i64;
+    type Message = Message;
+
+    fn init(&self) -> (i64, Command<Message>) {
+        (0, Command::none())
+    }
+
+    fn update(&self, count: &mut i64, message: Message) -> Command<Message> {
+        match message {
+            Message::Increment => *count += 1,
+            Message::Decrement => *count -= 1,
+            Message::Reset => *count = 0,
+            Message::Quit => return Command::quit(),
+        }
+        Command::none()
+    }
+
+    fn view(&self, count: &i64) -> Element<Message> {
+        Element::column(vec![
+            Element::text(format!("Count: {}", count))
+                .with_style(Style::default().with_foreground(Color::Cyan).with_bold(true)),
+            Element::space(0, 1),
+            Element::row(vec![
+                Element::button("-").on_press(Message::Decrement),
+                Element::button("+").on_press(Message::Increment),
+                Element::button("Reset").on_press(Message::Reset),
+            ]),
+            Element::space(0, 1),
+            Element::text("Press q to quit"),
+        ])
+        .bordered()
+    }
+
+    fn on_event(&self, _count: &i64, event: &Event) -> Option<Message> {
+        match event {
+            Event::KeyPress { key: Key::Char('+'), .. } => Some(Message::Increment),
+            Event::KeyPress { key: Key::Char('-'), .. } => Some(Message::Decrement),
+            Event::KeyPress { key: Key::Char('q'), .. } | Event::KeyPress { key: Key::Esc, .. } => {
+                Some(Message::Quit)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    vibeui::init()?;
+
+    let mut app = App::new()?;
+    let count = app.run_program(Counter)?;
+
+    println!("Final count: {}", count);
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! Immediate-mode example
+//!
+//! This example draws a small progress screen every frame without building
+//! a component tree.
+
+use std::time::{Duration, Instant};
+use vibeui::components::{Constraint, Layout};
+use vibeui::events::Key;
+use vibeui::platform::Terminal;
+use vibeui::widgets::{Block, Gauge, Label};
+use vibeui::{App, Color, Event, Result, Style};
+
+fn main() -> Result<()> {
+    // Raw mode and the alternate screen keep frames out of the shell
+    vibeui::init()?;
+    Terminal::enter_alternate_screen()?;
+
+    let result = run();
+
+    Terminal::leave_alternate_screen()?;
+    vibeui::platform::cleanup()?;
+    result
+}
+
+/// Draw frames until q is pressed
+fn run() -> Result<()> {
+    let mut app = App::new()?;
+    let start = Instant::now();
+
+    loop {
+        let progress = (start.elapsed().as_secs_f64() / 10.0).min(1.0);
+
+        app.draw(|frame| {
+            let block = Block::bordered().with_title(" Download ");
+            let inner = block.inner(frame.area());
+            frame.render_widget(block, frame.area())?;
+
+            let rows = Layout::vertical(vec![
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .with_margin(1)
+            .with_spacing(1)
+            .split(inner);
+
+            frame.render_widget(
+                Label::new("Fetching packages...").with_style(Style::default().with_bold(true)),
+                rows[0],
+            )?;
+            frame.render_widget(Gauge::new(progress), rows[1])?;
+            frame.render_widget(
+                Label::new("Press q to quit").with_style(Style::default().with_foreground(Color::BrightBlack)),
+                rows[3],
+            )?;
+            Ok(())
+        })?;
+
+        if let Some(Event::KeyPress { key: Key::Char('q'), .. }) = Terminal::read_event()? {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    Ok(())
+}
@@ -0,0 +1,235 @@
+//! Animator driving running animations from the application tick
+
+use crate::animation::{Animation, Easing, Tween};
+use crate::components::Component;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Identifier of a running animation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnimationId(u64);
+
+/// Component property animated by a `ComponentAnimation`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComponentProperty {
+    /// Component position (x, y)
+    Position,
+    /// Component size (width, height)
+    Size,
+}
+
+/// Animation moving or resizing a named component
+#[derive(Debug)]
+pub struct ComponentAnimation {
+    target: String,
+    property: ComponentProperty,
+    tween: Tween<(u16, u16)>,
+}
+
+impl ComponentAnimation {
+    /// Animate the position of a component
+    pub fn position(target: &str, from: (u16, u16), to: (u16, u16), duration: Duration) -> Self {
+        Self {
+            target: target.to_string(),
+            property: ComponentProperty::Position,
+            tween: Tween::new(from, to, duration),
+        }
+    }
+
+    /// Animate the size of a component
+    pub fn size(target: &str, from: (u16, u16), to: (u16, u16), duration: Duration) -> Self {
+        Self {
+            target: target.to_string(),
+            property: ComponentProperty::Size,
+            tween: Tween::new(from, to, duration),
+        }
+    }
+
+    /// Set the easing curve
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.tween = self.tween.with_easing(easing);
+        self
+    }
+
+    /// Set a callback invoked when the animation finishes
+    pub fn on_complete<F: FnMut() + 'static>(mut self, f: F) -> Self {
+        self.tween = self.tween.on_complete(f);
+        self
+    }
+
+    /// Get the name of the animated component
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Get the animated property
+    pub fn property(&self) -> ComponentProperty {
+        self.property
+    }
+
+    /// Apply the current value to a component
+    pub fn apply(&self, component: &mut dyn Component) {
+        let (a, b) = self.tween.value();
+        match self.property {
+            ComponentProperty::Position => component.set_position(a, b),
+            ComponentProperty::Size => component.set_size(a, b),
+        }
+    }
+}
+
+/// Animator that owns and advances running animations
+#[derive(Default)]
+pub struct Animator {
+    next_id: u64,
+    animations: Vec<(AnimationId, Box<dyn Animation>)>,
+    component_animations: Vec<(AnimationId, ComponentAnimation)>,
+    last_tick: Option<Instant>,
+}
+
+impl Animator {
+    /// Create an empty animator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start an animation
+    pub fn start<A: Animation + 'static>(&mut self, animation: A) -> AnimationId {
+        let id = self.next_id();
+        self.animations.push((id, Box::new(animation)));
+        id
+    }
+
+    /// Start an animation of a component property
+    ///
+    /// A running animation of the same property on the same component is
+    /// replaced.
+    pub fn animate_component(&mut self, animation: ComponentAnimation) -> AnimationId {
+        self.component_animations.retain(|(_, running)| {
+            running.target != animation.target || running.property != animation.property
+        });
+        let id = self.next_id();
+        self.component_animations.push((id, animation));
+        id
+    }
+
+    /// Stop an animation without completing it
+    pub fn stop(&mut self, id: AnimationId) -> bool {
+        let count = self.len();
+        self.animations.retain(|(running, _)| *running != id);
+        self.component_animations.retain(|(running, _)| *running != id);
+        self.len() != count
+    }
+
+    /// Stop all animations
+    pub fn clear(&mut self) {
+        self.animations.clear();
+        self.component_animations.clear();
+    }
+
+    /// Get the number of running animations
+    pub fn len(&self) -> usize {
+        self.animations.len() + self.component_animations.len()
+    }
+
+    /// Check if no animation is running
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check if any animation is running
+    pub fn is_running(&self) -> bool {
+        !self.is_empty()
+    }
+
+    /// Advance all animations to `now` and apply component animations
+    ///
+    /// Returns whether any animation was running, meaning a redraw is needed.
+    pub fn tick(&mut self, now: Instant, components: &mut HashMap<String, Box<dyn Component>>) -> bool {
+        if !self.is_running() {
+            self.last_tick = None;
+            return false;
+        }
+
+        let dt = self.last_tick
+            .map(|last| now.saturating_duration_since(last))
+            .unwrap_or(Duration::ZERO);
+        self.last_tick = Some(now);
+        self.advance(dt, components);
+        true
+    }
+
+    /// Advance all animations by `dt` and apply component animations
+    pub fn advance(&mut self, dt: Duration, components: &mut HashMap<String, Box<dyn Component>>) {
+        for (_, animation) in &mut self.animations {
+            animation.advance(dt);
+        }
+        self.animations.retain(|(_, animation)| !animation.is_finished());
+
+        for (_, animation) in &mut self.component_animations {
+            animation.tween.advance(dt);
+            if let Some(component) = components.get_mut(&animation.target) {
+                animation.apply(component.as_mut());
+            }
+        }
+        self.component_animations.retain(|(_, animation)| !animation.tween.is_finished());
+    }
+
+    /// Allocate a new animation ID
+    fn next_id(&mut self) -> AnimationId {
+        self.next_id += 1;
+        AnimationId(self.next_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Label;
+
+    fn ms(value: u64) -> Duration {
+        Duration::from_millis(value)
+    }
+
+    #[test]
+    fn test_animator_lifecycle() {
+        let mut animator = Animator::new();
+        let mut components = HashMap::new();
+        assert!(!animator.tick(Instant::now(), &mut components));
+
+        let id = animator.start(Tween::new(0.0f32, 1.0, ms(100)));
+        assert!(animator.is_running());
+
+        let start = Instant::now();
+        assert!(animator.tick(start, &mut components));
+        assert!(animator.tick(start + ms(100), &mut components));
+        assert!(!animator.is_running());
+        assert!(!animator.stop(id));
+    }
+
+    #[test]
+    fn test_component_animation() {
+        let mut animator = Animator::new();
+        let mut components: HashMap<String, Box<dyn Component>> = HashMap::new();
+        components.insert("toast".to_string(), Box::new(Label::new("Saved")));
+
+        animator.animate_component(ComponentAnimation::position("toast", (0, 10), (0, 0), ms(100)));
+        animator.advance(ms(50), &mut components);
+        assert_eq!(components["toast"].bounds().1, 5);
+
+        animator.advance(ms(50), &mut components);
+        assert_eq!(components["toast"].bounds().1, 0);
+        assert!(!animator.is_running());
+    }
+
+    #[test]
+    fn test_component_animation_replaces_same_property() {
+        let mut animator = Animator::new();
+        animator.animate_component(ComponentAnimation::size("panel", (0, 0), (10, 10), ms(100)));
+        animator.animate_component(ComponentAnimation::position("panel", (0, 0), (5, 5), ms(100)));
+        let id = animator.animate_component(ComponentAnimation::size("panel", (10, 10), (0, 0), ms(100)));
+        assert_eq!(animator.len(), 2);
+
+        assert!(animator.stop(id));
+        assert_eq!(animator.len(), 1);
+    }
+}
@@ -0,0 +1,177 @@
+//! Easing curves for animations
+
+use std::f32::consts::PI;
+
+/// Easing curve mapping linear progress (0.0 to 1.0) to eased progress
+///
+/// Some curves (`Back`, `Elastic`) briefly overshoot the 0.0..1.0 range.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Easing {
+    /// Constant speed
+    #[default]
+    Linear,
+    /// Quadratic acceleration
+    EaseInQuad,
+    /// Quadratic deceleration
+    EaseOutQuad,
+    /// Quadratic acceleration then deceleration
+    EaseInOutQuad,
+    /// Cubic acceleration
+    EaseInCubic,
+    /// Cubic deceleration
+    EaseOutCubic,
+    /// Cubic acceleration then deceleration
+    EaseInOutCubic,
+    /// Sinusoidal acceleration
+    EaseInSine,
+    /// Sinusoidal deceleration
+    EaseOutSine,
+    /// Sinusoidal acceleration then deceleration
+    EaseInOutSine,
+    /// Exponential acceleration
+    EaseInExpo,
+    /// Exponential deceleration
+    EaseOutExpo,
+    /// Pull back before moving forward
+    EaseInBack,
+    /// Overshoot the target before settling
+    EaseOutBack,
+    /// Spring-like oscillation at the end
+    EaseOutElastic,
+    /// Bounce at the end
+    EaseOutBounce,
+    /// Custom easing function
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Apply the easing curve to a progress value
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInQuad => t * t,
+            Easing::EaseOutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Easing::EaseInCubic => t * t * t,
+            Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::EaseInSine => 1.0 - (t * PI / 2.0).cos(),
+            Easing::EaseOutSine => (t * PI / 2.0).sin(),
+            Easing::EaseInOutSine => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::EaseInExpo => {
+                if t == 0.0 {
+                    0.0
+                } else {
+                    2f32.powf(10.0 * t - 10.0)
+                }
+            }
+            Easing::EaseOutExpo => {
+                if t == 1.0 {
+                    1.0
+                } else {
+                    1.0 - 2f32.powf(-10.0 * t)
+                }
+            }
+            Easing::EaseInBack => {
+                let c1 = 1.70158;
+                let c3 = c1 + 1.0;
+                c3 * t * t * t - c1 * t * t
+            }
+            Easing::EaseOutBack => {
+                let c1 = 1.70158;
+                let c3 = c1 + 1.0;
+                1.0 + c3 * (t - 1.0).powi(3) + c1 * (t - 1.0).powi(2)
+            }
+            Easing::EaseOutElastic => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    let c4 = (2.0 * PI) / 3.0;
+                    2f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * c4).sin() + 1.0
+                }
+            }
+            Easing::EaseOutBounce => bounce_out(t),
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+/// Bounce-out curve shared by the bounce easings
+fn bounce_out(t: f32) -> f32 {
+    let n1 = 7.5625;
+    let d1 = 2.75;
+
+    if t < 1.0 / d1 {
+        n1 * t * t
+    } else if t < 2.0 / d1 {
+        let t = t - 1.5 / d1;
+        n1 * t * t + 0.75
+    } else if t < 2.5 / d1 {
+        let t = t - 2.25 / d1;
+        n1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / d1;
+        n1 * t * t + 0.984375
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Easing; 16] = [
+        Easing::Linear,
+        Easing::EaseInQuad,
+        Easing::EaseOutQuad,
+        Easing::EaseInOutQuad,
+        Easing::EaseInCubic,
+        Easing::EaseOutCubic,
+        Easing::EaseInOutCubic,
+        Easing::EaseInSine,
+        Easing::EaseOutSine,
+        Easing::EaseInOutSine,
+        Easing::EaseInExpo,
+        Easing::EaseOutExpo,
+        Easing::EaseInBack,
+        Easing::EaseOutBack,
+        Easing::EaseOutElastic,
+        Easing::EaseOutBounce,
+    ];
+
+    #[test]
+    fn test_easing_endpoints() {
+        for easing in ALL {
+            assert!(easing.apply(0.0).abs() < 1e-3, "{:?} at 0", easing);
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-3, "{:?} at 1", easing);
+        }
+    }
+
+    #[test]
+    fn test_easing_shapes() {
+        assert_eq!(Easing::Linear.apply(0.25), 0.25);
+        assert!(Easing::EaseInQuad.apply(0.5) < 0.5);
+        assert!(Easing::EaseOutQuad.apply(0.5) > 0.5);
+        assert!((Easing::EaseInOutCubic.apply(0.5) - 0.5).abs() < 1e-6);
+        assert!(Easing::EaseInBack.apply(0.2) < 0.0);
+    }
+
+    #[test]
+    fn test_custom_easing() {
+        let step = Easing::Custom(|t| if t < 1.0 { 0.0 } else { 1.0 });
+        assert_eq!(step.apply(0.9), 0.0);
+        assert_eq!(step.apply(1.5), 1.0); // Progress is clamped
+    }
+}
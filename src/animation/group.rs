@@ -0,0 +1,219 @@
+//! Animation groups for chaining and running animations together
+
+use crate::animation::Animation;
+use std::time::Duration;
+
+/// Runs animations one after another
+#[derive(Default)]
+pub struct Sequence {
+    animations: Vec<Box<dyn Animation>>,
+    current: usize,
+    on_complete: Option<Box<dyn FnMut()>>,
+}
+
+impl Sequence {
+    /// Create an empty sequence
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an animation to the sequence
+    pub fn then<A: Animation + 'static>(mut self, animation: A) -> Self {
+        self.animations.push(Box::new(animation));
+        self
+    }
+
+    /// Set a callback invoked when the last animation finishes
+    pub fn on_complete<F: FnMut() + 'static>(mut self, f: F) -> Self {
+        self.on_complete = Some(Box::new(f));
+        self
+    }
+
+    /// Get the number of animations in the sequence
+    pub fn len(&self) -> usize {
+        self.animations.len()
+    }
+
+    /// Check if the sequence is empty
+    pub fn is_empty(&self) -> bool {
+        self.animations.is_empty()
+    }
+}
+
+impl Animation for Sequence {
+    fn advance(&mut self, dt: Duration) -> Duration {
+        if self.is_finished() {
+            return dt;
+        }
+
+        let mut remaining = dt;
+        while let Some(animation) = self.animations.get_mut(self.current) {
+            remaining = animation.advance(remaining);
+            if !animation.is_finished() {
+                return Duration::ZERO;
+            }
+            self.current += 1;
+        }
+
+        if let Some(ref mut callback) = self.on_complete {
+            callback();
+        }
+        remaining
+    }
+
+    fn is_finished(&self) -> bool {
+        self.current >= self.animations.len()
+    }
+
+    fn reset(&mut self) {
+        self.current = 0;
+        for animation in &mut self.animations {
+            animation.reset();
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        self.animations.iter().map(|animation| animation.duration()).sum()
+    }
+}
+
+/// Runs animations at the same time
+#[derive(Default)]
+pub struct Parallel {
+    animations: Vec<Box<dyn Animation>>,
+    finished: bool,
+    on_complete: Option<Box<dyn FnMut()>>,
+}
+
+impl Parallel {
+    /// Create an empty parallel group
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an animation to the group
+    pub fn with<A: Animation + 'static>(mut self, animation: A) -> Self {
+        self.animations.push(Box::new(animation));
+        self
+    }
+
+    /// Set a callback invoked when all animations have finished
+    pub fn on_complete<F: FnMut() + 'static>(mut self, f: F) -> Self {
+        self.on_complete = Some(Box::new(f));
+        self
+    }
+
+    /// Get the number of animations in the group
+    pub fn len(&self) -> usize {
+        self.animations.len()
+    }
+
+    /// Check if the group is empty
+    pub fn is_empty(&self) -> bool {
+        self.animations.is_empty()
+    }
+}
+
+impl Animation for Parallel {
+    fn advance(&mut self, dt: Duration) -> Duration {
+        if self.finished {
+            return dt;
+        }
+
+        // The group finishes with the longest animation, which leaves the
+        // least time over
+        let mut leftover = dt;
+        for animation in &mut self.animations {
+            leftover = leftover.min(animation.advance(dt));
+        }
+
+        if self.animations.iter().all(|animation| animation.is_finished()) {
+            self.finished = true;
+            if let Some(ref mut callback) = self.on_complete {
+                callback();
+            }
+            leftover
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn reset(&mut self) {
+        self.finished = false;
+        for animation in &mut self.animations {
+            animation.reset();
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        self.animations
+            .iter()
+            .map(|animation| animation.duration())
+            .max()
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::Tween;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn ms(value: u64) -> Duration {
+        Duration::from_millis(value)
+    }
+
+    #[test]
+    fn test_sequence_carries_leftover_time() {
+        let value = Rc::new(Cell::new(0.0f32));
+        let (first, second) = (value.clone(), value.clone());
+
+        let mut sequence = Sequence::new()
+            .then(Tween::new(0.0f32, 1.0, ms(100)).on_update(move |v| first.set(*v)))
+            .then(Tween::new(1.0f32, 3.0, ms(100)).on_update(move |v| second.set(*v)));
+        assert_eq!(sequence.duration(), ms(200));
+
+        sequence.advance(ms(150));
+        assert!((value.get() - 2.0).abs() < 1e-4);
+        assert!(!sequence.is_finished());
+
+        assert_eq!(sequence.advance(ms(70)), ms(20));
+        assert!(sequence.is_finished());
+        assert_eq!(value.get(), 3.0);
+    }
+
+    #[test]
+    fn test_parallel_finishes_with_longest() {
+        let done = Rc::new(Cell::new(false));
+        let done_ref = done.clone();
+
+        let mut group = Parallel::new()
+            .with(Tween::new(0u16, 10, ms(50)))
+            .with(Tween::new(0u16, 10, ms(100)))
+            .on_complete(move || done_ref.set(true));
+        assert_eq!(group.duration(), ms(100));
+
+        group.advance(ms(60));
+        assert!(!group.is_finished());
+
+        assert_eq!(group.advance(ms(50)), ms(10));
+        assert!(group.is_finished());
+        assert!(done.get());
+    }
+
+    #[test]
+    fn test_group_reset() {
+        let mut sequence = Sequence::new().then(Tween::new(0u16, 1, ms(10)));
+        sequence.advance(ms(10));
+        assert!(sequence.is_finished());
+
+        sequence.reset();
+        assert!(!sequence.is_finished());
+    }
+}
@@ -0,0 +1,34 @@
+//! Animation module
+//!
+//! This module contains the animation system for VibeUI applications,
+//! including tweens, easing curves and animation groups.
+
+pub mod animator;
+pub mod easing;
+pub mod group;
+pub mod tween;
+
+pub use animator::{AnimationId, Animator, ComponentAnimation, ComponentProperty};
+pub use easing::Easing;
+pub use group::{Parallel, Sequence};
+pub use tween::{Animatable, Tween};
+
+use std::time::Duration;
+
+/// Trait implemented by everything that can be animated over time
+pub trait Animation {
+    /// Advance the animation by `dt`
+    ///
+    /// Returns the part of `dt` left over after the animation finished, so
+    /// chained animations can continue without losing time.
+    fn advance(&mut self, dt: Duration) -> Duration;
+
+    /// Check if the animation has finished
+    fn is_finished(&self) -> bool;
+
+    /// Restart the animation from the beginning
+    fn reset(&mut self);
+
+    /// Get the total duration of the animation
+    fn duration(&self) -> Duration;
+}
@@ -0,0 +1,247 @@
+//! Tweens that interpolate a value between two endpoints
+
+use crate::animation::{Animation, Easing};
+use crate::style::Color;
+use std::fmt;
+use std::time::Duration;
+
+/// Values that can be interpolated by a tween
+pub trait Animatable: Clone {
+    /// Interpolate between `self` and `to` at eased progress `t`
+    ///
+    /// `t` may fall slightly outside 0.0..1.0 for overshooting easings.
+    fn interpolate(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Animatable for f32 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Animatable for f64 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        self + (to - self) * t as f64
+    }
+}
+
+impl Animatable for u8 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        (*self as f32).interpolate(&(*to as f32), t).round().clamp(0.0, u8::MAX as f32) as u8
+    }
+}
+
+impl Animatable for u16 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        (*self as f32).interpolate(&(*to as f32), t).round().clamp(0.0, u16::MAX as f32) as u16
+    }
+}
+
+impl Animatable for i32 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        (*self as f64).interpolate(&(*to as f64), t).round() as i32
+    }
+}
+
+impl Animatable for (u16, u16) {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        (self.0.interpolate(&to.0, t), self.1.interpolate(&to.1, t))
+    }
+}
+
+impl Animatable for Color {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        self.blend(to, t)
+    }
+}
+
+/// Callback receiving the current value of a tween
+type UpdateCallback<T> = Box<dyn FnMut(&T)>;
+
+/// Animation of a single value from a start to an end value
+pub struct Tween<T: Animatable> {
+    from: T,
+    to: T,
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+    finished: bool,
+    on_update: Option<UpdateCallback<T>>,
+    on_complete: Option<Box<dyn FnMut()>>,
+}
+
+impl<T: Animatable> Tween<T> {
+    /// Create a new linear tween
+    pub fn new(from: T, to: T, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            elapsed: Duration::ZERO,
+            easing: Easing::Linear,
+            finished: false,
+            on_update: None,
+            on_complete: None,
+        }
+    }
+
+    /// Set the easing curve
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Set a callback receiving the current value every time the tween advances
+    pub fn on_update<F: FnMut(&T) + 'static>(mut self, f: F) -> Self {
+        self.on_update = Some(Box::new(f));
+        self
+    }
+
+    /// Set a callback invoked when the tween finishes
+    pub fn on_complete<F: FnMut() + 'static>(mut self, f: F) -> Self {
+        self.on_complete = Some(Box::new(f));
+        self
+    }
+
+    /// Get the start value
+    pub fn from(&self) -> &T {
+        &self.from
+    }
+
+    /// Get the end value
+    pub fn to(&self) -> &T {
+        &self.to
+    }
+
+    /// Get the linear progress (0.0 to 1.0)
+    pub fn progress(&self) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    /// Get the current value
+    pub fn value(&self) -> T {
+        if self.progress() >= 1.0 {
+            return self.to.clone();
+        }
+        self.from.interpolate(&self.to, self.easing.apply(self.progress()))
+    }
+}
+
+impl<T: Animatable> Animation for Tween<T> {
+    fn advance(&mut self, dt: Duration) -> Duration {
+        if self.finished {
+            return dt;
+        }
+
+        self.elapsed += dt;
+        let leftover = self.elapsed.saturating_sub(self.duration);
+        if self.elapsed >= self.duration {
+            self.elapsed = self.duration;
+            self.finished = true;
+        }
+
+        if self.on_update.is_some() {
+            let value = self.value();
+            if let Some(ref mut callback) = self.on_update {
+                callback(&value);
+            }
+        }
+        if self.finished {
+            if let Some(ref mut callback) = self.on_complete {
+                callback();
+            }
+        }
+
+        leftover
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.finished = false;
+    }
+
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl<T: Animatable + fmt::Debug> fmt::Debug for Tween<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tween")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("duration", &self.duration)
+            .field("elapsed", &self.elapsed)
+            .field("easing", &self.easing)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_numeric_tween() {
+        let mut tween = Tween::new(0.0f32, 10.0, Duration::from_millis(100));
+        assert_eq!(tween.value(), 0.0);
+
+        assert_eq!(tween.advance(Duration::from_millis(50)), Duration::ZERO);
+        assert!((tween.value() - 5.0).abs() < 1e-4);
+        assert!(!tween.is_finished());
+
+        let leftover = tween.advance(Duration::from_millis(80));
+        assert_eq!(leftover, Duration::from_millis(30));
+        assert!(tween.is_finished());
+        assert_eq!(tween.value(), 10.0);
+    }
+
+    #[test]
+    fn test_position_tween_clamps() {
+        let tween = Tween::new((0u16, 10u16), (20, 0), Duration::from_millis(100))
+            .with_easing(Easing::EaseInBack);
+        let (x, y) = tween.from().interpolate(tween.to(), -0.1);
+        assert_eq!(x, 0); // Overshoot below zero is clamped
+        assert_eq!(y, 11);
+    }
+
+    #[test]
+    fn test_color_tween() {
+        let mut tween = Tween::new(Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0), Duration::from_millis(10));
+        tween.advance(Duration::from_millis(5));
+        assert_eq!(tween.value(), Color::Rgb(100, 50, 0));
+    }
+
+    #[test]
+    fn test_tween_callbacks() {
+        let last = Rc::new(Cell::new(0u16));
+        let done = Rc::new(Cell::new(false));
+        let (last_ref, done_ref) = (last.clone(), done.clone());
+
+        let mut tween = Tween::new(0u16, 4, Duration::from_millis(4))
+            .on_update(move |value| last_ref.set(*value))
+            .on_complete(move || done_ref.set(true));
+
+        tween.advance(Duration::from_millis(1));
+        assert_eq!(last.get(), 1);
+        assert!(!done.get());
+
+        tween.advance(Duration::from_millis(10));
+        assert_eq!(last.get(), 4);
+        assert!(done.get());
+
+        tween.reset();
+        assert!(!tween.is_finished());
+        assert_eq!(tween.value(), 0);
+    }
+}
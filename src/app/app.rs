@@ -1,11 +1,22 @@
 //! Main application structure
 
-use crate::app::{Config, Result};
-use crate::events::{Event, EventHandler};
-use crate::platform::Terminal;
-use crate::render::Renderer;
-use crate::components::Component;
-use std::collections::HashMap;
+use crate::animation::{Animation, AnimationId, Animator, ComponentAnimation};
+use crate::app::{Config, HotReloader, Result, TimerQueue};
+#[cfg(feature = "async")]
+use crate::app::{Runtime, Spawner};
+use crate::events::{Event, EventHandler, Key};
+use crate::platform::{InputReader, Terminal};
+use crate::program::{Program, ProgramState};
+use crate::render::{Frame, Renderer};
+use crate::style::theme::ThemeManager;
+use crate::style::{ColorVision, Stylesheet};
+use crate::components::state::{focus_target, update_pointer_state};
+use crate::components::{Component, ComponentRegistry, Context, InteractionState, LayoutNode, Selector};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 /// Main application structure
 pub struct App {
@@ -14,6 +25,24 @@ pub struct App {
     renderer: Renderer,
     event_handler: EventHandler,
     components: HashMap<String, Box<dyn Component>>,
+    /// Component names in the order they were added, which is drawing order
+    order: Vec<String>,
+    timers: Rc<RefCell<TimerQueue>>,
+    themes: ThemeManager,
+    registry: ComponentRegistry,
+    layouts: HashMap<String, PathBuf>,
+    hot_reload: Option<HotReloader>,
+    animator: Animator,
+    #[cfg(feature = "async")]
+    runtime: Runtime,
+    #[cfg(feature = "async")]
+    input_error: Option<String>,
+    focused: Option<String>,
+    next_tick: Instant,
+    /// Whether to pick the theme from the terminal colors on the next run
+    detect_appearance: bool,
+    theme_changed: bool,
+    needs_redraw: bool,
     running: bool,
 }
 
@@ -27,22 +56,231 @@ impl App {
     /// Create a new application with custom configuration
     pub fn with_config(config: Config) -> Result<Self> {
         let terminal = Terminal::new()?;
-        let renderer = Renderer::new(&terminal);
+        let mut renderer = Renderer::new(&terminal);
+        renderer.set_color_depth(config.color_depth.unwrap_or_else(Terminal::color_depth));
+        renderer.set_ascii_only(config.ascii_only);
+        renderer.set_sanitizer(config.sanitizer);
         let event_handler = EventHandler::new()?;
 
+        // Without a configured appearance, the terminal is asked for its
+        // colors when the application starts running
+        let mut themes = ThemeManager::new();
+        if let Some(appearance) = config.appearance {
+            themes.select_appearance(appearance);
+        }
+        let detect_appearance = config.appearance.is_none();
+
         Ok(Self {
             config,
             terminal,
             renderer,
             event_handler,
             components: HashMap::new(),
+            order: Vec::new(),
+            timers: Rc::new(RefCell::new(TimerQueue::new())),
+            themes,
+            registry: ComponentRegistry::new(),
+            layouts: HashMap::new(),
+            hot_reload: None,
+            animator: Animator::new(),
+            #[cfg(feature = "async")]
+            runtime: Runtime::new(),
+            #[cfg(feature = "async")]
+            input_error: None,
+            focused: None,
+            next_tick: Instant::now(),
+            detect_appearance,
+            theme_changed: true,
+            needs_redraw: true,
             running: false,
         })
     }
 
     /// Add a component to the application
+    ///
+    /// The component is mounted immediately, and a component it replaces is
+    /// unmounted.
     pub fn add_component<C: Component + 'static>(&mut self, name: &str, component: C) {
-        self.components.insert(name.to_string(), Box::new(component));
+        self.add_boxed_component(name, Box::new(component));
+    }
+
+    /// Add a boxed component to the application
+    pub fn add_boxed_component(&mut self, name: &str, mut component: Box<dyn Component>) {
+        let context = self.context();
+        component.on_mount(&context);
+        match self.components.insert(name.to_string(), component) {
+            Some(mut replaced) => replaced.on_unmount(&context),
+            None => self.order.push(name.to_string()),
+        }
+    }
+
+    /// Remove a component from the application, unmounting it
+    pub fn remove_component(&mut self, name: &str) -> Option<Box<dyn Component>> {
+        let mut component = self.components.remove(name)?;
+        self.order.retain(|existing| existing != name);
+        // The focused component may have been part of the removed tree
+        if self.focused.as_deref().is_some_and(|selector| self.find(selector).is_none()) {
+            self.focused = None;
+        }
+        component.on_unmount(&self.context());
+        self.needs_redraw = true;
+        Some(component)
+    }
+
+    /// Load a component tree from a TOML or JSON layout file
+    ///
+    /// The tree is built with the component registry, resolving style names
+    /// against the current theme, and added under the root node's name.
+    /// Returns that name.
+    pub fn load_layout<P: AsRef<Path>>(&mut self, path: P) -> Result<String> {
+        let path = path.as_ref();
+        let (name, component) = self.build_layout(path)?;
+        self.add_boxed_component(&name, component);
+        self.layouts.insert(name.clone(), path.to_path_buf());
+        if let Some(reloader) = &mut self.hot_reload {
+            reloader.watch(path);
+        }
+        self.needs_redraw = true;
+        Ok(name)
+    }
+
+    /// Watch theme and layout files and reload them when they change
+    ///
+    /// Files are polled every `interval`. Themes loaded from files replace
+    /// the theme of the same name, and
+    /// layouts loaded with `load_layout` are rebuilt when their file or a
+    /// theme changes. A file that fails to load keeps its previous version
+    /// and an error banner is shown until it loads again.
+    pub fn enable_hot_reload(&mut self, interval: Duration) {
+        let mut reloader = HotReloader::new(interval);
+        for path in self.themes.themes().filter_map(|theme| theme.source.as_ref()) {
+            reloader.watch(path);
+        }
+        for path in self.layouts.values() {
+            reloader.watch(path);
+        }
+        self.hot_reload = Some(reloader);
+    }
+
+    /// Stop watching theme and layout files
+    pub fn disable_hot_reload(&mut self) {
+        if self.hot_reload.take().is_some() {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Get the error shown for files that failed to reload
+    pub fn reload_error(&self) -> Option<String> {
+        self.hot_reload.as_ref().and_then(HotReloader::error_message)
+    }
+
+    /// Get the message shown in the error banner
+    fn error_message(&self) -> Option<String> {
+        #[cfg(feature = "async")]
+        let input_error = self.input_error.clone();
+        #[cfg(not(feature = "async"))]
+        let input_error = None;
+        self.reload_error().or(input_error)
+    }
+
+    /// Replace the stylesheet, including the built-in component rules
+    pub fn set_stylesheet(&mut self, stylesheet: Stylesheet) {
+        self.renderer.set_stylesheet(stylesheet);
+        self.needs_redraw = true;
+    }
+
+    /// Add the rules of a stylesheet file after the current rules
+    pub fn load_stylesheet<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let stylesheet = Stylesheet::load_from_file(path)?;
+        self.renderer.stylesheet_mut().extend(stylesheet);
+        self.needs_redraw = true;
+        Ok(())
+    }
+
+    /// Get the stylesheet used to style components
+    pub fn stylesheet(&self) -> &Stylesheet {
+        self.renderer.stylesheet()
+    }
+
+    /// Preview the UI as seen with a color vision deficiency
+    ///
+    /// All rendered colors are transformed until this is called with
+    /// `None`.
+    pub fn set_color_vision(&mut self, vision: Option<ColorVision>) {
+        self.renderer.set_color_vision(vision);
+        self.needs_redraw = true;
+    }
+
+    /// Get the simulated color vision deficiency, if any
+    pub fn color_vision(&self) -> Option<ColorVision> {
+        self.renderer.color_vision()
+    }
+
+    /// Get the registry used to build layouts
+    pub fn registry(&self) -> &ComponentRegistry {
+        &self.registry
+    }
+
+    /// Get the registry mutably, to register custom component types
+    pub fn registry_mut(&mut self) -> &mut ComponentRegistry {
+        &mut self.registry
+    }
+
+    /// Get the theme manager
+    pub fn theme_manager(&self) -> &ThemeManager {
+        &self.themes
+    }
+
+    /// Get the theme manager mutably
+    ///
+    /// The renderer picks up the current theme on the next frame, and the
+    /// theme is no longer picked from the terminal colors.
+    pub fn theme_manager_mut(&mut self) -> &mut ThemeManager {
+        self.detect_appearance = false;
+        self.theme_changed = true;
+        self.needs_redraw = true;
+        &mut self.themes
+    }
+
+    /// Get the context passed to lifecycle hooks
+    pub fn context(&self) -> Context {
+        Context::new(self.event_handler.sender(), self.timers.clone())
+    }
+
+    /// Move focus to the component matching a selector
+    ///
+    /// The previously focused component is blurred first. Returns `false`
+    /// and keeps the current focus if nothing matches.
+    pub fn focus(&mut self, selector: &str) -> bool {
+        if self.find(selector).is_none() {
+            return false;
+        }
+        self.blur();
+        if let Some(component) = self.find_mut(selector) {
+            let state = component.state();
+            component.set_state(InteractionState { focused: true, ..state });
+            component.on_focus();
+        }
+        self.focused = Some(selector.to_string());
+        self.needs_redraw = true;
+        true
+    }
+
+    /// Remove focus from the focused component
+    pub fn blur(&mut self) {
+        if let Some(selector) = self.focused.take() {
+            if let Some(component) = self.find_mut(&selector) {
+                let state = component.state();
+                component.set_state(InteractionState { focused: false, ..state });
+                component.on_blur();
+            }
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Get the selector of the focused component
+    pub fn focused(&self) -> Option<&str> {
+        self.focused.as_deref()
     }
 
     /// Get a component by name
@@ -55,56 +293,471 @@ impl App {
         self.components.get_mut(name).map(|c| c.as_mut())
     }
 
+    /// Find a component by selector and downcast it
+    ///
+    /// Selectors are either a path of names starting at a top-level
+    /// component, such as `main/display`, or `#id` to search the whole tree.
+    pub fn query<T: Component + 'static>(&self, selector: &str) -> Option<&T> {
+        self.find(selector)?.downcast_ref()
+    }
+
+    /// Find a component by selector and downcast it mutably
+    pub fn query_mut<T: Component + 'static>(&mut self, selector: &str) -> Option<&mut T> {
+        self.find_mut(selector)?.downcast_mut()
+    }
+
+    /// Find a component by selector
+    pub fn find(&self, selector: &str) -> Option<&dyn Component> {
+        Selector::parse(selector).select(&self.components, &self.order)
+    }
+
+    /// Find a mutable component by selector
+    pub fn find_mut(&mut self, selector: &str) -> Option<&mut dyn Component> {
+        Selector::parse(selector).select_mut(&mut self.components, &self.order)
+    }
+
+    /// Schedule an `Event::Timer` with the given ID to fire once after `delay`
+    pub fn set_timeout(&mut self, id: &str, delay: Duration) {
+        self.timers.borrow_mut().set_timeout(id, delay);
+    }
+
+    /// Schedule an `Event::Timer` with the given ID to fire every `period`
+    pub fn set_interval(&mut self, id: &str, period: Duration) {
+        self.timers.borrow_mut().set_interval(id, period);
+    }
+
+    /// Cancel a timer, returning whether it was scheduled
+    pub fn cancel_timer(&mut self, id: &str) -> bool {
+        self.timers.borrow_mut().cancel(id)
+    }
+
+    /// Start an animation
+    pub fn animate<A: Animation + 'static>(&mut self, animation: A) -> AnimationId {
+        self.animator.start(animation)
+    }
+
+    /// Start an animation of a component's position or size
+    pub fn animate_component(&mut self, animation: ComponentAnimation) -> AnimationId {
+        self.animator.animate_component(animation)
+    }
+
+    /// Stop a running animation
+    pub fn stop_animation(&mut self, id: AnimationId) -> bool {
+        self.animator.stop(id)
+    }
+
+    /// Get a spawner for running futures whose results come back as events
+    ///
+    /// Clone it into components that need to start background work from
+    /// their event handlers.
+    #[cfg(feature = "async")]
+    pub fn spawner(&self) -> Spawner {
+        self.runtime.spawner()
+    }
+
+    /// Spawn a future and deliver its output as an event
+    #[cfg(feature = "async")]
+    pub fn spawn<F>(&self, future: F) -> tokio::task::JoinHandle<()>
+    where
+        F: std::future::Future<Output = Event> + Send + 'static,
+    {
+        self.runtime.spawner().spawn(future)
+    }
+
+    /// Draw a frame in immediate mode, bypassing the component tree
+    pub fn draw<F: FnOnce(&mut Frame) -> crate::render::Result<()>>(&mut self, f: F) -> Result<()> {
+        self.renderer.draw(f)?;
+        Ok(())
+    }
+
+    /// Request a redraw on the next loop iteration
+    pub fn request_redraw(&mut self) {
+        self.needs_redraw = true;
+    }
+
     /// Run the application
     pub fn run(&mut self) -> Result<()> {
         self.running = true;
         self.terminal.initialize()?;
+        self.detect_appearance();
+        let _input = self.read_input();
         
         while self.running {
+            // Queue expired timers and reload changed files
+            self.fire_timers()?;
+            self.poll_hot_reload();
+
             // Handle events
             while let Some(event) = self.event_handler.next_event()? {
                 self.handle_event(event)?;
             }
 
+            // Update components, redrawing when any of them changed
+            self.update()?;
+            if self.components.values().any(|component| component.is_dirty()) {
+                self.needs_redraw = true;
+            }
+
+            // Advance animations, which keep requesting redraws while running
+            if self.animator.tick(Instant::now(), &mut self.components) {
+                self.needs_redraw = true;
+            }
+
             // Render components
-            self.render()?;
+            if self.needs_redraw {
+                self.render()?;
+                self.needs_redraw = false;
+            }
 
-            // Update components
-            self.update()?;
+            // Sleep until the next deadline or input
+            let timeout = self.next_wakeup(Instant::now());
+            if let Some(event) = self.event_handler.wait_event(timeout)? {
+                self.handle_event(event)?;
+            }
+        }
+
+        self.terminal.cleanup()?;
+        Ok(())
+    }
+
+    /// Run the application on the current tokio runtime
+    ///
+    /// Terminal input is read from an async event stream and events produced
+    /// by spawned futures are handled as they arrive, while components are
+    /// updated through `Component::update_async`.
+    #[cfg(feature = "async")]
+    pub async fn run_async(&mut self) -> Result<()> {
+        self.running = true;
+        self.terminal.initialize()?;
+        self.detect_appearance();
+        let mut terminal_events = crate::platform::EventStream::new();
+
+        while self.running {
+            // Queue expired timers and reload changed files
+            self.fire_timers()?;
+            self.poll_hot_reload();
+
+            // Handle queued events and results of spawned futures
+            while let Some(event) = self.event_handler.next_event()? {
+                self.handle_event(event)?;
+            }
+            while let Some(event) = self.runtime.try_next() {
+                self.handle_event(event)?;
+            }
 
-            // Sleep for a bit to prevent high CPU usage
-            std::thread::sleep(std::time::Duration::from_millis(16));
+            // Update components, awaiting async updates
+            for component in self.components.values_mut() {
+                component.update_async().await?;
+            }
+            if self.components.values().any(|component| component.is_dirty()) {
+                self.needs_redraw = true;
+            }
+
+            // Advance animations, which keep requesting redraws while running
+            if self.animator.tick(Instant::now(), &mut self.components) {
+                self.needs_redraw = true;
+            }
+
+            // Render components
+            if self.needs_redraw {
+                self.render()?;
+                self.needs_redraw = false;
+            }
+
+            // Wait for input, a spawned future or the next timer deadline
+            let timeout = self.next_wakeup(Instant::now());
+            tokio::select! {
+                Some(event) = terminal_events.next() => match event {
+                    Ok(event) => {
+                        self.input_error = None;
+                        self.handle_event(event)?;
+                    }
+                    // Show read errors instead of stopping; the stream retries
+                    Err(error) => {
+                        self.input_error = Some(format!("Terminal input failed: {}", error));
+                        self.needs_redraw = true;
+                    }
+                },
+                Some(event) = self.runtime.next() => self.handle_event(event)?,
+                _ = tokio::time::sleep(timeout) => {}
+            }
         }
 
         self.terminal.cleanup()?;
         Ok(())
     }
 
+    /// Run an Elm-style program instead of the application's components
+    ///
+    /// The view is rebuilt from the model after every handled event and
+    /// messages are dispatched until the program returns `Command::Quit`.
+    pub fn run_program<P: Program>(&mut self, program: P) -> Result<P::Model> {
+        let mut state = ProgramState::new(program);
+        self.running = true;
+        self.terminal.initialize()?;
+        self.detect_appearance();
+        let _input = self.read_input();
+
+        while self.running && !state.should_quit() {
+            // Queue expired timers
+            self.fire_timers()?;
+
+            // Handle terminal input and queued events
+            while let Some(event) = self.event_handler.next_event()? {
+                if state.handle_event(&event) {
+                    self.needs_redraw = true;
+                }
+            }
+
+            // Render the view of the current model
+            if self.needs_redraw {
+                self.renderer.clear()?;
+                state.view().render(self.renderer.buffer_mut(), 0, 0)?;
+                self.renderer.present()?;
+                self.needs_redraw = false;
+            }
+
+            // Sleep until the next deadline or input
+            let timeout = self.next_wakeup(Instant::now());
+            if let Some(event) = self.event_handler.wait_event(timeout)? {
+                if state.handle_event(&event) {
+                    self.needs_redraw = true;
+                }
+            }
+        }
+
+        self.running = false;
+        self.terminal.cleanup()?;
+        Ok(state.into_model())
+    }
+
     /// Stop the application
     pub fn stop(&mut self) {
         self.running = false;
     }
 
+    /// Pick the dark or light theme to match the terminal, once
+    ///
+    /// This runs before terminal input is read, so the replies to the color
+    /// queries aren't taken for key presses. Nothing is sent unless stdin
+    /// and stdout are a tty.
+    fn detect_appearance(&mut self) {
+        if !std::mem::take(&mut self.detect_appearance) {
+            return;
+        }
+        if let Some(appearance) = crate::platform::terminal_colors().appearance() {
+            self.themes.select_appearance(appearance);
+            self.theme_changed = true;
+        }
+    }
+
+    /// Read terminal input into the event queue until the reader is dropped
+    ///
+    /// Input then wakes the loop like any other queued event. Read errors
+    /// are retried by the reader.
+    fn read_input(&self) -> InputReader {
+        let sender = self.event_handler.sender();
+        InputReader::spawn(move |result| match result {
+            Ok(event) => sender.send(event).is_ok(),
+            Err(_) => true,
+        })
+    }
+
+    /// Send events for all expired timers through the event queue
+    fn fire_timers(&mut self) -> Result<()> {
+        let expired = self.timers.borrow_mut().poll_expired(Instant::now());
+        for event in expired {
+            self.event_handler.send_event(event)?;
+        }
+        Ok(())
+    }
+
+    /// Build the component tree of a layout file
+    fn build_layout(&self, path: &Path) -> Result<(String, Box<dyn Component>)> {
+        let node = LayoutNode::load_from_file(path)?;
+        let component = self.registry.build(&node, self.themes.current_theme())?;
+        Ok((node.name().to_string(), component))
+    }
+
+    /// Reload theme and layout files that changed since the last poll
+    fn poll_hot_reload(&mut self) {
+        let Some(mut reloader) = self.hot_reload.take() else {
+            return;
+        };
+        if !reloader.due(Instant::now()) {
+            self.hot_reload = Some(reloader);
+            return;
+        }
+
+        let sources: Vec<(String, PathBuf)> = self
+            .themes
+            .themes()
+            .filter_map(|theme| Some((theme.name.clone(), theme.source.clone()?)))
+            .collect();
+        // Themes extending a reloaded theme resolved against its old version,
+        // so they are reloaded after it, breadth first
+        let mut pending: Vec<(String, PathBuf)> = sources
+            .into_iter()
+            .filter(|(_, path)| reloader.changed(path))
+            .collect();
+        let mut visited = HashSet::new();
+        let mut next = 0;
+        let mut theme_changed = false;
+        while let Some((name, path)) = pending.get(next).cloned() {
+            next += 1;
+            match self.themes.load_file(&path) {
+                Ok(theme) => {
+                    // Swap the whole theme so nothing sees a partial update
+                    self.themes.replace_theme(&name, theme);
+                    reloader.clear_error(&path);
+                    theme_changed = true;
+                    self.theme_changed = true;
+                    if visited.insert(name.clone()) {
+                        pending.extend(self.themes.dependents(&name));
+                    }
+                }
+                Err(error) => reloader.set_error(&path, error.to_string()),
+            }
+            self.needs_redraw = true;
+        }
+
+        // Layouts resolve styles when built, so a new theme rebuilds them all
+        let layouts: Vec<(String, PathBuf)> = self
+            .layouts
+            .iter()
+            .map(|(name, path)| (name.clone(), path.clone()))
+            .collect();
+        let mut rebuilt = false;
+        for (name, path) in layouts {
+            if !reloader.changed(&path) && !theme_changed {
+                continue;
+            }
+            match self.build_layout(&path) {
+                Ok((new_name, component)) => {
+                    rebuilt = true;
+                    if new_name != name {
+                        self.layouts.remove(&name);
+                        self.remove_component(&name);
+                    }
+                    self.add_boxed_component(&new_name, component);
+                    self.layouts.insert(new_name, path.clone());
+                    reloader.clear_error(&path);
+                }
+                Err(error) => reloader.set_error(&path, error.to_string()),
+            }
+            self.needs_redraw = true;
+        }
+
+        // Rebuilt components start unfocused, so focus the one matching the
+        // focused selector again, or drop focus if it is gone
+        if rebuilt {
+            if let Some(selector) = self.focused.take() {
+                self.focus(&selector);
+            }
+        }
+        self.hot_reload = Some(reloader);
+    }
+
+    /// Time to wait before the next loop iteration
+    ///
+    /// This is the time until the earliest of the next tick and the next
+    /// timer deadline. Ticks follow the tick rate, or the frame rate while
+    /// animations are running, and keep their deadline when an event wakes
+    /// the loop early.
+    fn next_wakeup(&mut self, now: Instant) -> Duration {
+        let mut tick = self.config.tick_rate;
+        if self.animator.is_running() {
+            tick = tick.min(Duration::from_secs(1) / self.config.frame_rate.max(1));
+        }
+        self.next_tick = if self.next_tick <= now {
+            now + tick
+        } else {
+            self.next_tick.min(now + tick)
+        };
+
+        let deadline = match self.timers.borrow().next_deadline() {
+            Some(timer) => timer.min(self.next_tick),
+            None => self.next_tick,
+        };
+        deadline.saturating_duration_since(now)
+    }
+
     /// Handle an event
+    ///
+    /// A redraw is requested only when the event changed something, so
+    /// timers and mouse moves over nothing don't redraw the screen.
     fn handle_event(&mut self, event: Event) -> Result<()> {
         match event {
             Event::Quit => self.stop(),
             _ => {
-                // Pass event to all components
+                // Fit the buffer to the new size and give top-level
+                // components the whole terminal area
+                if let Event::Resize { width, height } = event {
+                    self.renderer.update_size()?;
+                    let area = crate::render::Rect::new(0, 0, width, height);
+                    for component in self.components.values_mut() {
+                        component.on_resize(area);
+                    }
+                    self.needs_redraw = true;
+                }
+
+                // Track hover and pressed states before components react
+                for component in self.components.values_mut() {
+                    self.needs_redraw |= update_pointer_state(component.as_mut(), &event);
+                }
+
+                self.update_focus(&event);
+
                 for component in self.components.values_mut() {
-                    component.handle_event(&event);
+                    self.needs_redraw |= component.dispatch_event(&event);
                 }
             }
         }
         Ok(())
     }
 
+    /// Move focus to a clicked focusable component, or release it on a
+    /// click elsewhere or Esc
+    fn update_focus(&mut self, event: &Event) {
+        match event {
+            Event::MouseClick { x, y, .. } => {
+                // The component drawn last is on top
+                let target = self.order.iter().rev().find_map(|name| {
+                    let path = focus_target(self.components[name].as_ref(), *x, *y)?;
+                    Some(std::iter::once(name.clone()).chain(path).collect::<Vec<_>>().join("/"))
+                });
+                match target {
+                    // Clicking the focused component keeps its focus
+                    Some(selector) if self.find(&selector).is_some_and(|c| c.state().focused) => {}
+                    Some(selector) => {
+                        self.focus(&selector);
+                    }
+                    None => self.blur(),
+                }
+            }
+            Event::KeyPress { key: Key::Esc, .. } => self.blur(),
+            _ => {}
+        }
+    }
+
     /// Render all components
     fn render(&mut self) -> Result<()> {
         self.renderer.clear()?;
-        
-        for (name, component) in &self.components {
-            self.renderer.render_component(name, component.as_ref())?;
+        // Resolve stylesheet references against the theme active right now
+        if self.theme_changed {
+            self.renderer.set_theme(self.themes.current_theme().cloned());
+            self.theme_changed = false;
+        }
+
+        // Draw in a fixed order, so popups and their shadows stay on top
+        for name in &self.order {
+            self.renderer.render_component(name, self.components[name].as_ref())?;
+        }
+        if let Some(message) = self.error_message() {
+            crate::app::hot_reload::render_banner(&mut self.renderer, &message)?;
+        }
+        for component in self.components.values_mut() {
+            component.clear_dirty();
         }
         
         self.renderer.present()?;
@@ -135,4 +788,335 @@ mod tests {
         let app = App::new();
         assert!(app.is_ok());
     }
+
+    #[test]
+    fn test_appearance_override() {
+        use crate::style::Appearance;
+
+        let app = App::with_config(Config::new().with_appearance(Appearance::Light)).unwrap();
+        assert_eq!(app.theme_manager().current_theme_name(), Some("light"));
+        assert!(!app.detect_appearance);
+
+        // The terminal is only queried once running, unless a theme is picked
+        let mut app = App::new().unwrap();
+        assert!(app.detect_appearance);
+        app.theme_manager_mut();
+        assert!(!app.detect_appearance);
+    }
+
+    #[test]
+    fn test_color_depth_override() {
+        use crate::style::ColorDepth;
+
+        let app = App::with_config(Config::new().with_color_depth(ColorDepth::Ansi16)).unwrap();
+        assert_eq!(app.renderer.color_depth(), ColorDepth::Ansi16);
+
+        let app = App::with_config(Config::new().with_ascii_only(true)).unwrap();
+        assert!(app.renderer.symbols().is_ascii());
+    }
+
+    #[test]
+    fn test_timers() {
+        let mut app = App::new().unwrap();
+        let now = Instant::now();
+        assert_eq!(app.next_wakeup(now), app.config.tick_rate);
+
+        app.set_timeout("soon", Duration::from_millis(1));
+        assert!(app.next_wakeup(now) <= Duration::from_millis(1));
+
+        assert!(app.cancel_timer("soon"));
+        assert!(!app.cancel_timer("soon"));
+
+        app.set_interval("tick", Duration::ZERO);
+        std::thread::sleep(Duration::from_millis(2));
+        app.fire_timers().unwrap();
+        let event = app.event_handler.next_event().unwrap();
+        assert_eq!(event, Some(Event::Timer { id: "tick".to_string() }));
+    }
+
+    #[test]
+    fn test_animations_wake_at_frame_rate() {
+        let mut app = App::with_config(Config::new().with_tick_rate(Duration::from_secs(1))).unwrap();
+        assert_eq!(app.next_wakeup(Instant::now()), Duration::from_secs(1));
+
+        let id = app.animate(crate::animation::Tween::new(0.0f32, 1.0, Duration::from_secs(1)));
+        assert_eq!(app.next_wakeup(Instant::now()), Duration::from_secs(1) / 60);
+
+        assert!(app.stop_animation(id));
+    }
+
+    #[test]
+    fn test_wakeup_keeps_tick_deadline() {
+        let mut app = App::with_config(Config::new().with_tick_rate(Duration::from_secs(1))).unwrap();
+        let now = Instant::now();
+        assert_eq!(app.next_wakeup(now), Duration::from_secs(1));
+
+        // An event waking the loop early doesn't postpone the tick
+        assert_eq!(app.next_wakeup(now + Duration::from_millis(400)), Duration::from_millis(600));
+
+        app.timers.borrow_mut().set_timeout("soon", Duration::from_millis(100));
+        assert!(app.next_wakeup(now + Duration::from_millis(400)) <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_resize_reaches_top_level_components() {
+        use crate::components::BaseComponent;
+        use crate::render::{Rect, Renderer};
+
+        /// Component that records the bounds it is resized to
+        #[derive(crate::components::Component)]
+        struct Probe {
+            #[base]
+            base: BaseComponent,
+            resized: Rc<RefCell<Vec<Rect>>>,
+        }
+
+        impl Component for Probe {
+            fn render(&self, _renderer: &mut Renderer) -> Result<()> {
+                Ok(())
+            }
+            fn handle_event(&mut self, _event: &Event) -> bool {
+                false
+            }
+            fn update(&mut self) -> Result<()> {
+                Ok(())
+            }
+            fn on_resize(&mut self, bounds: Rect) {
+                self.resized.borrow_mut().push(bounds);
+            }
+        }
+
+        let resized = Rc::new(RefCell::new(Vec::new()));
+        let mut app = App::new().unwrap();
+        app.add_component("probe", Probe { base: BaseComponent::new("probe"), resized: resized.clone() });
+
+        app.handle_event(Event::Resize { width: 80, height: 24 }).unwrap();
+        assert_eq!(*resized.borrow(), vec![Rect::new(0, 0, 80, 24)]);
+    }
+
+    #[test]
+    fn test_query() {
+        use crate::components::{Container, Label};
+
+        let mut app = App::new().unwrap();
+        let mut main = Container::with_id("main");
+        main.add_child("display", Label::with_id("display", "0"));
+        app.add_component("main", main);
+
+        app.query_mut::<Label>("main/display").unwrap().set_text("42");
+        assert_eq!(app.query::<Label>("#display").unwrap().text(), "42");
+        assert!(app.query::<Container>("main/display").is_none());
+    }
+
+    #[test]
+    fn test_focus() {
+        use crate::components::TextInput;
+
+        let mut app = App::new().unwrap();
+        app.add_component("name", TextInput::with_id("name"));
+        app.add_component("email", TextInput::with_id("email"));
+
+        assert!(app.focus("name"));
+        assert!(app.query::<TextInput>("name").unwrap().is_focused());
+
+        assert!(app.focus("#email"));
+        assert!(!app.query::<TextInput>("name").unwrap().is_focused());
+        assert_eq!(app.focused(), Some("#email"));
+
+        assert!(!app.focus("missing"));
+        assert_eq!(app.focused(), Some("#email"));
+
+        app.remove_component("email");
+        app.blur();
+        assert!(app.focused().is_none());
+    }
+
+    #[test]
+    fn test_redraw_only_on_change() {
+        use crate::components::{Button, ComponentBase};
+        use crate::events::event::Modifiers;
+
+        let mut button = Button::new("OK");
+        button.set_size(4, 1);
+        let mut app = App::new().unwrap();
+        app.add_component("ok", button);
+        app.needs_redraw = false;
+
+        app.handle_event(Event::Timer { id: "tick".to_string() }).unwrap();
+        app.handle_event(Event::MouseMove { x: 9, y: 9, modifiers: Modifiers::default() }).unwrap();
+        assert!(!app.needs_redraw);
+
+        // Hovering the button changes how it looks
+        app.handle_event(Event::MouseMove { x: 1, y: 0, modifiers: Modifiers::default() }).unwrap();
+        assert!(app.needs_redraw);
+    }
+
+    #[test]
+    fn test_draw_order() {
+        use crate::components::Label;
+
+        let mut app = App::new().unwrap();
+        for name in ["base", "popup", "toast"] {
+            app.add_component(name, Label::new(name));
+        }
+        app.add_component("base", Label::new("replaced"));
+        app.remove_component("popup");
+        assert_eq!(app.order, ["base", "toast"]);
+    }
+
+    #[test]
+    fn test_click_to_focus() {
+        use crate::components::{Container, TextInput};
+        use crate::events::event::{Modifiers, MouseButton};
+        use crate::platform::ClickTracker;
+
+        // Send a press and a release as the terminal reports them
+        let mut clicks = ClickTracker::new();
+        let mut mouse = |app: &mut App, press: (u16, u16), release: (u16, u16)| {
+            let events = [
+                Event::MousePress { button: MouseButton::Left, x: press.0, y: press.1, modifiers: Modifiers::default() },
+                Event::MouseRelease { button: MouseButton::Left, x: release.0, y: release.1, modifiers: Modifiers::default() },
+            ];
+            for event in events.into_iter().flat_map(|event| clicks.track(event)) {
+                app.handle_event(event).unwrap();
+            }
+        };
+        let mut input = TextInput::with_id("name");
+        input.set_position(0, 1);
+        input.set_size(10, 1);
+        let mut form = Container::new();
+        form.set_size(20, 5);
+        form.add_child("name", input);
+        let mut app = App::new().unwrap();
+        app.add_component("form", form);
+
+        // Dragging off the input before releasing is not a click
+        mouse(&mut app, (2, 1), (15, 4));
+        assert!(app.focused().is_none());
+
+        mouse(&mut app, (2, 1), (2, 1));
+        assert_eq!(app.focused(), Some("form/name"));
+        assert!(app.query::<TextInput>("#name").unwrap().is_focused());
+        app.handle_event(Event::KeyPress { key: Key::Char('a'), modifiers: Modifiers::default() }).unwrap();
+        assert_eq!(app.query::<TextInput>("#name").unwrap().text(), "a");
+
+        mouse(&mut app, (15, 4), (15, 4));
+        assert!(app.focused().is_none());
+        assert!(!app.query::<TextInput>("#name").unwrap().is_focused());
+
+        app.focus("#name");
+        app.handle_event(Event::KeyPress { key: Key::Esc, modifiers: Modifiers::default() }).unwrap();
+        assert!(app.focused().is_none());
+    }
+
+    #[test]
+    fn test_load_layout() {
+        use crate::components::Label;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.json");
+        std::fs::write(&path, r#"{
+            "type": "Container",
+            "id": "main",
+            "children": [{"type": "Label", "id": "greeting", "properties": {"text": "Hi"}}]
+        }"#).unwrap();
+
+        let mut app = App::new().unwrap();
+        assert_eq!(app.load_layout(&path).unwrap(), "main");
+        assert_eq!(app.query::<Label>("main/greeting").unwrap().text(), "Hi");
+
+        assert!(app.load_layout(dir.path().join("missing.toml")).is_err());
+    }
+
+    #[test]
+    fn test_hot_reload() {
+        use std::time::SystemTime;
+
+        // Push the modification time forward so the change is seen
+        fn touch(path: &Path) {
+            let file = std::fs::File::options().write(true).open(path).unwrap();
+            file.set_modified(SystemTime::now() + Duration::from_secs(10)).unwrap();
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("theme.json");
+        crate::style::theme::builtin::dark().save_to_file(&path).unwrap();
+
+        let mut app = App::new().unwrap();
+        let theme = crate::style::Theme::load_from_file(&path).unwrap();
+        app.theme_manager_mut().add_theme(theme);
+        app.theme_manager_mut().set_current_theme("dark");
+        app.enable_hot_reload(Duration::ZERO);
+
+        // An invalid file keeps the old theme and shows an error
+        std::fs::write(&path, "{ not json").unwrap();
+        touch(&path);
+        app.poll_hot_reload();
+        assert!(app.reload_error().unwrap().contains("theme.json"));
+        assert!(app.theme_manager().current_theme().is_some());
+
+        // Fixing the file swaps in the new theme and clears the error
+        let fixed = crate::style::theme::builtin::dark().with_author("Reloaded");
+        fixed.save_to_file(&path).unwrap();
+        touch(&path);
+        app.poll_hot_reload();
+        assert!(app.reload_error().is_none());
+        let current = app.theme_manager().current_theme().unwrap();
+        assert_eq!(current.author.as_deref(), Some("Reloaded"));
+    }
+
+    #[test]
+    fn test_hot_reload_updates_dependent_themes() {
+        use crate::style::{Color, Theme};
+        use std::time::SystemTime;
+
+        let dir = tempfile::tempdir().unwrap();
+        let base_path = dir.path().join("base.json");
+        let child_path = dir.path().join("child.json");
+        std::fs::write(&base_path, r#"{"name": "base", "palette": {"primary": "red"}}"#).unwrap();
+        std::fs::write(&child_path, r#"{"name": "child", "extends": "base"}"#).unwrap();
+
+        let mut app = App::new().unwrap();
+        app.theme_manager_mut().add_theme(Theme::load_from_file(&base_path).unwrap());
+        app.theme_manager_mut().load_theme(&child_path).unwrap();
+        app.enable_hot_reload(Duration::ZERO);
+        app.poll_hot_reload();
+
+        std::fs::write(&base_path, r#"{"name": "base", "palette": {"primary": "blue"}}"#).unwrap();
+        let file = std::fs::File::options().write(true).open(&base_path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(10)).unwrap();
+        app.poll_hot_reload();
+
+        let child = app.theme_manager().get_theme("child").unwrap();
+        assert_eq!(child.palette.primary, Color::Blue);
+    }
+
+    #[test]
+    fn test_hot_reload_keeps_focus() {
+        use crate::components::TextInput;
+        use std::time::SystemTime;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.json");
+        let layout = |placeholder: &str| format!(r#"{{
+            "type": "Container",
+            "id": "main",
+            "children": [{{"type": "TextInput", "id": "name", "properties": {{"placeholder": "{}"}}}}]
+        }}"#, placeholder);
+        std::fs::write(&path, layout("Name")).unwrap();
+
+        let mut app = App::new().unwrap();
+        app.load_layout(&path).unwrap();
+        app.enable_hot_reload(Duration::ZERO);
+        app.poll_hot_reload();
+        assert!(app.focus("#name"));
+
+        std::fs::write(&path, layout("Full name")).unwrap();
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(10)).unwrap();
+        app.poll_hot_reload();
+
+        assert_eq!(app.focused(), Some("#name"));
+        assert!(app.query::<TextInput>("#name").unwrap().is_focused());
+    }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 //! Application configuration
 
-use crate::style::Color;
+use crate::render::Sanitizer;
+use crate::style::{Appearance, Color, ColorDepth};
 use std::time::Duration;
 
 /// Application configuration
@@ -26,6 +27,18 @@ pub struct Config {
     
     /// Tick rate for event handling
     pub tick_rate: Duration,
+
+    /// Dark or light theme, detected from the terminal on `App::run` when `None`
+    pub appearance: Option<Appearance>,
+
+    /// Colors output is limited to, detected from the environment when `None`
+    pub color_depth: Option<ColorDepth>,
+
+    /// Draw only ASCII glyphs, for legacy consoles and serial lines
+    pub ascii_only: bool,
+
+    /// How control characters and tabs in drawn text are shown
+    pub sanitizer: Sanitizer,
 }
 
 impl Default for Config {
@@ -38,6 +51,10 @@ impl Default for Config {
             mouse_support: true,
             raw_mode: true,
             tick_rate: Duration::from_millis(16),
+            appearance: None,
+            color_depth: None,
+            ascii_only: false,
+            sanitizer: Sanitizer::default(),
         }
     }
 }
@@ -89,6 +106,33 @@ impl Config {
         self.tick_rate = tick_rate;
         self
     }
+
+    /// Use the dark or light theme instead of detecting it
+    pub fn with_appearance(mut self, appearance: Appearance) -> Self {
+        self.appearance = Some(appearance);
+        self
+    }
+
+    /// Limit output to a color depth instead of detecting it
+    pub fn with_color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = Some(color_depth);
+        self
+    }
+
+    /// Draw only ASCII glyphs instead of the theme's symbols
+    pub fn with_ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+
+    /// Set how control characters and tabs in drawn text are shown
+    ///
+    /// Tab stops are counted from the left edge of the screen, except in a
+    /// `Paragraph`, which expands tabs from the start of each line.
+    pub fn with_sanitizer(mut self, sanitizer: Sanitizer) -> Self {
+        self.sanitizer = sanitizer;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -102,6 +146,8 @@ mod tests {
         assert_eq!(config.frame_rate, 60);
         assert!(config.mouse_support);
         assert!(config.raw_mode);
+        assert_eq!(config.appearance, None);
+        assert!(!config.ascii_only);
     }
 
     #[test]
@@ -109,11 +155,19 @@ mod tests {
         let config = Config::new()
             .with_title("Test App")
             .with_frame_rate(30)
-            .with_mouse_support(false);
+            .with_mouse_support(false)
+            .with_appearance(Appearance::Light)
+            .with_color_depth(ColorDepth::Ansi256)
+            .with_ascii_only(true)
+            .with_sanitizer(Sanitizer::new().with_tab_width(4));
 
         assert_eq!(config.title, "Test App");
         assert_eq!(config.frame_rate, 30);
         assert!(!config.mouse_support);
         assert!(config.raw_mode); // Should remain true
+        assert_eq!(config.appearance, Some(Appearance::Light));
+        assert_eq!(config.color_depth, Some(ColorDepth::Ansi256));
+        assert!(config.ascii_only);
+        assert_eq!(config.sanitizer.tab_width(), 4);
     }
 }
\ No newline at end of file
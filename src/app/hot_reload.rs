@@ -0,0 +1,160 @@
+//! Hot reloading of theme and layout files during development
+
+use crate::render::{Renderer, Result};
+use crate::style::{Color, Style};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Polls watched files for modification
+///
+/// The application reloads a file when `changed` reports it. Files that
+/// fail to load are recorded with `set_error` and shown in a banner until
+/// they load again.
+#[derive(Debug, Clone)]
+pub struct HotReloader {
+    interval: Duration,
+    next_poll: Option<Instant>,
+    modified: HashMap<PathBuf, Option<SystemTime>>,
+    errors: BTreeMap<PathBuf, String>,
+}
+
+impl HotReloader {
+    /// Create a reloader polling every `interval`
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_poll: None,
+            modified: HashMap::new(),
+            errors: BTreeMap::new(),
+        }
+    }
+
+    /// Get the polling interval
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Start watching a file from its current modification time
+    pub fn watch<P: AsRef<Path>>(&mut self, path: P) {
+        let path = path.as_ref();
+        self.modified.insert(path.to_path_buf(), modified_time(path));
+    }
+
+    /// Stop watching a file
+    pub fn unwatch<P: AsRef<Path>>(&mut self, path: P) {
+        self.modified.remove(path.as_ref());
+        self.errors.remove(path.as_ref());
+    }
+
+    /// Check if a file is watched
+    pub fn is_watching<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.modified.contains_key(path.as_ref())
+    }
+
+    /// Check if a file changed since it was last seen
+    ///
+    /// Files that are not watched yet start being watched and report no
+    /// change.
+    pub fn changed<P: AsRef<Path>>(&mut self, path: P) -> bool {
+        let path = path.as_ref();
+        let modified = modified_time(path);
+        match self.modified.insert(path.to_path_buf(), modified) {
+            Some(previous) => previous != modified,
+            None => false,
+        }
+    }
+
+    /// Check if it is time to poll, scheduling the next poll if so
+    pub fn due(&mut self, now: Instant) -> bool {
+        if self.next_poll.is_some_and(|next_poll| now < next_poll) {
+            return false;
+        }
+        self.next_poll = Some(now + self.interval);
+        true
+    }
+
+    /// Record that a file failed to load
+    pub fn set_error<P: AsRef<Path>>(&mut self, path: P, message: String) {
+        self.errors.insert(path.as_ref().to_path_buf(), message);
+    }
+
+    /// Record that a file loaded, returning whether it had an error
+    pub fn clear_error<P: AsRef<Path>>(&mut self, path: P) -> bool {
+        self.errors.remove(path.as_ref()).is_some()
+    }
+
+    /// Get the message shown in the error banner
+    pub fn error_message(&self) -> Option<String> {
+        let (path, message) = self.errors.iter().next()?;
+        let mut banner = format!("Reload failed: {}: {}", path.display(), message);
+        if self.errors.len() > 1 {
+            banner.push_str(&format!(" (+{} more)", self.errors.len() - 1));
+        }
+        Some(banner)
+    }
+}
+
+/// Draw an error banner across the top row
+pub(crate) fn render_banner(renderer: &mut Renderer, message: &str) -> Result<()> {
+    let style = Style::default()
+        .with_foreground(Color::White)
+        .with_background(Color::Red)
+        .with_bold(true);
+    let width = renderer.width();
+    renderer.draw_rect(0, 0, width, 1, &style)?;
+    // Keep the banner on one line
+    let message = message.replace(['\n', '\r'], " ");
+    renderer.draw_text(1, 0, &message, &style, Some(width.saturating_sub(2)))
+}
+
+/// Get a file's modification time, or `None` if it is missing
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("theme.json");
+        let mut reloader = HotReloader::new(Duration::from_millis(100));
+
+        // Unwatched files start being watched, even before they exist
+        assert!(!reloader.changed(&path));
+        assert!(reloader.is_watching(&path));
+
+        std::fs::write(&path, "{}").unwrap();
+        assert!(reloader.changed(&path));
+        assert!(!reloader.changed(&path));
+
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(10)).unwrap();
+        assert!(reloader.changed(&path));
+    }
+
+    #[test]
+    fn test_due() {
+        let now = Instant::now();
+        let mut reloader = HotReloader::new(Duration::from_millis(100));
+        assert!(reloader.due(now));
+        assert!(!reloader.due(now + Duration::from_millis(50)));
+        assert!(reloader.due(now + Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_error_message() {
+        let mut reloader = HotReloader::new(Duration::ZERO);
+        assert!(reloader.error_message().is_none());
+
+        reloader.set_error("b.toml", "bad".to_string());
+        reloader.set_error("a.json", "worse".to_string());
+        assert_eq!(reloader.error_message().unwrap(), "Reload failed: a.json: worse (+1 more)");
+
+        assert!(reloader.clear_error("a.json"));
+        assert_eq!(reloader.error_message().unwrap(), "Reload failed: b.toml: bad");
+    }
+}
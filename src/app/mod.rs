@@ -5,9 +5,17 @@
 
 pub mod app;
 pub mod config;
+pub mod hot_reload;
+pub mod timer;
+#[cfg(feature = "async")]
+pub mod runtime;
 
 pub use app::App;
 pub use config::Config;
+pub use hot_reload::HotReloader;
+pub use timer::TimerQueue;
+#[cfg(feature = "async")]
+pub use runtime::{Runtime, Spawner};
 
 /// Application-wide error type
 #[derive(Debug, thiserror::Error)]
@@ -23,6 +31,12 @@ pub enum Error {
     
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
+
+    #[error("Style error: {0}")]
+    Style(#[from] crate::style::Error),
+
+    #[error("Layout error: {0}")]
+    Layout(#[from] crate::components::LayoutError),
 }
 
 /// Result type used throughout the application
@@ -0,0 +1,122 @@
+//! Async task spawning for applications run with `App::run_async`
+
+use crate::events::Event;
+use std::future::Future;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinHandle;
+
+/// Handle for spawning futures whose results come back as events
+///
+/// A spawner is cheap to clone, so components can keep their own copy and
+/// start background work from `handle_event`. Futures run on the tokio
+/// runtime driving `App::run_async`; spawning outside of a runtime panics.
+#[derive(Debug, Clone)]
+pub struct Spawner {
+    sender: UnboundedSender<Event>,
+}
+
+impl Spawner {
+    /// Spawn a future and deliver its output as an event
+    pub fn spawn<F>(&self, future: F) -> JoinHandle<()>
+    where
+        F: Future<Output = Event> + Send + 'static,
+    {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            let event = future.await;
+            // The application may have exited while the task was running
+            let _ = sender.send(event);
+        })
+    }
+
+    /// Spawn a future and deliver its output as an `Event::Custom`
+    pub fn spawn_custom<F>(&self, event_type: &str, future: F) -> JoinHandle<()>
+    where
+        F: Future<Output = String> + Send + 'static,
+    {
+        let event_type = event_type.to_string();
+        self.spawn(async move {
+            let data = future.await;
+            Event::Custom { event_type, data }
+        })
+    }
+
+    /// Send an event to the application from any task or thread
+    pub fn send(&self, event: Event) -> bool {
+        self.sender.send(event).is_ok()
+    }
+}
+
+/// Queue collecting events produced by spawned futures
+#[derive(Debug)]
+pub struct Runtime {
+    sender: UnboundedSender<Event>,
+    receiver: UnboundedReceiver<Event>,
+}
+
+impl Runtime {
+    /// Create a new runtime queue
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self { sender, receiver }
+    }
+
+    /// Get a spawner delivering events to this queue
+    pub fn spawner(&self) -> Spawner {
+        Spawner {
+            sender: self.sender.clone(),
+        }
+    }
+
+    /// Get the next event without waiting
+    pub fn try_next(&mut self) -> Option<Event> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Wait for the next event
+    pub async fn next(&mut self) -> Option<Event> {
+        self.receiver.recv().await
+    }
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_spawned_future_delivers_event() {
+        let mut runtime = Runtime::new();
+        let spawner = runtime.spawner();
+
+        spawner.spawn_custom("fetched", async {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            "42".to_string()
+        });
+
+        let event = runtime.next().await;
+        assert_eq!(
+            event,
+            Some(Event::Custom {
+                event_type: "fetched".to_string(),
+                data: "42".to_string(),
+            })
+        );
+        assert_eq!(runtime.try_next(), None);
+    }
+
+    #[tokio::test]
+    async fn test_send_from_clone() {
+        let mut runtime = Runtime::new();
+        let spawner = runtime.spawner().clone();
+
+        assert!(spawner.send(Event::Quit));
+        assert_eq!(runtime.try_next(), Some(Event::Quit));
+    }
+}
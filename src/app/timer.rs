@@ -0,0 +1,192 @@
+//! Timer scheduling for the application event loop
+
+use crate::events::Event;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Shortest allowed interval period, so a zero period cannot spin the loop
+const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+/// A single scheduled timer
+#[derive(Debug, Clone, Copy)]
+struct Timer {
+    /// When the timer fires next
+    deadline: Instant,
+    /// Repeat period for interval timers
+    interval: Option<Duration>,
+}
+
+/// Queue of timers driven by a monotonic clock
+///
+/// Expired timers are turned into `Event::Timer` events which the application
+/// delivers through its normal event queue.
+#[derive(Debug, Clone, Default)]
+pub struct TimerQueue {
+    timers: HashMap<String, Timer>,
+}
+
+impl TimerQueue {
+    /// Create an empty timer queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Schedule a one-shot timer, replacing any timer with the same ID
+    pub fn set_timeout(&mut self, id: &str, delay: Duration) {
+        self.schedule(id, Instant::now() + delay, None);
+    }
+
+    /// Schedule a repeating timer, replacing any timer with the same ID
+    pub fn set_interval(&mut self, id: &str, period: Duration) {
+        let period = period.max(MIN_INTERVAL);
+        self.schedule(id, Instant::now() + period, Some(period));
+    }
+
+    /// Cancel a timer, returning whether it was scheduled
+    pub fn cancel(&mut self, id: &str) -> bool {
+        self.timers.remove(id).is_some()
+    }
+
+    /// Cancel all timers
+    pub fn clear(&mut self) {
+        self.timers.clear();
+    }
+
+    /// Check if a timer is scheduled
+    pub fn is_scheduled(&self, id: &str) -> bool {
+        self.timers.contains_key(id)
+    }
+
+    /// Get the number of scheduled timers
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Check if no timers are scheduled
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// Get the earliest deadline of all scheduled timers
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.timers.values().map(|timer| timer.deadline).min()
+    }
+
+    /// Get the time left until the earliest deadline
+    pub fn time_until_next(&self, now: Instant) -> Option<Duration> {
+        self.next_deadline()
+            .map(|deadline| deadline.saturating_duration_since(now))
+    }
+
+    /// Remove expired timers and return their events in deadline order
+    ///
+    /// Interval timers are rescheduled. An interval that fell behind fires
+    /// once and resumes one period after `now` instead of firing repeatedly.
+    pub fn poll_expired(&mut self, now: Instant) -> Vec<Event> {
+        let mut expired: Vec<(Instant, String)> = self.timers
+            .iter()
+            .filter(|(_, timer)| timer.deadline <= now)
+            .map(|(id, timer)| (timer.deadline, id.clone()))
+            .collect();
+        expired.sort();
+
+        for (_, id) in &expired {
+            let timer = self.timers[id];
+            match timer.interval {
+                Some(period) => {
+                    let mut deadline = timer.deadline + period;
+                    if deadline <= now {
+                        deadline = now + period;
+                    }
+                    self.timers.insert(id.clone(), Timer { deadline, interval: Some(period) });
+                }
+                None => {
+                    self.timers.remove(id);
+                }
+            }
+        }
+
+        expired
+            .into_iter()
+            .map(|(_, id)| Event::Timer { id })
+            .collect()
+    }
+
+    /// Insert a timer with an explicit deadline
+    fn schedule(&mut self, id: &str, deadline: Instant, interval: Option<Duration>) {
+        self.timers.insert(id.to_string(), Timer { deadline, interval });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(events: &[Event]) -> Vec<&str> {
+        events
+            .iter()
+            .map(|event| match event {
+                Event::Timer { id } => id.as_str(),
+                _ => panic!("Expected timer event"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_timeout_fires_once() {
+        let now = Instant::now();
+        let mut queue = TimerQueue::new();
+        queue.schedule("once", now + Duration::from_millis(10), None);
+
+        assert!(queue.poll_expired(now).is_empty());
+        assert_eq!(queue.time_until_next(now), Some(Duration::from_millis(10)));
+
+        let events = queue.poll_expired(now + Duration::from_millis(10));
+        assert_eq!(ids(&events), vec!["once"]);
+        assert!(queue.is_empty());
+        assert!(queue.next_deadline().is_none());
+    }
+
+    #[test]
+    fn test_interval_reschedules() {
+        let now = Instant::now();
+        let period = Duration::from_millis(100);
+        let mut queue = TimerQueue::new();
+        queue.schedule("tick", now + period, Some(period));
+
+        let events = queue.poll_expired(now + period);
+        assert_eq!(ids(&events), vec!["tick"]);
+        assert_eq!(queue.next_deadline(), Some(now + period * 2));
+
+        // Falling far behind fires once and resumes from the poll time
+        let late = now + period * 10;
+        let events = queue.poll_expired(late);
+        assert_eq!(events.len(), 1);
+        assert_eq!(queue.next_deadline(), Some(late + period));
+    }
+
+    #[test]
+    fn test_expired_in_deadline_order() {
+        let now = Instant::now();
+        let mut queue = TimerQueue::new();
+        queue.schedule("second", now + Duration::from_millis(20), None);
+        queue.schedule("first", now + Duration::from_millis(10), None);
+        queue.schedule("later", now + Duration::from_millis(50), None);
+
+        let events = queue.poll_expired(now + Duration::from_millis(30));
+        assert_eq!(ids(&events), vec!["first", "second"]);
+        assert!(queue.is_scheduled("later"));
+    }
+
+    #[test]
+    fn test_cancel_and_replace() {
+        let mut queue = TimerQueue::new();
+        queue.set_timeout("job", Duration::from_secs(60));
+        queue.set_interval("job", Duration::from_secs(1));
+        assert_eq!(queue.len(), 1);
+
+        assert!(queue.cancel("job"));
+        assert!(!queue.cancel("job"));
+        assert!(queue.is_empty());
+    }
+}
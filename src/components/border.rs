@@ -0,0 +1,315 @@
+//! Borders drawn around components
+
+use crate::components::layout::Alignment;
+use crate::render::{Rect, RenderBuffer, Result};
+use crate::style::{BorderSet, Style, Symbols};
+use serde::{Deserialize, Serialize};
+
+/// How much a drop shadow darkens the cells underneath
+const SHADOW_AMOUNT: f32 = 0.3;
+
+/// Line style of a border
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BorderType {
+    /// The border set of the active theme
+    #[default]
+    Plain,
+    /// Thin lines with rounded corners
+    Rounded,
+    /// Double lines
+    Double,
+    /// Heavy lines
+    Thick,
+    /// Thin dashed lines
+    Dashed,
+}
+
+impl BorderType {
+    /// Get the characters to draw with
+    ///
+    /// Every type falls back to the active border set when the symbols are
+    /// ASCII-only, so borders stay readable on legacy consoles.
+    pub fn border_set(&self, symbols: &Symbols) -> BorderSet {
+        match self {
+            _ if symbols.is_ascii() => symbols.border,
+            BorderType::Plain => symbols.border,
+            BorderType::Rounded => BorderSet::ROUNDED,
+            BorderType::Double => BorderSet::DOUBLE,
+            BorderType::Thick => BorderSet::THICK,
+            BorderType::Dashed => BorderSet::DASHED,
+        }
+    }
+}
+
+/// Which sides of a border are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sides {
+    /// Top side
+    pub top: bool,
+    /// Right side
+    pub right: bool,
+    /// Bottom side
+    pub bottom: bool,
+    /// Left side
+    pub left: bool,
+}
+
+impl Sides {
+    /// All four sides
+    pub const ALL: Sides = Sides::new(true, true, true, true);
+    /// No sides
+    pub const NONE: Sides = Sides::new(false, false, false, false);
+    /// Top and bottom
+    pub const HORIZONTAL: Sides = Sides::new(true, false, true, false);
+    /// Left and right
+    pub const VERTICAL: Sides = Sides::new(false, true, false, true);
+
+    /// Create a set of sides, clockwise from the top
+    pub const fn new(top: bool, right: bool, bottom: bool, left: bool) -> Self {
+        Self { top, right, bottom, left }
+    }
+}
+
+impl Default for Sides {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// A border with optional title, footer and drop shadow
+///
+/// The title and footer are drawn into the top and bottom lines, between
+/// the corners. The shadow is drawn one cell to the right of and below the
+/// border by darkening what is already there, so popups should be
+/// rendered after the content they cover.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Border {
+    border_type: BorderType,
+    sides: Sides,
+    style: Style,
+    focus_style: Option<Style>,
+    title: Option<(String, Alignment)>,
+    footer: Option<(String, Alignment)>,
+    shadow: bool,
+}
+
+impl Border {
+    /// Create a plain border on all sides
+    pub fn new() -> Self {
+        Self {
+            border_type: BorderType::Plain,
+            sides: Sides::ALL,
+            style: Style::default(),
+            focus_style: None,
+            title: None,
+            footer: None,
+            shadow: false,
+        }
+    }
+
+    /// Set the line style
+    pub fn with_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = border_type;
+        self
+    }
+
+    /// Set which sides are drawn
+    pub fn with_sides(mut self, sides: Sides) -> Self {
+        self.sides = sides;
+        self
+    }
+
+    /// Set the style of the lines, title and footer
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style used while the component has focus
+    pub fn with_focus_style(mut self, style: Style) -> Self {
+        self.focus_style = Some(style);
+        self
+    }
+
+    /// Set the title shown in the top line
+    pub fn with_title<S: Into<String>>(mut self, title: S, alignment: Alignment) -> Self {
+        self.title = Some((title.into(), alignment));
+        self
+    }
+
+    /// Set the footer shown in the bottom line
+    pub fn with_footer<S: Into<String>>(mut self, footer: S, alignment: Alignment) -> Self {
+        self.footer = Some((footer.into(), alignment));
+        self
+    }
+
+    /// Enable or disable the drop shadow
+    pub fn with_shadow(mut self, shadow: bool) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
+    /// Get the line style
+    pub fn border_type(&self) -> BorderType {
+        self.border_type
+    }
+
+    /// Get the sides that are drawn
+    pub fn sides(&self) -> Sides {
+        self.sides
+    }
+
+    /// Get the title and its alignment
+    pub fn title(&self) -> Option<(&str, Alignment)> {
+        self.title.as_ref().map(|(title, alignment)| (title.as_str(), *alignment))
+    }
+
+    /// Get the footer and its alignment
+    pub fn footer(&self) -> Option<(&str, Alignment)> {
+        self.footer.as_ref().map(|(footer, alignment)| (footer.as_str(), *alignment))
+    }
+
+    /// Get the style for a focus state
+    pub fn style(&self, focused: bool) -> &Style {
+        match &self.focus_style {
+            Some(style) if focused => style,
+            _ => &self.style,
+        }
+    }
+
+    /// Get the area inside the drawn sides
+    pub fn inner(&self, area: Rect) -> Rect {
+        let Sides { top, right, bottom, left } = self.sides;
+        area.inset(top as u16, right as u16, bottom as u16, left as u16)
+    }
+
+    /// Draw the border around `area`
+    pub fn draw(&self, buffer: &mut RenderBuffer, area: Rect, focused: bool) -> Result<()> {
+        if area.is_empty() {
+            return Ok(());
+        }
+        if self.shadow {
+            buffer.darken_area(area.right(), area.y + 1, 1, area.height, SHADOW_AMOUNT)?;
+            buffer.darken_area(area.x + 1, area.bottom(), area.width - 1, 1, SHADOW_AMOUNT)?;
+        }
+
+        let style = *self.style(focused);
+        let set = self.border_type.border_set(buffer.symbols());
+        let Sides { top, right, bottom, left } = self.sides;
+        let (x0, y0) = (area.x, area.y);
+        let (x1, y1) = (area.right() - 1, area.bottom() - 1);
+
+        for x in x0..=x1 {
+            if top {
+                buffer.draw_char(x, y0, set.horizontal, &style)?;
+            }
+            if bottom {
+                buffer.draw_char(x, y1, set.horizontal, &style)?;
+            }
+        }
+        for y in y0..=y1 {
+            if left {
+                buffer.draw_char(x0, y, set.vertical, &style)?;
+            }
+            if right {
+                buffer.draw_char(x1, y, set.vertical, &style)?;
+            }
+        }
+
+        let corners = [
+            (top && left, x0, y0, set.top_left),
+            (top && right, x1, y0, set.top_right),
+            (bottom && left, x0, y1, set.bottom_left),
+            (bottom && right, x1, y1, set.bottom_right),
+        ];
+        for (drawn, x, y, ch) in corners {
+            if drawn {
+                buffer.draw_char(x, y, ch, &style)?;
+            }
+        }
+
+        // Labels only go into lines that are drawn
+        if let (true, Some((title, alignment))) = (top, &self.title) {
+            Self::draw_label(buffer, area, y0, title, *alignment, &style)?;
+        }
+        if let (true, Some((footer, alignment))) = (bottom, &self.footer) {
+            Self::draw_label(buffer, area, y1, footer, *alignment, &style)?;
+        }
+        Ok(())
+    }
+
+    /// Draw text into a border line, between the corners
+    fn draw_label(buffer: &mut RenderBuffer, area: Rect, y: u16, text: &str, alignment: Alignment, style: &Style) -> Result<()> {
+        let space = area.width.saturating_sub(2);
+        let text: String = text.chars().take(space as usize).collect();
+        let free = space - text.chars().count() as u16;
+        let offset = match alignment {
+            Alignment::Start | Alignment::Stretch | Alignment::Justify => 0,
+            Alignment::Center => free / 2,
+            Alignment::End => free,
+        };
+        buffer.draw_text(area.x + 1 + offset, y, &text, style)
+    }
+}
+
+impl Default for Border {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Style> for Border {
+    fn from(style: Style) -> Self {
+        Self::new().with_style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn test_border_draw() {
+        let mut buffer = RenderBuffer::new(10, 4);
+        let border = Border::new()
+            .with_type(BorderType::Rounded)
+            .with_title("Hi", Alignment::Center)
+            .with_footer("ok", Alignment::End);
+        border.draw(&mut buffer, Rect::new(0, 0, 10, 4), false).unwrap();
+        assert_eq!(buffer.as_string(), "╭───Hi───╮\n│        │\n│        │\n╰──────ok╯");
+
+        let mut buffer = RenderBuffer::new(6, 3);
+        let border = Border::new().with_sides(Sides::HORIZONTAL).with_title("Long title", Alignment::Start);
+        assert_eq!(border.inner(Rect::new(0, 0, 6, 3)), Rect::new(0, 1, 6, 1));
+        border.draw(&mut buffer, Rect::new(0, 0, 6, 3), false).unwrap();
+        assert_eq!(buffer.as_string(), "─Long─\n      \n──────");
+
+        let mut buffer = RenderBuffer::new(4, 3);
+        buffer.set_symbols(Symbols::ascii());
+        Border::new().with_type(BorderType::Double).draw(&mut buffer, Rect::new(0, 0, 4, 3), false).unwrap();
+        assert_eq!(buffer.as_string(), "+--+\n|  |\n+--+");
+
+        let mut buffer = RenderBuffer::new(4, 2);
+        let border = Border::new().with_sides(Sides::VERTICAL).with_title("T", Alignment::Start).with_footer("F", Alignment::End);
+        border.draw(&mut buffer, Rect::new(0, 0, 4, 2), false).unwrap();
+        assert_eq!(buffer.as_string(), "│  │\n│  │");
+    }
+
+    #[test]
+    fn test_border_focus_and_shadow() {
+        let blur = Style::default().with_foreground(Color::White);
+        let focus = Style::default().with_foreground(Color::Cyan);
+        let border = Border::from(blur).with_focus_style(focus).with_shadow(true);
+        assert_eq!(border.style(false), &blur);
+        assert_eq!(border.style(true), &focus);
+
+        let mut buffer = RenderBuffer::new(5, 4);
+        border.draw(&mut buffer, Rect::new(0, 0, 3, 2), true).unwrap();
+        assert_eq!(buffer.get_cell(0, 0).unwrap().style, focus);
+        let shadowed = |x, y| buffer.get_cell(x, y).unwrap().style.background.is_some();
+        assert!(shadowed(3, 1) && shadowed(3, 2) && shadowed(1, 2) && shadowed(2, 2));
+        assert!(!shadowed(3, 0) && !shadowed(0, 2) && !shadowed(4, 1));
+    }
+}
@@ -1,50 +1,35 @@
 //! Button component for clickable actions
 
 use crate::app::Result;
-use crate::components::{BaseComponent, Component};
+use crate::components::{BaseComponent, Component, ComponentBase};
 use crate::events::Event;
 use crate::render::Renderer;
-use crate::style::{Style, Color};
+use crate::style::Style;
 
 /// Button component that can be clicked
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Component)]
 pub struct Button {
+    #[base]
     base: BaseComponent,
     text: String,
     style: Style,
     hover_style: Style,
     active_style: Style,
-    is_hovered: bool,
-    is_active: bool,
     on_click: Option<Box<dyn Fn(&mut Self)>>,
 }
 
 impl Button {
     /// Create a new button with the given text
+    ///
+    /// The button is styled by the stylesheet until styles are set with
+    /// `with_style` or `with_styles`.
     pub fn new<S: Into<String>>(text: S) -> Self {
-        let default_style = Style::default()
-            .with_foreground(Color::White)
-            .with_background(Color::Blue)
-            .with_bold(true);
-            
-        let hover_style = Style::default()
-            .with_foreground(Color::Black)
-            .with_background(Color::Cyan)
-            .with_bold(true);
-            
-        let active_style = Style::default()
-            .with_foreground(Color::White)
-            .with_background(Color::Red)
-            .with_bold(true);
-
         Self {
             base: BaseComponent::new("button"),
             text: text.into(),
-            style: default_style,
-            hover_style,
-            active_style,
-            is_hovered: false,
-            is_active: false,
+            style: Style::default(),
+            hover_style: Style::default(),
+            active_style: Style::default(),
             on_click: None,
         }
     }
@@ -63,7 +48,11 @@ impl Button {
 
     /// Set the button text
     pub fn set_text<S: Into<String>>(&mut self, text: S) {
-        self.text = text.into();
+        let text = text.into();
+        if self.text != text {
+            self.text = text;
+            self.base.mark_dirty();
+        }
     }
 
     /// Set the click callback
@@ -74,12 +63,24 @@ impl Button {
 
     /// Check if the button is hovered
     pub fn is_hovered(&self) -> bool {
-        self.is_hovered
+        self.base.state().hovered
     }
 
-    /// Check if the button is active
+    /// Check if the button is being pressed
     pub fn is_active(&self) -> bool {
-        self.is_active
+        self.base.state().pressed
+    }
+
+    /// Add a stylesheet class
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.base.add_class(class);
+        self
+    }
+
+    /// Set the normal style, keeping the hover and active styles
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
     }
 
     /// Set custom styles
@@ -90,15 +91,16 @@ impl Button {
         self
     }
 
-    /// Get the current style based on button state
-    fn current_style(&self) -> &Style {
-        if self.is_active {
-            &self.active_style
-        } else if self.is_hovered {
-            &self.hover_style
-        } else {
-            &self.style
+    /// Get the inline style for the current button state
+    fn current_style(&self) -> Style {
+        let mut style = self.style;
+        if self.is_hovered() {
+            style.apply(&self.hover_style);
         }
+        if self.is_active() {
+            style.apply(&self.active_style);
+        }
+        style
     }
 
     /// Handle mouse click
@@ -116,54 +118,28 @@ impl Component for Button {
         }
 
         let (x, y, width, height) = self.bounds();
-        let style = self.current_style();
+        let style = renderer.resolve_style(self, &self.current_style());
         
         // Draw button background
         for row in 0..height {
-            renderer.draw_rect(x, y + row, width, 1, style)?;
-        }
-        
-        // Draw button text (centered)
-        let text_len = self.text.len() as u16;
-        if text_len < width {
-            let text_x = x + (width - text_len) / 2;
-            let text_y = y + height / 2;
-            renderer.draw_text(text_x, text_y, &self.text, style, Some(width))?;
-        } else {
-            // Truncate text if it's too long
-            let truncated = &self.text[..(width - 3).min(text_len) as usize];
-            let text = format!("{}...", truncated);
-            renderer.draw_text(x, y + height / 2, &text, style, Some(width))?;
+            renderer.draw_rect(x, y + row, width, 1, &style)?;
         }
         
+        // Draw button text centered, truncated with the theme's ellipsis
+        let text_len = self.text.chars().count() as u16;
+        let text_x = x + width.saturating_sub(text_len) / 2;
+        renderer.draw_text(text_x, y + height / 2, &self.text, &style, Some(width))?;
+
         Ok(())
     }
 
     fn handle_event(&mut self, event: &Event) -> bool {
-        match event {
-            Event::MouseClick { x, y, .. } => {
-                let (bx, by, bw, bh) = self.bounds();
-                if *x >= bx && *x < bx + bw && *y >= by && *y < by + bh {
-                    self.handle_click();
-                    self.is_active = true;
-                    return true;
-                }
+        if let Event::MouseClick { x, y, .. } = event {
+            let (bx, by, bw, bh) = self.bounds();
+            if *x >= bx && *x < bx + bw && *y >= by && *y < by + bh {
+                self.handle_click();
+                return true;
             }
-            Event::MouseMove { x, y, .. } => {
-                let (bx, by, bw, bh) = self.bounds();
-                let was_hovered = self.is_hovered;
-                self.is_hovered = *x >= bx && *x < bx + bw && *y >= by && *y < by + bh;
-                if was_hovered != self.is_hovered {
-                    return true; // State changed, need to redraw
-                }
-            }
-            Event::MouseRelease { .. } => {
-                if self.is_active {
-                    self.is_active = false;
-                    return true;
-                }
-            }
-            _ => {}
         }
         false
     }
@@ -172,35 +148,6 @@ impl Component for Button {
         // Buttons don't need to update
         Ok(())
     }
-
-    fn bounds(&self) -> (u16, u16, u16, u16) {
-        (
-            self.base.position().0,
-            self.base.position().1,
-            self.base.size().0,
-            self.base.size().1,
-        )
-    }
-
-    fn set_position(&mut self, x: u16, y: u16) {
-        self.base.set_position(x, y);
-    }
-
-    fn set_size(&mut self, width: u16, height: u16) {
-        self.base.set_size(width, height);
-    }
-
-    fn is_visible(&self) -> bool {
-        self.base.is_visible()
-    }
-
-    fn set_visible(&mut self, visible: bool) {
-        self.base.set_visible(visible);
-    }
-
-    fn id(&self) -> &str {
-        self.base.id()
-    }
 }
 
 #[cfg(test)]
@@ -230,4 +177,21 @@ mod tests {
         assert!(!button.is_hovered());
         assert!(!button.is_active());
     }
+
+    #[test]
+    fn test_disabled_button_ignores_clicks() {
+        use crate::events::event::{Modifiers, MouseButton};
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let clicks = Rc::new(Cell::new(0));
+        let counter = clicks.clone();
+        let mut button = Button::new("Test").on_click(move |_| counter.set(counter.get() + 1));
+        let click = Event::MouseClick { button: MouseButton::Left, x: 0, y: 0, modifiers: Modifiers::default() };
+
+        assert!(button.dispatch_event(&click));
+        button.set_disabled(true);
+        assert!(!button.dispatch_event(&click));
+        assert_eq!(clicks.get(), 1);
+    }
 }
\ No newline at end of file
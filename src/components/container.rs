@@ -1,25 +1,27 @@
 //! Container component for grouping other components
 
 use crate::app::Result;
-use crate::components::{BaseComponent, Component};
+use crate::components::{BaseComponent, Border, Component, ComponentBase, Context, InteractionState};
 use crate::events::Event;
-use crate::render::Renderer;
+use crate::render::{Rect, Renderer};
 use crate::style::{Style, Color};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
 /// Container component that can hold other components
 #[derive(Debug, Clone)]
 pub struct Container {
     base: BaseComponent,
     style: Style,
-    border_style: Option<Style>,
-    children: HashMap<String, Box<dyn Component>>,
+    border: Option<Border>,
+    children: Vec<(String, Box<dyn Component>)>,
     layout: LayoutType,
     padding: (u16, u16, u16, u16), // top, right, bottom, left
+    context: Option<Context>,
 }
 
 /// Layout types for container
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum LayoutType {
     /// Stack children vertically
     Vertical,
@@ -37,10 +39,11 @@ impl Container {
         Self {
             base: BaseComponent::new("container"),
             style: Style::default(),
-            border_style: None,
-            children: HashMap::new(),
+            border: None,
+            children: Vec::new(),
             layout: LayoutType::Vertical,
             padding: (1, 1, 1, 1),
+            context: None,
         }
     }
 
@@ -69,52 +72,108 @@ impl Container {
         self
     }
 
-    /// Set border style
-    pub fn with_border(mut self, style: Style) -> Self {
-        self.border_style = Some(style);
+    /// Set background style
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Add a stylesheet class
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.base.add_class(class);
+        self
+    }
+
+    /// Set the border, from a style or a configured `Border`
+    pub fn with_border(mut self, border: impl Into<Border>) -> Self {
+        self.border = Some(border.into());
         self
     }
 
     /// Remove border
     pub fn without_border(mut self) -> Self {
-        self.border_style = None;
+        self.border = None;
         self
     }
 
+    /// Get the border
+    pub fn border(&self) -> Option<&Border> {
+        self.border.as_ref()
+    }
+
     /// Add a child component
+    ///
+    /// Children are laid out in the order they are added. Adding a child
+    /// with an existing name replaces it in place.
+    ///
+    /// If the container is mounted, the child is mounted too and any child
+    /// it replaces is unmounted.
     pub fn add_child<C: Component + 'static>(&mut self, name: &str, component: C) {
-        self.children.insert(name.to_string(), Box::new(component));
+        self.add_boxed_child(name, Box::new(component));
+    }
+
+    /// Add a boxed child component
+    pub fn add_boxed_child(&mut self, name: &str, mut child: Box<dyn Component>) {
+        if let Some(context) = &self.context {
+            child.on_mount(context);
+        }
+        match self.children.iter_mut().find(|(existing, _)| existing == name) {
+            Some((_, slot)) => {
+                let mut replaced = std::mem::replace(slot, child);
+                if let Some(context) = &self.context {
+                    replaced.on_unmount(context);
+                }
+            }
+            None => self.children.push((name.to_string(), child)),
+        }
     }
 
     /// Get a child component by name
     pub fn get_child(&self, name: &str) -> Option<&dyn Component> {
-        self.children.get(name).map(|c| c.as_ref())
+        self.children
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, child)| child.as_ref())
     }
 
     /// Get a mutable child component by name
     pub fn get_child_mut(&mut self, name: &str) -> Option<&mut dyn Component> {
-        self.children.get_mut(name).map(|c| c.as_mut())
+        self.children
+            .iter_mut()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, child)| child.as_mut() as &mut dyn Component)
     }
 
     /// Remove a child component
+    ///
+    /// A mounted child is unmounted before it is returned.
     pub fn remove_child(&mut self, name: &str) -> Option<Box<dyn Component>> {
-        self.children.remove(name)
+        let index = self.children.iter().position(|(existing, _)| existing == name)?;
+        let (_, mut child) = self.children.remove(index);
+        if let Some(context) = &self.context {
+            child.on_unmount(context);
+        }
+        Some(child)
     }
 
     /// Get all child names
     pub fn child_names(&self) -> Vec<&str> {
-        self.children.keys().map(|s| s.as_str()).collect()
+        self.children.iter().map(|(name, _)| name.as_str()).collect()
     }
 
     /// Calculate child positions based on layout
+    ///
+    /// Children are placed inside the border and padding.
     fn arrange_children(&mut self) {
         let (x, y, width, height) = self.bounds();
+        let mut area = Rect::new(x, y, width, height);
+        if let Some(border) = &self.border {
+            area = border.inner(area);
+        }
         let (padding_top, padding_right, padding_bottom, padding_left) = self.padding;
-        
-        let content_x = x + padding_left;
-        let content_y = y + padding_top;
-        let content_width = width.saturating_sub(padding_left + padding_right);
-        let content_height = height.saturating_sub(padding_top + padding_bottom);
+        let content = area.inset(padding_top, padding_right, padding_bottom, padding_left);
+        let (content_x, content_y) = (content.x, content.y);
+        let (content_width, content_height) = (content.width, content.height);
 
         match self.layout {
             LayoutType::Vertical => {
@@ -122,8 +181,7 @@ impl Container {
                 let mut current_y = content_y;
                 
                 for (i, (_, child)) in self.children.iter_mut().enumerate() {
-                    child.set_position(content_x, current_y);
-                    child.set_size(content_width, child_height);
+                    Self::place_child(child.as_mut(), content_x, current_y, content_width, child_height);
                     current_y += child_height;
                 }
             }
@@ -131,20 +189,19 @@ impl Container {
                 let child_width = content_width / self.children.len().max(1) as u16;
                 let mut current_x = content_x;
                 
-                for (_, child) in self.children.values_mut() {
-                    child.set_position(current_x, content_y);
-                    child.set_size(child_width, content_height);
+                for (_, child) in self.children.iter_mut() {
+                    Self::place_child(child.as_mut(), current_x, content_y, child_width, content_height);
                     current_x += child_width;
                 }
             }
             LayoutType::Absolute => {
                 // Children keep their positions
-                for child in self.children.values_mut() {
-                    let (cx, cy, _, _) = child.bounds();
+                for (_, child) in self.children.iter_mut() {
+                    let (cx, cy, cw, ch) = child.bounds();
                     // Ensure children are within container bounds
                     let new_x = content_x + cx.min(content_width);
                     let new_y = content_y + cy.min(content_height);
-                    child.set_position(new_x, new_y);
+                    Self::place_child(child.as_mut(), new_x, new_y, cw, ch);
                 }
             }
             LayoutType::Grid => {
@@ -153,42 +210,96 @@ impl Container {
                 let child_height = content_height / self.children.len().max(1) as u16;
                 let mut current_y = content_y;
                 
-                for (_, child) in self.children.values_mut() {
-                    child.set_position(content_x, current_y);
-                    child.set_size(content_width, child_height);
+                for (_, child) in self.children.iter_mut() {
+                    Self::place_child(child.as_mut(), content_x, current_y, content_width, child_height);
                     current_y += child_height;
                 }
             }
         }
     }
 
+    /// Move and resize a child, notifying it when its bounds change
+    fn place_child(child: &mut dyn Component, x: u16, y: u16, width: u16, height: u16) {
+        let old_bounds = child.bounds();
+        child.set_position(x, y);
+        child.set_size(width, height);
+        if child.bounds() != old_bounds {
+            child.on_resize(Rect::new(x, y, width, height));
+        }
+    }
+
     /// Draw border if present
+    ///
+    /// The border uses its focus style while the container or any of its
+    /// descendants has focus.
     fn draw_border(&self, renderer: &mut Renderer) -> Result<()> {
-        if let Some(ref border_style) = self.border_style {
+        if let Some(border) = &self.border {
             let (x, y, width, height) = self.bounds();
-            
-            // Draw top and bottom borders
-            for i in 0..width {
-                renderer.draw_text(x + i, y, "─", border_style, Some(1))?;
-                renderer.draw_text(x + i, y + height - 1, "─", border_style, Some(1))?;
-            }
-            
-            // Draw left and right borders
-            for i in 0..height {
-                renderer.draw_text(x, y + i, "│", border_style, Some(1))?;
-                renderer.draw_text(x + width - 1, y + i, "│", border_style, Some(1))?;
-            }
-            
-            // Draw corners
-            renderer.draw_text(x, y, "┌", border_style, Some(1))?;
-            renderer.draw_text(x + width - 1, y, "┐", border_style, Some(1))?;
-            renderer.draw_text(x, y + height - 1, "└", border_style, Some(1))?;
-            renderer.draw_text(x + width - 1, y + height - 1, "┘", border_style, Some(1))?;
+            border.draw(renderer.buffer_mut(), Rect::new(x, y, width, height), contains_focus(self))?;
         }
         Ok(())
     }
 }
 
+/// Check whether a component or any of its descendants has focus
+fn contains_focus(component: &dyn Component) -> bool {
+    component.state().focused || component.children().into_iter().any(contains_focus)
+}
+
+impl ComponentBase for Container {
+    fn bounds(&self) -> (u16, u16, u16, u16) {
+        (
+            self.base.position().0,
+            self.base.position().1,
+            self.base.size().0,
+            self.base.size().1,
+        )
+    }
+
+    fn set_position(&mut self, x: u16, y: u16) {
+        self.base.set_position(x, y);
+    }
+
+    fn set_size(&mut self, width: u16, height: u16) {
+        self.base.set_size(width, height);
+    }
+
+    fn is_visible(&self) -> bool {
+        self.base.is_visible()
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.base.set_visible(visible);
+    }
+
+    fn id(&self) -> &str {
+        self.base.id()
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.base.is_dirty() || self.children.iter().any(|(_, child)| child.is_dirty())
+    }
+
+    fn clear_dirty(&mut self) {
+        self.base.clear_dirty();
+        for (_, child) in self.children.iter_mut() {
+            child.clear_dirty();
+        }
+    }
+
+    fn classes(&self) -> &[String] {
+        self.base.classes()
+    }
+
+    fn state(&self) -> InteractionState {
+        self.base.state()
+    }
+
+    fn set_state(&mut self, state: InteractionState) {
+        self.base.set_state(state);
+    }
+}
+
 impl Component for Container {
     fn render(&self, renderer: &mut Renderer) -> Result<()> {
         if !self.base.is_visible() {
@@ -196,10 +307,11 @@ impl Component for Container {
         }
 
         let (x, y, width, height) = self.bounds();
+        let style = renderer.resolve_style(self, &self.style);
         
         // Draw container background
         for row in 0..height {
-            renderer.draw_rect(x, y + row, width, 1, &self.style)?;
+            renderer.draw_rect(x, y + row, width, 1, &style)?;
         }
         
         // Draw border if present
@@ -207,21 +319,22 @@ impl Component for Container {
         
         // Render children (note: we need a mutable reference to rearrange,
         // but render takes &self, so arrangement should happen in update)
-        for child in self.children.values() {
-            if child.is_visible() {
-                child.render(renderer)?;
-            }
-        }
+        renderer.push_inherited(style);
+        let result = self.children
+            .iter()
+            .filter(|(_, child)| child.is_visible())
+            .try_for_each(|(_, child)| child.render(renderer));
+        renderer.pop_inherited();
         
-        Ok(())
+        result
     }
 
     fn handle_event(&mut self, event: &Event) -> bool {
         let mut handled = false;
         
         // Pass events to children in reverse order (top to bottom)
-        for child in self.children.values_mut().rev() {
-            if child.handle_event(event) {
+        for (_, child) in self.children.iter_mut().rev() {
+            if child.dispatch_event(event) {
                 handled = true;
                 break; // Only one component should handle the event
             }
@@ -235,40 +348,48 @@ impl Component for Container {
         self.arrange_children();
         
         // Update all children
-        for child in self.children.values_mut() {
+        for (_, child) in self.children.iter_mut() {
             child.update()?;
         }
         
         Ok(())
     }
 
-    fn bounds(&self) -> (u16, u16, u16, u16) {
-        (
-            self.base.position().0,
-            self.base.position().1,
-            self.base.size().0,
-            self.base.size().1,
-        )
+    fn on_mount(&mut self, context: &Context) {
+        for (_, child) in self.children.iter_mut() {
+            child.on_mount(context);
+        }
+        self.context = Some(context.clone());
     }
 
-    fn set_position(&mut self, x: u16, y: u16) {
-        self.base.set_position(x, y);
+    fn on_unmount(&mut self, context: &Context) {
+        self.context = None;
+        for (_, child) in self.children.iter_mut() {
+            child.on_unmount(context);
+        }
     }
 
-    fn set_size(&mut self, width: u16, height: u16) {
-        self.base.set_size(width, height);
+    fn child(&self, name: &str) -> Option<&dyn Component> {
+        self.get_child(name)
     }
 
-    fn is_visible(&self) -> bool {
-        self.base.is_visible()
+    fn child_mut(&mut self, name: &str) -> Option<&mut dyn Component> {
+        self.get_child_mut(name)
     }
 
-    fn set_visible(&mut self, visible: bool) {
-        self.base.set_visible(visible);
+    fn child_names(&self) -> Vec<&str> {
+        self.children.iter().map(|(name, _)| name.as_str()).collect()
     }
 
-    fn id(&self) -> &str {
-        self.base.id()
+    fn children(&self) -> Vec<&dyn Component> {
+        self.children.iter().map(|(_, child)| child.as_ref()).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Component> {
+        self.children
+            .iter_mut()
+            .map(|(_, child)| child.as_mut() as &mut dyn Component)
+            .collect()
     }
 }
 
@@ -302,6 +423,102 @@ mod tests {
         assert!(container.get_child("nonexistent").is_none());
     }
 
+    #[test]
+    fn test_container_border_focus() {
+        use crate::components::{BorderType, TextInput};
+
+        let mut container = Container::new().with_border(Border::new().with_type(BorderType::Rounded));
+        assert_eq!(container.border().unwrap().border_type(), BorderType::Rounded);
+        assert!(!contains_focus(&container));
+
+        let mut input = TextInput::new();
+        input.set_focused(true);
+        container.add_child("input", input);
+        assert!(contains_focus(&container));
+
+        let container = Container::new().with_border(Style::default()).without_border();
+        assert!(container.border().is_none());
+    }
+
+    #[test]
+    fn test_children_inside_border() {
+        use crate::components::{Label, Sides};
+
+        let mut container = Container::new().with_uniform_padding(0).with_border(Style::default());
+        container.add_child("label", Label::new("Hi"));
+        container.set_size(10, 4);
+        container.update().unwrap();
+        assert_eq!(container.get_child("label").unwrap().bounds(), (1, 1, 8, 2));
+
+        let border = Border::new().with_sides(Sides::HORIZONTAL);
+        let mut container = Container::new().with_uniform_padding(1).with_border(border);
+        container.add_child("label", Label::new("Hi"));
+        container.set_size(10, 5);
+        container.update().unwrap();
+        assert_eq!(container.get_child("label").unwrap().bounds(), (1, 2, 8, 1));
+    }
+
+    #[test]
+    fn test_container_lifecycle() {
+        use crate::app::TimerQueue;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        /// Component that records the hooks it receives
+        #[derive(Component)]
+        struct Probe {
+            #[base]
+            base: BaseComponent,
+            log: Rc<RefCell<Vec<String>>>,
+        }
+
+        impl Component for Probe {
+            fn render(&self, _renderer: &mut Renderer) -> Result<()> {
+                Ok(())
+            }
+            fn handle_event(&mut self, _event: &Event) -> bool {
+                false
+            }
+            fn update(&mut self) -> Result<()> {
+                Ok(())
+            }
+            fn on_mount(&mut self, _context: &Context) {
+                self.log.borrow_mut().push(format!("mount {}", self.id()));
+            }
+            fn on_unmount(&mut self, _context: &Context) {
+                self.log.borrow_mut().push(format!("unmount {}", self.id()));
+            }
+            fn on_resize(&mut self, bounds: Rect) {
+                self.log.borrow_mut().push(format!("resize {}x{}", bounds.width, bounds.height));
+            }
+        }
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let probe = |id: &str| Probe { base: BaseComponent::new(id), log: log.clone() };
+        let (sender, _receiver) = std::sync::mpsc::channel();
+        let context = Context::new(sender, Rc::new(RefCell::new(TimerQueue::new())));
+
+        let mut container = Container::new().with_uniform_padding(0);
+        container.add_child("first", probe("first"));
+        assert!(log.borrow().is_empty());
+
+        container.on_mount(&context);
+        container.add_child("second", probe("second"));
+        assert_eq!(*log.borrow(), vec!["mount first", "mount second"]);
+        log.borrow_mut().clear();
+
+        container.set_size(10, 4);
+        container.update().unwrap();
+        assert_eq!(*log.borrow(), vec!["resize 10x2"; 2]);
+        log.borrow_mut().clear();
+
+        container.update().unwrap();
+        assert!(log.borrow().is_empty());
+
+        container.remove_child("first");
+        assert_eq!(*log.borrow(), vec!["unmount first"]);
+    }
+
     #[test]
     fn test_container_styles() {
         let container = Container::new()
@@ -0,0 +1,75 @@
+//! Context passed to component lifecycle hooks
+
+use crate::app::TimerQueue;
+use crate::events::Event;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+/// Access to the application from `Component::on_mount` and `on_unmount`
+///
+/// Containers keep the context they were mounted with, so children added
+/// later are mounted with the same one.
+#[derive(Debug, Clone)]
+pub struct Context {
+    sender: Sender<Event>,
+    timers: Rc<RefCell<TimerQueue>>,
+}
+
+impl Context {
+    /// Create a context from the application's event sender and timers
+    pub fn new(sender: Sender<Event>, timers: Rc<RefCell<TimerQueue>>) -> Self {
+        Self { sender, timers }
+    }
+
+    /// Send an event through the application's event queue
+    ///
+    /// Returns `false` if the application has shut down.
+    pub fn send(&self, event: Event) -> bool {
+        self.sender.send(event).is_ok()
+    }
+
+    /// Get a sender that can be moved to other threads
+    pub fn sender(&self) -> Sender<Event> {
+        self.sender.clone()
+    }
+
+    /// Schedule an `Event::Timer` with the given ID to fire once after `delay`
+    pub fn set_timeout(&self, id: &str, delay: Duration) {
+        self.timers.borrow_mut().set_timeout(id, delay);
+    }
+
+    /// Schedule an `Event::Timer` with the given ID to fire every `period`
+    pub fn set_interval(&self, id: &str, period: Duration) {
+        self.timers.borrow_mut().set_interval(id, period);
+    }
+
+    /// Cancel a timer, returning whether it was scheduled
+    pub fn cancel_timer(&self, id: &str) -> bool {
+        self.timers.borrow_mut().cancel(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_context() {
+        let (sender, receiver) = mpsc::channel();
+        let timers = Rc::new(RefCell::new(TimerQueue::new()));
+        let context = Context::new(sender, timers.clone());
+
+        assert!(context.send(Event::Quit));
+        assert_eq!(receiver.try_recv(), Ok(Event::Quit));
+
+        context.set_interval("poll", Duration::from_secs(1));
+        assert!(timers.borrow().is_scheduled("poll"));
+        assert!(context.cancel_timer("poll"));
+
+        drop(receiver);
+        assert!(!context.send(Event::Quit));
+    }
+}
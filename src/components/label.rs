@@ -1,17 +1,22 @@
 //! Label component for displaying text
 
 use crate::app::Result;
-use crate::components::{BaseComponent, Component};
+use crate::components::{BaseComponent, Component, ComponentBase};
 use crate::events::Event;
+use crate::reactive::Binding;
 use crate::render::Renderer;
 use crate::style::{Style, Color};
 
 /// Simple text label component
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Component)]
 pub struct Label {
+    #[base]
     base: BaseComponent,
     text: String,
     style: Style,
+    text_binding: Option<Binding<String>>,
+    style_binding: Option<Binding<Style>>,
+    visible_binding: Option<Binding<bool>>,
 }
 
 impl Label {
@@ -21,6 +26,9 @@ impl Label {
             base: BaseComponent::new("label"),
             text: text.into(),
             style: Style::default(),
+            text_binding: None,
+            style_binding: None,
+            visible_binding: None,
         }
     }
 
@@ -38,7 +46,17 @@ impl Label {
 
     /// Set the label text
     pub fn set_text<S: Into<String>>(&mut self, text: S) {
-        self.text = text.into();
+        let text = text.into();
+        if self.text != text {
+            self.text = text;
+            self.base.mark_dirty();
+        }
+    }
+
+    /// Add a stylesheet class
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.base.add_class(class);
+        self
     }
 
     /// Get the label style
@@ -48,7 +66,10 @@ impl Label {
 
     /// Set the label style
     pub fn set_style(&mut self, style: Style) {
-        self.style = style;
+        if self.style != style {
+            self.style = style;
+            self.base.mark_dirty();
+        }
     }
 
     /// Set text color
@@ -65,15 +86,49 @@ impl Label {
 
     /// Set bold text
     pub fn with_bold(mut self, bold: bool) -> Self {
-        self.style.bold = bold;
+        self.style.bold = Some(bold);
         self
     }
 
     /// Set italic text
     pub fn with_italic(mut self, italic: bool) -> Self {
-        self.style.italic = italic;
+        self.style.italic = Some(italic);
+        self
+    }
+
+    /// Bind the text to a signal or computed value
+    pub fn bind_text<B: Into<Binding<String>>>(mut self, binding: B) -> Self {
+        self.text_binding = Some(binding.into());
+        self.sync_bindings();
+        self
+    }
+
+    /// Bind the style to a signal or computed value
+    pub fn bind_style<B: Into<Binding<Style>>>(mut self, binding: B) -> Self {
+        self.style_binding = Some(binding.into());
+        self.sync_bindings();
+        self
+    }
+
+    /// Bind the visibility to a signal or computed value
+    pub fn bind_visible<B: Into<Binding<bool>>>(mut self, binding: B) -> Self {
+        self.visible_binding = Some(binding.into());
+        self.sync_bindings();
         self
     }
+
+    /// Apply bound values that changed since the last sync
+    fn sync_bindings(&mut self) {
+        if let Some(text) = self.text_binding.as_ref().and_then(|binding| binding.changed()) {
+            self.set_text(text);
+        }
+        if let Some(style) = self.style_binding.as_ref().and_then(|binding| binding.changed()) {
+            self.set_style(style);
+        }
+        if let Some(visible) = self.visible_binding.as_ref().and_then(|binding| binding.changed()) {
+            self.base.set_visible(visible);
+        }
+    }
 }
 
 impl Component for Label {
@@ -83,7 +138,8 @@ impl Component for Label {
         }
 
         let (x, y, width, height) = self.bounds();
-        renderer.draw_text(x, y, &self.text, &self.style, Some(width))?;
+        let style = renderer.resolve_style(self, &self.style);
+        renderer.draw_text(x, y, &self.text, &style, Some(width))?;
         
         Ok(())
     }
@@ -94,38 +150,9 @@ impl Component for Label {
     }
 
     fn update(&mut self) -> Result<()> {
-        // Labels don't need to update
+        self.sync_bindings();
         Ok(())
     }
-
-    fn bounds(&self) -> (u16, u16, u16, u16) {
-        (
-            self.base.position().0,
-            self.base.position().1,
-            self.base.size().0,
-            self.base.size().1,
-        )
-    }
-
-    fn set_position(&mut self, x: u16, y: u16) {
-        self.base.set_position(x, y);
-    }
-
-    fn set_size(&mut self, width: u16, height: u16) {
-        self.base.set_size(width, height);
-    }
-
-    fn is_visible(&self) -> bool {
-        self.base.is_visible()
-    }
-
-    fn set_visible(&mut self, visible: bool) {
-        self.base.set_visible(visible);
-    }
-
-    fn id(&self) -> &str {
-        self.base.id()
-    }
 }
 
 #[cfg(test)]
@@ -153,7 +180,7 @@ mod tests {
             .with_bold(true);
         
         assert_eq!(label.style().foreground, Some(Color::Red));
-        assert!(label.style().bold);
+        assert_eq!(label.style().bold, Some(true));
     }
 
     #[test]
@@ -165,4 +192,29 @@ mod tests {
         assert_eq!(label.text(), "Updated");
         assert!(!label.is_visible());
     }
+
+    #[test]
+    fn test_label_bindings() {
+        use crate::reactive::{Computed, Signal};
+
+        let count = Signal::new(0);
+        let count_ref = count.clone();
+        let visible = Signal::new(true);
+        let mut label = Label::new("")
+            .bind_text(Computed::new(move || format!("Count: {}", count_ref.get())))
+            .bind_visible(&visible);
+        assert_eq!(label.text(), "Count: 0");
+        assert!(label.is_visible());
+
+        label.clear_dirty();
+        label.update().unwrap();
+        assert!(!label.is_dirty());
+
+        count.set(3);
+        visible.set(false);
+        label.update().unwrap();
+        assert_eq!(label.text(), "Count: 3");
+        assert!(!label.is_visible());
+        assert!(label.is_dirty());
+    }
 }
\ No newline at end of file
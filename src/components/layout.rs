@@ -1,7 +1,9 @@
 //! Layout utilities for arranging components
 
 use crate::components::Component;
+use crate::render::Rect;
 use crate::style::{Style, Color};
+use serde::Deserialize;
 
 /// Layout constraints for components
 #[derive(Debug, Clone, Copy)]
@@ -85,16 +87,24 @@ impl Constraints {
 }
 
 /// Alignment options for layout
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// Layout files write these in lowercase, with `left` and `right` accepted
+/// for `start` and `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Alignment {
     /// Align to start (left/top)
+    #[serde(alias = "left")]
     Start,
     /// Align to center
     Center,
     /// Align to end (right/bottom)
+    #[serde(alias = "right")]
     End,
     /// Stretch to fill available space
     Stretch,
+    /// Spread words to both edges, except on the last row of a paragraph
+    Justify,
 }
 
 /// Layout margins
@@ -173,6 +183,159 @@ impl Margins {
     }
 }
 
+/// Size constraint of a single area in a `Layout`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Constraint {
+    /// Fixed number of cells
+    Length(u16),
+    /// Percentage of the available space
+    Percentage(u16),
+    /// Fraction of the available space
+    Ratio(u32, u32),
+    /// At least this many cells, growing to take unused space
+    Min(u16),
+    /// At most this many cells
+    Max(u16),
+    /// Share of the unused space, proportional to the weight
+    Fill(u16),
+}
+
+/// Layout splitting a `Rect` into areas along one direction
+#[derive(Debug, Clone)]
+pub struct Layout {
+    direction: flex::Direction,
+    constraints: Vec<Constraint>,
+    margin: u16,
+    spacing: u16,
+}
+
+impl Layout {
+    /// Create a layout stacking areas from top to bottom
+    pub fn vertical(constraints: Vec<Constraint>) -> Self {
+        Self::new(flex::Direction::Column, constraints)
+    }
+
+    /// Create a layout placing areas from left to right
+    pub fn horizontal(constraints: Vec<Constraint>) -> Self {
+        Self::new(flex::Direction::Row, constraints)
+    }
+
+    /// Create a layout with the given direction
+    pub fn new(direction: flex::Direction, constraints: Vec<Constraint>) -> Self {
+        Self {
+            direction,
+            constraints,
+            margin: 0,
+            spacing: 0,
+        }
+    }
+
+    /// Set the margin around all areas
+    pub fn with_margin(mut self, margin: u16) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set the space between areas
+    pub fn with_spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Split an area according to the constraints
+    ///
+    /// Areas that do not fit are shrunk starting from the last one.
+    pub fn split(&self, area: Rect) -> Vec<Rect> {
+        let area = area.inner(self.margin);
+        let count = self.constraints.len() as u16;
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let total = match self.direction {
+            flex::Direction::Row => area.width,
+            flex::Direction::Column => area.height,
+        };
+        let available = total.saturating_sub(self.spacing.saturating_mul(count - 1));
+        let sizes = Self::resolve(&self.constraints, available);
+
+        let mut offset = 0u16;
+        sizes
+            .into_iter()
+            .map(|size| {
+                let rect = match self.direction {
+                    flex::Direction::Row => Rect::new(area.x + offset, area.y, size, area.height),
+                    flex::Direction::Column => Rect::new(area.x, area.y + offset, area.width, size),
+                };
+                offset = offset.saturating_add(size).saturating_add(self.spacing).min(total);
+                rect
+            })
+            .collect()
+    }
+
+    /// Resolve constraints into sizes that fit into `available` cells
+    fn resolve(constraints: &[Constraint], available: u16) -> Vec<u16> {
+        let mut sizes: Vec<u16> = constraints
+            .iter()
+            .map(|constraint| match *constraint {
+                Constraint::Length(length) => length,
+                Constraint::Percentage(percent) => (available as u32 * percent.min(100) as u32 / 100) as u16,
+                Constraint::Ratio(_, 0) => 0,
+                Constraint::Ratio(num, den) => (available as u64 * num.min(den) as u64 / den as u64) as u16,
+                Constraint::Min(min) => min,
+                Constraint::Max(max) => max.min(available),
+                Constraint::Fill(_) => 0,
+            })
+            .collect();
+
+        // Shrink from the end until everything fits
+        let mut used: u32 = sizes.iter().map(|&size| size as u32).sum();
+        for size in sizes.iter_mut().rev() {
+            if used <= available as u32 {
+                break;
+            }
+            let cut = (*size as u32).min(used - available as u32);
+            *size -= cut as u16;
+            used -= cut;
+        }
+
+        // Hand unused space to fill areas, or to minimum-sized areas
+        let mut remaining = available - used as u16;
+        let fill: Vec<(usize, u16)> = constraints
+            .iter()
+            .enumerate()
+            .filter_map(|(index, constraint)| match constraint {
+                Constraint::Fill(weight) => Some((index, *weight)),
+                _ => None,
+            })
+            .collect();
+        let growable: Vec<(usize, u16)> = if fill.is_empty() {
+            constraints
+                .iter()
+                .enumerate()
+                .filter(|(_, constraint)| matches!(constraint, Constraint::Min(_)))
+                .map(|(index, _)| (index, 1))
+                .collect()
+        } else {
+            fill
+        };
+
+        let total_weight: u32 = growable.iter().map(|&(_, weight)| weight as u32).sum();
+        let share = remaining as u32;
+        for &(index, weight) in &growable {
+            let extra = (share * weight as u32).checked_div(total_weight).unwrap_or(0) as u16;
+            sizes[index] += extra;
+            remaining -= extra;
+        }
+        // Rounding leftovers go to the last growable area
+        if let Some(&(index, _)) = growable.iter().rev().find(|&&(_, weight)| weight > 0) {
+            sizes[index] += remaining;
+        }
+
+        sizes
+    }
+}
+
 /// Flex layout utilities
 pub mod flex {
     use super::*;
@@ -448,6 +611,37 @@ mod tests {
         assert_eq!(positions[2], (40, 2, 10, 5)); // Last item at end
     }
 
+    #[test]
+    fn test_layout_split() {
+        let area = Rect::new(0, 0, 20, 10);
+        let rows = Layout::vertical(vec![
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(2),
+        ])
+        .split(area);
+        assert_eq!(rows, vec![
+            Rect::new(0, 0, 20, 1),
+            Rect::new(0, 1, 20, 7),
+            Rect::new(0, 8, 20, 2),
+        ]);
+
+        let columns = Layout::horizontal(vec![Constraint::Percentage(25), Constraint::Fill(1), Constraint::Fill(2)])
+            .with_spacing(1)
+            .split(area);
+        assert_eq!(columns[0], Rect::new(0, 0, 4, 10));
+        assert_eq!(columns[1], Rect::new(5, 0, 4, 10));
+        assert_eq!(columns[2], Rect::new(10, 0, 10, 10));
+    }
+
+    #[test]
+    fn test_layout_overflow() {
+        let areas = Layout::vertical(vec![Constraint::Length(4), Constraint::Length(4)])
+            .with_margin(1)
+            .split(Rect::new(0, 0, 10, 7));
+        assert_eq!(areas, vec![Rect::new(1, 1, 8, 4), Rect::new(1, 5, 8, 1)]);
+    }
+
     #[test]
     fn test_grid_config() {
         let grid = GridConfig::new(2, 3).with_gap(1);
@@ -0,0 +1,543 @@
+//! Declarative layouts loaded from TOML or JSON
+//!
+//! A layout file describes a component tree. Every node names a registered
+//! component type and may set an id, a theme style, a container layout,
+//! type-specific properties and children:
+//!
+//! ```toml
+//! type = "Container"
+//! id = "main"
+//! layout = "vertical"
+//! properties = { padding = 1, border = "border" }
+//!
+//! [[children]]
+//! type = "Label"
+//! id = "title"
+//! style = "title"
+//! properties = { text = "Hello" }
+//! ```
+
+use crate::components::layout::Alignment;
+use crate::components::{Border, BorderType, Button, Component, Container, Label, LayoutType, Paragraph, TextInput};
+use crate::style::{Color, Style, Theme};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+/// Error produced while loading a layout
+#[derive(Debug, thiserror::Error)]
+pub enum LayoutError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON layout error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("TOML layout error: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    #[error("Unknown component type `{0}`")]
+    UnknownType(String),
+
+    #[error("Unknown style `{style}` on `{node}`")]
+    UnknownStyle { node: String, style: String },
+
+    #[error("Invalid property `{property}` on `{node}`: {message}")]
+    InvalidProperty {
+        node: String,
+        property: String,
+        message: String,
+    },
+
+    #[error("Duplicate child name `{name}` in `{node}`")]
+    DuplicateName { node: String, name: String },
+}
+
+/// Node in a declarative layout
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayoutNode {
+    /// Registered component type, such as `Label` or `Container`
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Component id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Name under the parent, defaulting to the id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Name of a style in the theme
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    /// Layout of a container's children
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<LayoutType>,
+    /// Type-specific properties
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub properties: Map<String, Value>,
+    /// Child nodes
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<LayoutNode>,
+}
+
+impl LayoutNode {
+    /// Create a node of a component type
+    pub fn new(kind: &str) -> Self {
+        Self {
+            kind: kind.to_string(),
+            id: None,
+            name: None,
+            style: None,
+            layout: None,
+            properties: Map::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Load a layout from a JSON string
+    pub fn from_json(json: &str) -> Result<Self, LayoutError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Load a layout from a TOML string
+    pub fn from_toml(toml: &str) -> Result<Self, LayoutError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Load a layout from a file
+    ///
+    /// Files ending in `.toml` are read as TOML, anything else as JSON.
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, LayoutError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml(&content),
+            _ => Self::from_json(&content),
+        }
+    }
+
+    /// Get the component id, falling back to the name and then the type
+    pub fn id(&self) -> &str {
+        self.id.as_deref().or(self.name.as_deref()).unwrap_or(&self.kind)
+    }
+
+    /// Get the name under the parent, falling back to the id
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or_else(|| self.id())
+    }
+
+    /// Get a property converted to `T`
+    pub fn property<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, LayoutError> {
+        self.properties
+            .get(key)
+            .map(|value| {
+                serde_json::from_value(value.clone())
+                    .map_err(|error| self.invalid_property(key, error))
+            })
+            .transpose()
+    }
+
+    /// Get a color property written as a name or `#rrggbb`
+    pub fn color(&self, key: &str) -> Result<Option<Color>, LayoutError> {
+        self.property::<String>(key)?
+            .map(|color| Color::parse(&color).map_err(|error| self.invalid_property(key, error)))
+            .transpose()
+    }
+
+    /// Create an error for an invalid property of this node
+    pub fn invalid_property<E: fmt::Display>(&self, key: &str, error: E) -> LayoutError {
+        LayoutError::InvalidProperty {
+            node: self.id().to_string(),
+            property: key.to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Constructor for a registered component type
+pub type ComponentFactory =
+    Box<dyn Fn(&LayoutNode, &LayoutBuilder) -> Result<Box<dyn Component>, LayoutError>>;
+
+/// Registry mapping type names in layouts to component constructors
+///
+/// `Label`, `Paragraph`, `Button`, `TextInput` and `Container` are
+/// registered by default.
+pub struct ComponentRegistry {
+    factories: HashMap<String, ComponentFactory>,
+}
+
+impl ComponentRegistry {
+    /// Create a registry with the built-in components
+    pub fn new() -> Self {
+        let mut registry = Self { factories: HashMap::new() };
+        registry.register("Label", build_label);
+        registry.register("Paragraph", build_paragraph);
+        registry.register("Button", build_button);
+        registry.register("TextInput", build_text_input);
+        registry.register("Container", build_container);
+        registry
+    }
+
+    /// Register a constructor for a type name, replacing any existing one
+    pub fn register<F>(&mut self, kind: &str, factory: F)
+    where
+        F: Fn(&LayoutNode, &LayoutBuilder) -> Result<Box<dyn Component>, LayoutError> + 'static,
+    {
+        self.factories.insert(kind.to_string(), Box::new(factory));
+    }
+
+    /// Check if a type name is registered
+    pub fn contains(&self, kind: &str) -> bool {
+        self.factories.contains_key(kind)
+    }
+
+    /// Get all registered type names
+    pub fn types(&self) -> Vec<&str> {
+        self.factories.keys().map(|kind| kind.as_str()).collect()
+    }
+
+    /// Build a component tree, resolving style names against a theme
+    pub fn build(&self, node: &LayoutNode, theme: Option<&Theme>) -> Result<Box<dyn Component>, LayoutError> {
+        LayoutBuilder { registry: self, theme }.build(node)
+    }
+}
+
+impl Default for ComponentRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ComponentRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComponentRegistry")
+            .field("types", &self.types())
+            .finish()
+    }
+}
+
+/// Access to the registry and theme from component constructors
+pub struct LayoutBuilder<'a> {
+    registry: &'a ComponentRegistry,
+    theme: Option<&'a Theme>,
+}
+
+impl<'a> LayoutBuilder<'a> {
+    /// Build a node with its registered constructor
+    pub fn build(&self, node: &LayoutNode) -> Result<Box<dyn Component>, LayoutError> {
+        let factory = self
+            .registry
+            .factories
+            .get(&node.kind)
+            .ok_or_else(|| LayoutError::UnknownType(node.kind.clone()))?;
+        factory(node, self)
+    }
+
+    /// Get the theme used to resolve style names
+    pub fn theme(&self) -> Option<&'a Theme> {
+        self.theme
+    }
+
+    /// Look up a style by name in the theme
+    pub fn resolve_style(&self, node: &LayoutNode, name: &str) -> Result<Style, LayoutError> {
+        self.theme
+            .and_then(|theme| theme.get_style(name))
+            .copied()
+            .ok_or_else(|| LayoutError::UnknownStyle {
+                node: node.id().to_string(),
+                style: name.to_string(),
+            })
+    }
+
+    /// Get the node's theme style, if it names one
+    pub fn style(&self, node: &LayoutNode) -> Result<Option<Style>, LayoutError> {
+        node.style
+            .as_deref()
+            .map(|name| self.resolve_style(node, name))
+            .transpose()
+    }
+}
+
+/// Container padding, either uniform or `[top, right, bottom, left]`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Padding {
+    Uniform(u16),
+    Sides([u16; 4]),
+}
+
+/// Build a `Label` from `text`, `color`, `background`, `bold` and `italic`
+fn build_label(node: &LayoutNode, builder: &LayoutBuilder) -> Result<Box<dyn Component>, LayoutError> {
+    let text = node.property::<String>("text")?.unwrap_or_default();
+    let mut label = Label::with_id(node.id().to_string(), text);
+    if let Some(style) = builder.style(node)? {
+        label.set_style(style);
+    }
+    if let Some(color) = node.color("color")? {
+        label = label.with_color(color);
+    }
+    if let Some(color) = node.color("background")? {
+        label = label.with_background(color);
+    }
+    if let Some(bold) = node.property("bold")? {
+        label = label.with_bold(bold);
+    }
+    if let Some(italic) = node.property("italic")? {
+        label = label.with_italic(italic);
+    }
+    Ok(Box::new(label))
+}
+
+/// Build a `Paragraph` from `text`, `align` and `wrap`
+fn build_paragraph(node: &LayoutNode, builder: &LayoutBuilder) -> Result<Box<dyn Component>, LayoutError> {
+    let text = node.property::<String>("text")?.unwrap_or_default();
+    let mut paragraph = Paragraph::with_id(node.id().to_string(), text);
+    if let Some(style) = builder.style(node)? {
+        paragraph = paragraph.with_style(style);
+    }
+    if let Some(alignment) = node.property("align")? {
+        paragraph = paragraph.with_alignment(alignment);
+    }
+    if let Some(wrap) = node.property("wrap")? {
+        paragraph = paragraph.with_wrap(wrap);
+    }
+    Ok(Box::new(paragraph))
+}
+
+/// Build a `Button` from `text`
+fn build_button(node: &LayoutNode, builder: &LayoutBuilder) -> Result<Box<dyn Component>, LayoutError> {
+    let text = node.property::<String>("text")?.unwrap_or_default();
+    let mut button = Button::with_id(node.id().to_string(), text);
+    if let Some(style) = builder.style(node)? {
+        button = button.with_style(style);
+    }
+    Ok(Box::new(button))
+}
+
+/// Build a `TextInput` from `text`, `placeholder`, `max_length` and `password`
+fn build_text_input(node: &LayoutNode, builder: &LayoutBuilder) -> Result<Box<dyn Component>, LayoutError> {
+    let mut input = TextInput::with_id(node.id());
+    if let Some(style) = builder.style(node)? {
+        input = input.with_style(style);
+    }
+    if let Some(max_length) = node.property("max_length")? {
+        input = input.with_max_length(max_length);
+    }
+    if let Some(password) = node.property("password")? {
+        input = input.with_password(password);
+    }
+    if let Some(placeholder) = node.property::<String>("placeholder")? {
+        input.set_placeholder(placeholder);
+    }
+    if let Some(text) = node.property::<String>("text")? {
+        input.set_text(text);
+    }
+    Ok(Box::new(input))
+}
+
+/// Build a `Container` from `padding`, `border` and its children
+///
+/// `border` is either a style name or `true` for the default border. A
+/// border can be refined with `border_type`, `focus_border` (a style name),
+/// `title` and `footer` with their `title_align` and `footer_align`, and
+/// `shadow`.
+fn build_container(node: &LayoutNode, builder: &LayoutBuilder) -> Result<Box<dyn Component>, LayoutError> {
+    let mut container = Container::with_id(node.id());
+    if let Some(layout) = node.layout {
+        container = container.with_layout(layout);
+    }
+    if let Some(style) = builder.style(node)? {
+        container = container.with_style(style);
+    }
+    match node.property::<Padding>("padding")? {
+        Some(Padding::Uniform(padding)) => container = container.with_uniform_padding(padding),
+        Some(Padding::Sides([top, right, bottom, left])) => {
+            container = container.with_padding(top, right, bottom, left)
+        }
+        None => {}
+    }
+    let border = match node.properties.get("border") {
+        Some(Value::Bool(true)) => Some(Border::new()),
+        Some(Value::Bool(false)) | None => None,
+        Some(Value::String(name)) => Some(Border::from(builder.resolve_style(node, name)?)),
+        Some(_) => return Err(node.invalid_property("border", "expected a style name or a boolean")),
+    };
+    if let Some(mut border) = border {
+        if let Some(border_type) = node.property::<BorderType>("border_type")? {
+            border = border.with_type(border_type);
+        }
+        if let Some(name) = node.property::<String>("focus_border")? {
+            border = border.with_focus_style(builder.resolve_style(node, &name)?);
+        }
+        if let Some(title) = node.property::<String>("title")? {
+            let alignment = node.property("title_align")?.unwrap_or(Alignment::Start);
+            border = border.with_title(title, alignment);
+        }
+        if let Some(footer) = node.property::<String>("footer")? {
+            let alignment = node.property("footer_align")?.unwrap_or(Alignment::End);
+            border = border.with_footer(footer, alignment);
+        }
+        if let Some(shadow) = node.property("shadow")? {
+            border = border.with_shadow(shadow);
+        }
+        container = container.with_border(border);
+    }
+    for (name, child) in child_names(node)?.iter().zip(&node.children) {
+        container.add_boxed_child(name, builder.build(child)?);
+    }
+    Ok(Box::new(container))
+}
+
+/// Get the names of a node's children under it
+///
+/// Children without a name or id are named after their type, numbered from
+/// the second one on, as in `Label#2`. Other names must be unique.
+fn child_names(node: &LayoutNode) -> Result<Vec<String>, LayoutError> {
+    let implicit = |child: &LayoutNode| child.name.is_none() && child.id.is_none();
+    let mut taken = HashSet::new();
+    for child in node.children.iter().filter(|child| !implicit(child)) {
+        if !taken.insert(child.name().to_string()) {
+            return Err(LayoutError::DuplicateName {
+                node: node.id().to_string(),
+                name: child.name().to_string(),
+            });
+        }
+    }
+
+    let mut names = Vec::with_capacity(node.children.len());
+    for child in &node.children {
+        let mut name = child.name().to_string();
+        if implicit(child) {
+            let mut number = 1;
+            while taken.contains(&name) {
+                number += 1;
+                name = format!("{}#{}", child.kind, number);
+            }
+            taken.insert(name.clone());
+        }
+        names.push(name);
+    }
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::ComponentBase;
+
+    const LAYOUT: &str = r##"
+        type = "Container"
+        id = "main"
+        layout = "horizontal"
+        properties = { padding = [0, 1, 0, 1], border = "border", border_type = "rounded", title = "Main", title_align = "center", footer = "v1" }
+
+        [[children]]
+        type = "Label"
+        id = "title"
+        style = "title"
+        properties = { text = "Hello", color = "#ff0000" }
+
+        [[children]]
+        type = "TextInput"
+        name = "name"
+        properties = { placeholder = "Your name", max_length = 20 }
+
+        [[children]]
+        type = "Paragraph"
+        id = "about"
+        properties = { text = "Wrapped and justified text", align = "justify" }
+    "##;
+
+    fn theme() -> Theme {
+        Theme::new("test")
+            .add_style("title", Style::default().with_bold(true))
+            .add_style("border", Style::default().with_foreground(Color::Cyan))
+    }
+
+    #[test]
+    fn test_build_from_toml() {
+        let node = LayoutNode::from_toml(LAYOUT).unwrap();
+        assert_eq!(node.layout, Some(LayoutType::Horizontal));
+        assert_eq!(node.children[1].name(), "name");
+
+        let theme = theme();
+        let root = ComponentRegistry::new().build(&node, Some(&theme)).unwrap();
+        assert_eq!(root.id(), "main");
+        let border = root.downcast_ref::<Container>().unwrap().border().unwrap();
+        assert_eq!(border.border_type(), BorderType::Rounded);
+        assert_eq!(border.style(false).foreground, Some(Color::Cyan));
+        assert_eq!(border.title(), Some(("Main", Alignment::Center)));
+        assert_eq!(border.footer(), Some(("v1", Alignment::End)));
+
+        let title = root.query::<Label>("title").unwrap();
+        assert_eq!(title.text(), "Hello");
+        assert_eq!(title.style().bold, Some(true));
+        assert_eq!(title.style().foreground, Some(Color::Rgb(255, 0, 0)));
+
+        let input = root.query::<TextInput>("#name").unwrap();
+        assert_eq!(input.placeholder(), "Your name");
+
+        let about = root.query::<Paragraph>("about").unwrap();
+        let rows: Vec<_> = about.layout(12).into_iter().map(|(_, line)| line.to_string()).collect();
+        assert_eq!(rows, vec!["Wrapped  and", "justified", "text"]);
+
+        // JSON describes the same tree
+        let json = serde_json::to_string(&node).unwrap();
+        assert_eq!(LayoutNode::from_json(&json).unwrap(), node);
+    }
+
+    #[test]
+    fn test_build_errors() {
+        let registry = ComponentRegistry::new();
+
+        let node = LayoutNode::new("Slider");
+        assert!(matches!(registry.build(&node, None), Err(LayoutError::UnknownType(kind)) if kind == "Slider"));
+
+        let node = LayoutNode::from_toml(LAYOUT).unwrap();
+        assert!(matches!(registry.build(&node, None), Err(LayoutError::UnknownStyle { .. })));
+
+        let node = LayoutNode::from_json(r#"{"type": "Label", "properties": {"bold": "yes"}}"#).unwrap();
+        let error = registry.build(&node, None).unwrap_err();
+        assert!(error.to_string().starts_with("Invalid property `bold` on `Label`"));
+    }
+
+    #[test]
+    fn test_child_names() {
+        let registry = ComponentRegistry::new();
+        let node = LayoutNode::from_json(
+            r#"{"type": "Container", "children": [
+                {"type": "Label", "properties": {"text": "a"}},
+                {"type": "Label", "properties": {"text": "b"}},
+                {"type": "Label", "name": "Label#2", "properties": {"text": "c"}}
+            ]}"#,
+        )
+        .unwrap();
+        let root = registry.build(&node, None).unwrap();
+        let container = root.downcast_ref::<Container>().unwrap();
+        assert_eq!(container.child_names(), vec!["Label", "Label#3", "Label#2"]);
+        assert_eq!(root.query::<Label>("Label#3").unwrap().text(), "b");
+
+        let node = LayoutNode::from_json(
+            r#"{"type": "Container", "id": "form", "children": [{"type": "Label", "id": "x"}, {"type": "Button", "name": "x"}]}"#,
+        )
+        .unwrap();
+        let error = registry.build(&node, None).unwrap_err();
+        assert_eq!(error.to_string(), "Duplicate child name `x` in `form`");
+    }
+
+    #[test]
+    fn test_register_custom_type() {
+        let mut registry = ComponentRegistry::new();
+        registry.register("Heading", |node, _builder| {
+            let text = node.property::<String>("text")?.unwrap_or_default();
+            Ok(Box::new(Label::with_id(node.id().to_string(), text.to_uppercase()).with_bold(true)))
+        });
+        assert!(registry.contains("Heading"));
+
+        let node = LayoutNode::from_json(r#"{"type": "Heading", "id": "h", "properties": {"text": "intro"}}"#).unwrap();
+        let heading = registry.build(&node, None).unwrap();
+        assert_eq!(heading.downcast_ref::<Label>().unwrap().text(), "INTRO");
+    }
+}
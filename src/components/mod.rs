@@ -3,29 +3,65 @@
 //! This module contains all UI components that can be used to build
 //! terminal user interfaces.
 
+pub mod border;
 pub mod button;
 pub mod label;
 pub mod text_input;
 pub mod container;
+pub mod context;
 pub mod layout;
+pub mod loader;
+pub mod paragraph;
+pub mod query;
+pub mod state;
+pub mod view;
 
+pub use border::{Border, BorderType, Sides};
 pub use button::Button;
 pub use label::Label;
 pub use text_input::TextInput;
-pub use container::Container;
-pub use layout::Layout;
+pub use container::{Container, LayoutType};
+pub use context::Context;
+pub use layout::{Constraint, Layout};
+pub use loader::{ComponentRegistry, LayoutError, LayoutNode};
+pub use paragraph::Paragraph;
+pub use query::Selector;
+pub use state::InteractionState;
+pub use vibeui_derive::Component;
 
-/// Component trait that all UI components must implement
-pub trait Component {
-    /// Render the component
-    fn render(&self, renderer: &mut crate::render::Renderer) -> crate::app::Result<()>;
-    
-    /// Handle an event
-    fn handle_event(&mut self, event: &crate::events::Event) -> bool;
-    
-    /// Update the component state
-    fn update(&mut self) -> crate::app::Result<()>;
-    
+use std::any::Any;
+
+/// Future returned by `Component::update_async`
+#[cfg(feature = "async")]
+pub type UpdateFuture<'a> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = crate::app::Result<()>> + 'a>>;
+
+/// Access to a component as `Any` for downcasting
+///
+/// Implemented for every `'static` type, so components get it for free.
+pub trait AsAny {
+    /// Get a reference to `self` as `Any`
+    fn as_any(&self) -> &dyn Any;
+
+    /// Get a mutable reference to `self` as `Any`
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Identity, geometry and visibility shared by all components
+///
+/// Components built on a `BaseComponent` field usually derive this with
+/// `#[derive(Component)]` and a `#[base]` attribute on that field.
+pub trait ComponentBase {
     /// Get the component's position and size
     fn bounds(&self) -> (u16, u16, u16, u16); // x, y, width, height
     
@@ -43,6 +79,173 @@ pub trait Component {
     
     /// Get the component's unique ID
     fn id(&self) -> &str;
+
+    /// Check if the component changed since it was last rendered
+    ///
+    /// Components that don't track their changes are redrawn when they
+    /// handle an event or when the application is asked to redraw.
+    fn is_dirty(&self) -> bool {
+        false
+    }
+
+    /// Mark the component as rendered
+    fn clear_dirty(&mut self) {}
+
+    /// Get the type name matched by stylesheet selectors, such as `Button`
+    fn type_name(&self) -> &str {
+        let name = std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
+    /// Get the classes matched by stylesheet selectors
+    fn classes(&self) -> &[String] {
+        &[]
+    }
+
+    /// Get the interaction state
+    fn state(&self) -> InteractionState {
+        InteractionState::default()
+    }
+
+    /// Set the interaction state
+    ///
+    /// The application updates hover, pressed and focus itself.
+    fn set_state(&mut self, _state: InteractionState) {}
+
+    /// Check if the component ignores input
+    fn is_disabled(&self) -> bool {
+        self.state().disabled
+    }
+
+    /// Enable or disable the component
+    fn set_disabled(&mut self, disabled: bool) {
+        let state = self.state();
+        self.set_state(InteractionState { disabled, ..state });
+    }
+
+    /// Check if the component is selected
+    fn is_selected(&self) -> bool {
+        self.state().selected
+    }
+
+    /// Select or deselect the component
+    fn set_selected(&mut self, selected: bool) {
+        let state = self.state();
+        self.set_state(InteractionState { selected, ..state });
+    }
+}
+
+/// Component trait that all UI components must implement
+pub trait Component: ComponentBase + AsAny {
+    /// Render the component
+    fn render(&self, renderer: &mut crate::render::Renderer) -> crate::app::Result<()>;
+    
+    /// Handle an event
+    fn handle_event(&mut self, event: &crate::events::Event) -> bool;
+
+    /// Deliver an event unless the component is disabled
+    ///
+    /// The application and containers dispatch through this, so
+    /// `handle_event` doesn't need to check `is_disabled` itself.
+    fn dispatch_event(&mut self, event: &crate::events::Event) -> bool {
+        !self.is_disabled() && self.handle_event(event)
+    }
+
+    /// Check if clicking the component gives it keyboard focus
+    fn is_focusable(&self) -> bool {
+        false
+    }
+    
+    /// Update the component state
+    fn update(&mut self) -> crate::app::Result<()>;
+
+    /// Update the component state, awaiting async work
+    ///
+    /// Called instead of `update` by `App::run_async`. The render loop waits
+    /// for the returned future, so long-running work such as network requests
+    /// should be started with a `Spawner` and its result handled as an event.
+    #[cfg(feature = "async")]
+    fn update_async(&mut self) -> UpdateFuture<'_> {
+        Box::pin(async move { self.update() })
+    }
+    
+    /// Called when the component is added to a running tree
+    ///
+    /// Start timers, subscriptions or background work here.
+    fn on_mount(&mut self, _context: &Context) {}
+
+    /// Called when the component is removed from the tree
+    ///
+    /// Cancel anything started in `on_mount`.
+    fn on_unmount(&mut self, _context: &Context) {}
+
+    /// Called when layout gives the component new bounds
+    ///
+    /// Top-level components of an `App` get the terminal area whenever the
+    /// terminal is resized.
+    fn on_resize(&mut self, _bounds: crate::render::Rect) {}
+
+    /// Called when the component receives focus
+    fn on_focus(&mut self) {}
+
+    /// Called when the component loses focus
+    fn on_blur(&mut self) {}
+
+    /// Get a direct child by name
+    fn child(&self, _name: &str) -> Option<&dyn Component> {
+        None
+    }
+
+    /// Get a mutable direct child by name
+    fn child_mut(&mut self, _name: &str) -> Option<&mut dyn Component> {
+        None
+    }
+
+    /// Get the names of all direct children, in the same order as `children`
+    fn child_names(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Get all direct children
+    fn children(&self) -> Vec<&dyn Component> {
+        Vec::new()
+    }
+
+    /// Get all direct children mutably
+    fn children_mut(&mut self) -> Vec<&mut dyn Component> {
+        Vec::new()
+    }
+}
+
+impl<'a> dyn Component + 'a {
+    /// Check if the component is of type `T`
+    pub fn is<T: Component + 'static>(&self) -> bool {
+        self.as_any().is::<T>()
+    }
+
+    /// Downcast to a concrete component type
+    pub fn downcast_ref<T: Component + 'static>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcast to a mutable concrete component type
+    pub fn downcast_mut<T: Component + 'static>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
+
+    /// Find a component in this subtree and downcast it
+    ///
+    /// See [`Selector`] for the accepted syntax. Paths are relative to this
+    /// component.
+    pub fn query<T: Component + 'static>(&self, selector: &str) -> Option<&T> {
+        Selector::parse(selector).find(self)?.downcast_ref()
+    }
+
+    /// Find a component in this subtree and downcast it mutably
+    pub fn query_mut<T: Component + 'static>(&mut self, selector: &str) -> Option<&mut T> {
+        Selector::parse(selector).find_mut(self)?.downcast_mut()
+    }
 }
 
 /// Base component structure that provides common functionality
@@ -54,6 +257,9 @@ pub struct BaseComponent {
     width: u16,
     height: u16,
     visible: bool,
+    dirty: bool,
+    classes: Vec<String>,
+    state: InteractionState,
 }
 
 impl BaseComponent {
@@ -66,6 +272,9 @@ impl BaseComponent {
             width: 10,
             height: 1,
             visible: true,
+            dirty: true,
+            classes: Vec::new(),
+            state: InteractionState::default(),
         }
     }
 
@@ -81,8 +290,11 @@ impl BaseComponent {
 
     /// Set the component position
     pub fn set_position(&mut self, x: u16, y: u16) {
-        self.x = x;
-        self.y = y;
+        if (self.x, self.y) != (x, y) {
+            self.x = x;
+            self.y = y;
+            self.dirty = true;
+        }
     }
 
     /// Get the component size
@@ -92,8 +304,11 @@ impl BaseComponent {
 
     /// Set the component size
     pub fn set_size(&mut self, width: u16, height: u16) {
-        self.width = width;
-        self.height = height;
+        if (self.width, self.height) != (width, height) {
+            self.width = width;
+            self.height = height;
+            self.dirty = true;
+        }
     }
 
     /// Check if the component is visible
@@ -103,7 +318,65 @@ impl BaseComponent {
 
     /// Set the component visibility
     pub fn set_visible(&mut self, visible: bool) {
-        self.visible = visible;
+        if self.visible != visible {
+            self.visible = visible;
+            self.dirty = true;
+        }
+    }
+
+    /// Check if the component needs to be re-rendered
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Mark the component as needing to be re-rendered
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Mark the component as rendered
+    pub fn clear_dirty(&mut self) {
+        self.dirty = false;
+    }
+
+    /// Get the interaction state
+    pub fn state(&self) -> InteractionState {
+        self.state
+    }
+
+    /// Set the interaction state
+    pub fn set_state(&mut self, state: InteractionState) {
+        if self.state != state {
+            self.state = state;
+            self.dirty = true;
+        }
+    }
+
+    /// Get the stylesheet classes
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// Check if the component has a stylesheet class
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes.iter().any(|c| c == class)
+    }
+
+    /// Add a stylesheet class
+    pub fn add_class(&mut self, class: &str) {
+        if !self.has_class(class) {
+            self.classes.push(class.to_string());
+            self.dirty = true;
+        }
+    }
+
+    /// Remove a stylesheet class
+    pub fn remove_class(&mut self, class: &str) {
+        let len = self.classes.len();
+        self.classes.retain(|c| c != class);
+        if self.classes.len() != len {
+            self.dirty = true;
+        }
     }
 }
 
@@ -128,4 +401,53 @@ mod tests {
         assert_eq!(component.size(), (20, 5));
         assert!(!component.is_visible());
     }
+
+    #[test]
+    fn test_base_component_dirty() {
+        let mut component = BaseComponent::new("test");
+        assert!(component.is_dirty());
+
+        component.clear_dirty();
+        component.set_visible(true);
+        assert!(!component.is_dirty());
+
+        component.set_visible(false);
+        assert!(component.is_dirty());
+    }
+
+    #[test]
+    fn test_base_component_classes() {
+        let mut component = BaseComponent::new("test");
+        component.clear_dirty();
+
+        component.add_class("primary");
+        component.add_class("primary");
+        assert_eq!(component.classes(), ["primary"]);
+        assert!(component.is_dirty());
+
+        component.remove_class("primary");
+        assert!(!component.has_class("primary"));
+    }
+
+    #[test]
+    fn test_base_component_state() {
+        let mut component = BaseComponent::new("test");
+        component.clear_dirty();
+
+        component.set_state(InteractionState { hovered: true, ..component.state() });
+        assert!(component.state().hovered);
+        assert!(component.is_dirty());
+
+        let mut label = Label::new("text");
+        label.set_disabled(true);
+        assert!(label.is_disabled());
+        assert!(!label.is_selected());
+    }
+
+    #[test]
+    fn test_type_name() {
+        let label = Label::new("text");
+        let component: &dyn Component = &label;
+        assert_eq!(component.type_name(), "Label");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,346 @@
+//! Paragraph component for displaying wrapped, styled text
+
+use crate::app::Result;
+use crate::components::layout::Alignment;
+use crate::components::{BaseComponent, Component, ComponentBase};
+use crate::events::Event;
+use crate::render::{Renderer, Sanitizer};
+use crate::style::{Line, Style, Text};
+use unicode_width::UnicodeWidthChar;
+
+/// Component drawing styled text with wrapping and alignment
+///
+/// Lines longer than the component wrap at word boundaries, and words
+/// longer than a whole row are broken. Span styles carry over to the rows a
+/// line wraps onto. `Alignment::Justify` justifies every row except the
+/// last of each line.
+#[derive(Debug, Clone, Component)]
+pub struct Paragraph {
+    #[base]
+    base: BaseComponent,
+    text: Text,
+    style: Style,
+    alignment: Alignment,
+    wrap: bool,
+}
+
+impl Paragraph {
+    /// Create a new paragraph
+    pub fn new<T: Into<Text>>(text: T) -> Self {
+        Self {
+            base: BaseComponent::new("paragraph"),
+            text: text.into(),
+            style: Style::default(),
+            alignment: Alignment::Start,
+            wrap: true,
+        }
+    }
+
+    /// Create a new paragraph with ID and text
+    pub fn with_id<S: Into<String>, T: Into<Text>>(id: S, text: T) -> Self {
+        let mut paragraph = Self::new(text);
+        paragraph.base = BaseComponent::new(&id.into());
+        paragraph
+    }
+
+    /// Set the style spans are drawn on top of
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the horizontal alignment
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Wrap long lines, or clip them when disabled
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Add a stylesheet class
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.base.add_class(class);
+        self
+    }
+
+    /// Get the text
+    pub fn text(&self) -> &Text {
+        &self.text
+    }
+
+    /// Set the text
+    pub fn set_text<T: Into<Text>>(&mut self, text: T) {
+        let text = text.into();
+        if self.text != text {
+            self.text = text;
+            self.base.mark_dirty();
+        }
+    }
+
+    /// Lay out the text for a width with the default sanitizer
+    ///
+    /// Returns the rows to draw, each with its offset from the left edge.
+    pub fn layout(&self, width: u16) -> Vec<(u16, Line)> {
+        self.layout_with(width, &Sanitizer::default())
+    }
+
+    /// Lay out the text for a width, sanitizing it first
+    ///
+    /// Tabs expand from the start of each line and control characters are
+    /// made printable before wrapping, and wide characters count as two
+    /// columns, so rows are measured as they will be drawn.
+    pub fn layout_with(&self, width: u16, sanitizer: &Sanitizer) -> Vec<(u16, Line)> {
+        let width = width as usize;
+        let mut rows = Vec::new();
+        for line in &self.text.lines {
+            let chars = sanitize(line, sanitizer);
+            let wrapped = if self.wrap {
+                wrap(&chars, width)
+            } else {
+                vec![clip(chars, width)]
+            };
+
+            let count = wrapped.len();
+            for (index, row) in wrapped.into_iter().enumerate() {
+                let free = width.saturating_sub(row_width(&row));
+                let (offset, row) = match self.alignment {
+                    Alignment::Start | Alignment::Stretch => (0, row),
+                    Alignment::Center => (free / 2, row),
+                    Alignment::End => (free, row),
+                    Alignment::Justify if index + 1 < count => (0, justify(row, width)),
+                    Alignment::Justify => (0, row),
+                };
+                rows.push((offset as u16, Line::from_styled_chars(row)));
+            }
+        }
+        rows
+    }
+}
+
+/// Get the number of columns a sanitized character takes
+fn char_width(ch: char) -> usize {
+    ch.width().unwrap_or(0)
+}
+
+/// Get the number of columns a row takes
+fn row_width(row: &[(char, Style)]) -> usize {
+    row.iter().map(|&(ch, _)| char_width(ch)).sum()
+}
+
+/// Sanitize a line as the buffer would, keeping each character's style
+///
+/// Tab stops are counted from the start of the line.
+fn sanitize(line: &Line, sanitizer: &Sanitizer) -> Vec<(char, Style)> {
+    let mut chars = Vec::new();
+    let mut column = 0;
+    let mut encoded = [0; 4];
+    for (ch, style) in line.styled_chars() {
+        let column_u16 = u16::try_from(column).unwrap_or(u16::MAX);
+        for ch in sanitizer.sanitize(ch.encode_utf8(&mut encoded), column_u16).chars() {
+            column += char_width(ch);
+            chars.push((ch, style));
+        }
+    }
+    chars
+}
+
+/// Keep the characters that fit in `width` columns
+fn clip(chars: Vec<(char, Style)>, width: usize) -> Vec<(char, Style)> {
+    let mut columns = 0;
+    chars
+        .into_iter()
+        .take_while(|&(ch, _)| {
+            columns += char_width(ch);
+            columns <= width
+        })
+        .collect()
+}
+
+/// Break styled characters into rows of at most `width` columns
+///
+/// Whitespace at the end of a row and at the start of a wrapped row is
+/// dropped; indentation of the first row is kept.
+fn wrap(chars: &[(char, Style)], width: usize) -> Vec<Vec<(char, Style)>> {
+    if width == 0 {
+        return Vec::new();
+    }
+
+    let mut rows = Vec::new();
+    let mut row: Vec<(char, Style)> = Vec::new();
+    let mut columns = 0;
+    let mut start = 0;
+    while start < chars.len() {
+        let space = chars[start].0.is_whitespace();
+        let end = chars[start..]
+            .iter()
+            .position(|(ch, _)| ch.is_whitespace() != space)
+            .map_or(chars.len(), |length| start + length);
+        let token = &chars[start..end];
+        start = end;
+
+        if space {
+            if row.is_empty() && !rows.is_empty() {
+                continue;
+            }
+            for &styled in token {
+                if columns + char_width(styled.0) > width {
+                    break;
+                }
+                columns += char_width(styled.0);
+                row.push(styled);
+            }
+            continue;
+        }
+        let has_words = row.iter().any(|(ch, _)| !ch.is_whitespace());
+        if has_words && columns + row_width(token) > width {
+            rows.push(trim_end(std::mem::take(&mut row)));
+            columns = 0;
+        }
+        for &styled in token {
+            // A character wider than the whole row still gets a row
+            if columns + char_width(styled.0) > width && !row.is_empty() {
+                rows.push(std::mem::take(&mut row));
+                columns = 0;
+            }
+            columns += char_width(styled.0);
+            row.push(styled);
+        }
+    }
+    rows.push(trim_end(row));
+    rows
+}
+
+/// Remove trailing whitespace from a row
+fn trim_end(mut row: Vec<(char, Style)>) -> Vec<(char, Style)> {
+    while row.last().is_some_and(|(ch, _)| ch.is_whitespace()) {
+        row.pop();
+    }
+    row
+}
+
+/// Widen the gaps between words so a row fills `width` columns
+///
+/// Leftover spaces go to the leftmost gaps. Added spaces take the style of
+/// the gap they widen.
+fn justify(row: Vec<(char, Style)>, width: usize) -> Vec<(char, Style)> {
+    let indent = row.iter().take_while(|(ch, _)| ch.is_whitespace()).count();
+    let gaps = row
+        .windows(2)
+        .enumerate()
+        .skip(indent)
+        .filter(|(_, pair)| !pair[0].0.is_whitespace() && pair[1].0.is_whitespace())
+        .count();
+    let extra = width.saturating_sub(row_width(&row));
+    if gaps == 0 || extra == 0 {
+        return row;
+    }
+
+    let mut justified = Vec::with_capacity(row.len() + extra);
+    let mut gap = 0;
+    for (index, &(ch, style)) in row.iter().enumerate() {
+        let starts_gap = index > indent && ch.is_whitespace() && !row[index - 1].0.is_whitespace();
+        if starts_gap {
+            let count = extra / gaps + usize::from(gap < extra % gaps);
+            justified.extend(std::iter::repeat_n((' ', style), count));
+            gap += 1;
+        }
+        justified.push((ch, style));
+    }
+    justified
+}
+
+impl Component for Paragraph {
+    fn render(&self, renderer: &mut Renderer) -> Result<()> {
+        if !self.base.is_visible() {
+            return Ok(());
+        }
+
+        let (x, y, width, height) = self.bounds();
+        let style = renderer.resolve_style(self, &self.style);
+        let rows = self.layout_with(width, &renderer.sanitizer());
+        for (row, (offset, line)) in rows.into_iter().take(height as usize).enumerate() {
+            renderer.draw_spans(x + offset, y + row as u16, &line.spans, &style, Some(width - offset))?;
+        }
+        Ok(())
+    }
+
+    fn handle_event(&mut self, _event: &Event) -> bool {
+        false
+    }
+
+    fn update(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::RenderBuffer;
+    use crate::style::{Color, Span};
+
+    fn rows(paragraph: &Paragraph, width: u16) -> Vec<String> {
+        paragraph
+            .layout(width)
+            .into_iter()
+            .map(|(offset, line)| format!("{}{}", " ".repeat(offset as usize), line))
+            .collect()
+    }
+
+    #[test]
+    fn test_paragraph_wrap() {
+        let red = Style::default().with_foreground(Color::Red);
+        let line = Line::raw("the ").with_span(Span::styled("quick brown", red)).with_span(" fox");
+        let paragraph = Paragraph::new(line);
+        assert_eq!(rows(&paragraph, 10), vec!["the quick", "brown fox"]);
+
+        // The styled span continues onto the second row
+        let layout = paragraph.layout(10);
+        assert_eq!(layout[0].1.spans[1], Span::styled("quick", red));
+        assert_eq!(layout[1].1.spans[0], Span::styled("brown", red));
+
+        let paragraph = Paragraph::new("  indented\nabcdefghij");
+        assert_eq!(rows(&paragraph, 4), vec!["  in", "dent", "ed", "abcd", "efgh", "ij"]);
+        assert_eq!(rows(&paragraph.clone().with_wrap(false), 4), vec!["  in", "abcd"]);
+        assert!(paragraph.layout(0).is_empty());
+    }
+
+    #[test]
+    fn test_paragraph_alignment() {
+        let paragraph = Paragraph::new("a bb ccc dd");
+        assert_eq!(rows(&paragraph.clone().with_alignment(Alignment::Center), 8), vec!["a bb ccc", "   dd"]);
+        assert_eq!(rows(&paragraph.clone().with_alignment(Alignment::End), 9), vec![" a bb ccc", "       dd"]);
+        assert_eq!(rows(&paragraph.clone().with_alignment(Alignment::Justify), 10), vec!["a  bb  ccc", "dd"]);
+        assert_eq!(rows(&paragraph.with_alignment(Alignment::Stretch), 10), vec!["a bb ccc", "dd"]);
+
+        let paragraph = Paragraph::new("a b c d\ne").with_alignment(Alignment::Justify);
+        assert_eq!(rows(&paragraph, 6), vec!["a  b c", "d", "e"]);
+    }
+
+    #[test]
+    fn test_paragraph_measures_columns() {
+        // Tabs expand before wrapping, from the start of the line
+        let paragraph = Paragraph::new("a\tb c");
+        assert_eq!(rows(&paragraph, 9), vec!["a       b", "c"]);
+
+        // Wide characters take two columns
+        let paragraph = Paragraph::new("日本 語").with_alignment(Alignment::End);
+        assert_eq!(rows(&paragraph, 5), vec![" 日本", "   語"]);
+
+        // and the buffer draws them in the columns they were measured in
+        let mut buffer = RenderBuffer::new(5, 2);
+        for (row, (offset, line)) in paragraph.layout(5).into_iter().enumerate() {
+            buffer.draw_spans(offset, row as u16, &line.spans, 5 - offset).unwrap();
+        }
+        assert_eq!(buffer.as_string(), " 日本\n   語");
+        assert_eq!(rows(&paragraph.with_wrap(false), 3), vec![" 日"]);
+
+        // Control characters are measured in caret notation
+        let paragraph = Paragraph::new("ab \x1bc");
+        assert_eq!(rows(&paragraph, 4), vec!["ab", "^[c"]);
+    }
+}
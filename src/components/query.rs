@@ -0,0 +1,179 @@
+//! Component lookup by path or id
+
+use crate::components::Component;
+use std::collections::HashMap;
+
+/// Selector for finding a component in a tree
+///
+/// `main/display` walks child names from the root, while `#display`
+/// searches the whole tree depth-first for a component with that id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selector<'a> {
+    /// Slash-separated child names
+    Path(&'a str),
+    /// Component id anywhere in the tree
+    Id(&'a str),
+}
+
+impl<'a> Selector<'a> {
+    /// Parse a selector string
+    pub fn parse(selector: &'a str) -> Self {
+        match selector.strip_prefix('#') {
+            Some(id) => Selector::Id(id),
+            None => Selector::Path(selector),
+        }
+    }
+
+    /// Find a component under a root
+    ///
+    /// Paths are relative to the root, so an empty path returns it.
+    pub fn find<'c>(&self, root: &'c dyn Component) -> Option<&'c dyn Component> {
+        match self {
+            Selector::Path(path) => segments(path).try_fold(root, |component, name| component.child(name)),
+            Selector::Id(id) => find_by_id(root, id),
+        }
+    }
+
+    /// Find a mutable component under a root
+    pub fn find_mut<'c>(&self, root: &'c mut dyn Component) -> Option<&'c mut dyn Component> {
+        match self {
+            Selector::Path(path) => {
+                segments(path).try_fold(root, |component, name| component.child_mut(name))
+            }
+            Selector::Id(id) => find_by_id_mut(root, id),
+        }
+    }
+
+    /// Find a component among named roots
+    ///
+    /// The first path segment names the root. Ids are searched in the roots
+    /// listed in `order`, so the first match in drawing order wins.
+    pub(crate) fn select<'c>(
+        &self,
+        roots: &'c HashMap<String, Box<dyn Component>>,
+        order: &[String],
+    ) -> Option<&'c dyn Component> {
+        match self {
+            Selector::Path(path) => {
+                let mut names = segments(path);
+                let root = roots.get(names.next()?)?.as_ref();
+                names.try_fold(root, |component, name| component.child(name))
+            }
+            Selector::Id(id) => order
+                .iter()
+                .filter_map(|name| roots.get(name))
+                .find_map(|root| find_by_id(root.as_ref(), id)),
+        }
+    }
+
+    /// Find a mutable component among named roots
+    pub(crate) fn select_mut<'c>(
+        &self,
+        roots: &'c mut HashMap<String, Box<dyn Component>>,
+        order: &[String],
+    ) -> Option<&'c mut dyn Component> {
+        match self {
+            Selector::Path(path) => {
+                let mut names = segments(path);
+                let root = roots.get_mut(names.next()?)?.as_mut() as &mut dyn Component;
+                names.try_fold(root, |component, name| component.child_mut(name))
+            }
+            Selector::Id(id) => {
+                let name = order.iter().find(|name| {
+                    roots.get(*name).is_some_and(|root| find_by_id(root.as_ref(), id).is_some())
+                })?;
+                find_by_id_mut(roots.get_mut(name)?.as_mut(), id)
+            }
+        }
+    }
+}
+
+/// Split a path into non-empty child names
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|name| !name.is_empty())
+}
+
+/// Depth-first search for a component id
+fn find_by_id<'c>(component: &'c dyn Component, id: &str) -> Option<&'c dyn Component> {
+    if component.id() == id {
+        return Some(component);
+    }
+    component
+        .children()
+        .into_iter()
+        .find_map(|child| find_by_id(child, id))
+}
+
+/// Depth-first search for a mutable component id
+fn find_by_id_mut<'c>(component: &'c mut dyn Component, id: &str) -> Option<&'c mut dyn Component> {
+    if component.id() == id {
+        return Some(component);
+    }
+    component
+        .children_mut()
+        .into_iter()
+        .find_map(|child| find_by_id_mut(child, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{Button, Container, Label};
+
+    fn tree() -> HashMap<String, Box<dyn Component>> {
+        let mut row = Container::with_id("row");
+        row.add_child("ok", Button::with_id("ok_button", "OK"));
+
+        let mut main = Container::with_id("main");
+        main.add_child("display", Label::with_id("display", "0"));
+        main.add_child("row", row);
+
+        let mut roots: HashMap<String, Box<dyn Component>> = HashMap::new();
+        roots.insert("main".to_string(), Box::new(main));
+        roots
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Selector::parse("main/display"), Selector::Path("main/display"));
+        assert_eq!(Selector::parse("#display"), Selector::Id("display"));
+    }
+
+    #[test]
+    fn test_select_by_path_and_id() {
+        let mut roots = tree();
+
+        let order = ["main".to_string()];
+
+        let display = Selector::parse("main/display").select(&roots, &order).unwrap();
+        assert_eq!(display.downcast_ref::<Label>().unwrap().text(), "0");
+        assert!(Selector::parse("main/missing").select(&roots, &order).is_none());
+
+        let button = Selector::parse("#ok_button").select_mut(&mut roots, &order).unwrap();
+        button.downcast_mut::<Button>().unwrap().set_text("Done");
+
+        let main = roots["main"].as_ref();
+        assert!(main.is::<Container>());
+        assert_eq!(main.query::<Button>("row/ok").unwrap().text(), "Done");
+        assert!(main.query::<Label>("row/ok").is_none());
+    }
+
+    #[test]
+    fn test_select_id_in_order() {
+        let mut roots = tree();
+        roots.insert("popup".to_string(), Box::new(Label::with_id("display", "popup")));
+
+        let text = |roots: &HashMap<String, Box<dyn Component>>, order: &[String]| {
+            let display = Selector::parse("#display").select(roots, order).unwrap();
+            display.downcast_ref::<Label>().unwrap().text().to_string()
+        };
+        let order = ["main".to_string(), "popup".to_string()];
+        assert_eq!(text(&roots, &order), "0");
+        let order = ["popup".to_string(), "main".to_string()];
+        assert_eq!(text(&roots, &order), "popup");
+
+        let display = Selector::parse("#display").select_mut(&mut roots, &order).unwrap();
+        display.downcast_mut::<Label>().unwrap().set_text("top");
+        assert_eq!(roots["popup"].downcast_ref::<Label>().unwrap().text(), "top");
+    }
+}
@@ -0,0 +1,176 @@
+//! Interaction states shared by all components
+
+use crate::components::Component;
+use crate::events::Event;
+use crate::render::Rect;
+use crate::style::PseudoClass;
+
+/// How the user is currently interacting with a component
+///
+/// Stored in `BaseComponent` and kept up to date by the application: hover
+/// and pressed follow the mouse, and focus follows `App::focus`. Disabled
+/// and selected are set by the program.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InteractionState {
+    /// The mouse is over the component
+    pub hovered: bool,
+    /// The component has keyboard focus
+    pub focused: bool,
+    /// A mouse button was pressed on the component and not yet released
+    pub pressed: bool,
+    /// The component ignores input
+    pub disabled: bool,
+    /// The component is selected, such as the current item of a list
+    pub selected: bool,
+}
+
+impl InteractionState {
+    /// Create a state with nothing set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the stylesheet pseudo-classes matching this state
+    ///
+    /// A disabled component is never hovered, focused or active as far as
+    /// the stylesheet is concerned, so `:disabled` cannot lose to a more
+    /// specific `Button:hover`.
+    pub fn pseudo_classes(&self) -> Vec<PseudoClass> {
+        let interactive = !self.disabled;
+        [
+            (interactive && self.hovered, PseudoClass::Hover),
+            (interactive && self.focused, PseudoClass::Focus),
+            (interactive && self.pressed, PseudoClass::Active),
+            (self.disabled, PseudoClass::Disabled),
+            (self.selected, PseudoClass::Selected),
+        ]
+        .into_iter()
+        .filter_map(|(set, class)| set.then_some(class))
+        .collect()
+    }
+}
+
+/// Update hover and pressed states in a component tree from a mouse event
+///
+/// Returns whether any state changed. Disabled components are never
+/// pressed.
+pub(crate) fn update_pointer_state(component: &mut dyn Component, event: &Event) -> bool {
+    let Some((x, y)) = event.mouse_position() else {
+        return false;
+    };
+
+    let (bx, by, width, height) = component.bounds();
+    let inside = component.is_visible() && Rect::new(bx, by, width, height).contains(x, y);
+
+    let mut state = component.state();
+    let previous = state;
+    state.hovered = inside;
+    match event {
+        Event::MousePress { .. } => state.pressed = inside && !state.disabled,
+        // A click follows the release it completes
+        Event::MouseRelease { .. } | Event::MouseClick { .. } => state.pressed = false,
+        _ => {}
+    }
+    if state != previous {
+        component.set_state(state);
+    }
+
+    let mut changed = state != previous;
+    for child in component.children_mut() {
+        changed |= update_pointer_state(child, event);
+    }
+    changed
+}
+
+/// Find the path of the topmost focusable component under a point
+///
+/// Hidden and disabled components are skipped along with their children.
+/// The path is relative to `component`, and empty for the component itself.
+pub(crate) fn focus_target(component: &dyn Component, x: u16, y: u16) -> Option<Vec<String>> {
+    if !component.is_visible() || component.is_disabled() {
+        return None;
+    }
+    for name in component.child_names().into_iter().rev() {
+        if let Some(mut path) = component.child(name).and_then(|child| focus_target(child, x, y)) {
+            path.insert(0, name.to_string());
+            return Some(path);
+        }
+    }
+
+    let (bx, by, width, height) = component.bounds();
+    (component.is_focusable() && Rect::new(bx, by, width, height).contains(x, y)).then(Vec::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{Button, ComponentBase, Container};
+    use crate::events::event::{Modifiers, MouseButton};
+
+    fn press(x: u16, y: u16) -> Event {
+        Event::MousePress { button: MouseButton::Left, x, y, modifiers: Modifiers::default() }
+    }
+
+    #[test]
+    fn test_pseudo_classes() {
+        let state = InteractionState { hovered: true, disabled: true, ..InteractionState::new() };
+        assert_eq!(state.pseudo_classes(), vec![PseudoClass::Disabled]);
+        let state = InteractionState { hovered: true, selected: true, ..InteractionState::new() };
+        assert_eq!(state.pseudo_classes(), vec![PseudoClass::Hover, PseudoClass::Selected]);
+        assert!(InteractionState::new().pseudo_classes().is_empty());
+    }
+
+    #[test]
+    fn test_update_pointer_state() {
+        let mut button = Button::new("OK");
+        button.set_position(2, 2);
+        button.set_size(4, 1);
+        let mut container = Container::new();
+        container.set_size(10, 5);
+        container.add_child("ok", button);
+        let root: &mut dyn Component = &mut container;
+
+        let moved = Event::MouseMove { x: 3, y: 2, modifiers: Modifiers::default() };
+        assert!(update_pointer_state(root, &moved));
+        assert!(root.state().hovered);
+        assert!(root.query::<Button>("ok").unwrap().state().hovered);
+        assert!(!update_pointer_state(root, &moved));
+
+        update_pointer_state(root, &press(3, 2));
+        assert!(root.query::<Button>("ok").unwrap().is_active());
+
+        let release = Event::MouseRelease { button: MouseButton::Left, x: 0, y: 0, modifiers: Modifiers::default() };
+        update_pointer_state(root, &release);
+        let button = root.query::<Button>("ok").unwrap();
+        assert!(!button.is_active());
+        assert!(!button.is_hovered());
+    }
+
+    #[test]
+    fn test_focus_target() {
+        use crate::components::TextInput;
+
+        let mut input = TextInput::new();
+        input.set_position(0, 1);
+        input.set_size(10, 1);
+        let mut form = Container::new();
+        form.set_size(10, 3);
+        form.add_child("name", input);
+        form.add_child("ok", Button::new("OK"));
+
+        assert_eq!(focus_target(&form, 3, 1), Some(vec!["name".to_string()]));
+        assert_eq!(focus_target(&form, 0, 0), None);
+
+        form.get_child_mut("name").unwrap().set_disabled(true);
+        assert_eq!(focus_target(&form, 3, 1), None);
+    }
+
+    #[test]
+    fn test_disabled_is_not_pressed() {
+        let mut button = Button::new("OK");
+        button.set_disabled(true);
+        update_pointer_state(&mut button, &press(0, 0));
+        assert!(button.state().hovered);
+        assert!(!button.state().pressed);
+    }
+}
@@ -1,32 +1,31 @@
 //! Text input component for user text entry
 
 use crate::app::Result;
-use crate::components::{BaseComponent, Component};
+use crate::components::{BaseComponent, Component, ComponentBase, InteractionState};
 use crate::events::Event;
+use crate::reactive::Binding;
 use crate::render::Renderer;
 use crate::style::{Style, Color};
 
 /// Text input component for entering text
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Component)]
 pub struct TextInput {
+    #[base]
     base: BaseComponent,
     text: String,
     placeholder: String,
     style: Style,
     cursor_style: Style,
+    /// Cursor position in characters
     cursor_position: usize,
-    is_focused: bool,
     is_password: bool,
     max_length: Option<usize>,
+    value_binding: Option<Binding<String>>,
 }
 
 impl TextInput {
     /// Create a new text input
     pub fn new() -> Self {
-        let default_style = Style::default()
-            .with_foreground(Color::White)
-            .with_background(Color::Black);
-            
         let cursor_style = Style::default()
             .with_foreground(Color::Black)
             .with_background(Color::White);
@@ -35,12 +34,12 @@ impl TextInput {
             base: BaseComponent::new("text_input"),
             text: String::new(),
             placeholder: "Enter text...".to_string(),
-            style: default_style,
+            style: Style::default(),
             cursor_style,
             cursor_position: 0,
-            is_focused: false,
             is_password: false,
             max_length: None,
+            value_binding: None,
         }
     }
 
@@ -59,14 +58,39 @@ impl TextInput {
     /// Set the input text
     pub fn set_text<S: Into<String>>(&mut self, text: S) {
         let text = text.into();
+        if text == self.text {
+            return;
+        }
         if let Some(max_len) = self.max_length {
-            if text.len() <= max_len {
-                self.text = text;
-                self.cursor_position = self.text.len().min(self.cursor_position);
+            if text.chars().count() > max_len {
+                return;
             }
-        } else {
-            self.text = text;
-            self.cursor_position = self.text.len().min(self.cursor_position);
+        }
+        self.text = text;
+        self.cursor_position = self.cursor_position.min(self.char_count());
+        self.base.mark_dirty();
+    }
+
+    /// Bind the value to a signal in both directions
+    ///
+    /// The input shows the signal's value and writes every edit back to it.
+    pub fn bind_value<B: Into<Binding<String>>>(mut self, binding: B) -> Self {
+        self.value_binding = Some(binding.into());
+        self.sync_binding();
+        self
+    }
+
+    /// Apply the bound value if it changed since the last sync
+    fn sync_binding(&mut self) {
+        if let Some(text) = self.value_binding.as_ref().and_then(|binding| binding.changed()) {
+            self.set_text(text);
+        }
+    }
+
+    /// Write the current text back to the bound value
+    fn push_binding(&mut self) {
+        if let Some(binding) = &self.value_binding {
+            binding.set(self.text.clone());
         }
     }
 
@@ -77,10 +101,26 @@ impl TextInput {
 
     /// Set the placeholder text
     pub fn set_placeholder<S: Into<String>>(&mut self, placeholder: S) {
-        self.placeholder = placeholder.into();
+        let placeholder = placeholder.into();
+        if placeholder != self.placeholder {
+            self.placeholder = placeholder;
+            self.base.mark_dirty();
+        }
+    }
+
+    /// Add a stylesheet class
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.base.add_class(class);
+        self
+    }
+
+    /// Set the input style
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
     }
 
-    /// Set the maximum length of the input
+    /// Set the maximum length of the input in characters
     pub fn with_max_length(mut self, max_length: usize) -> Self {
         self.max_length = Some(max_length);
         self
@@ -94,39 +134,63 @@ impl TextInput {
 
     /// Check if the input is focused
     pub fn is_focused(&self) -> bool {
-        self.is_focused
+        self.base.state().focused
     }
 
     /// Set focus state
     pub fn set_focused(&mut self, focused: bool) {
-        self.is_focused = focused;
+        let state = self.base.state();
+        self.base.set_state(InteractionState { focused, ..state });
     }
 
-    /// Get the display text (with password masking if needed)
-    fn display_text(&self) -> String {
+    /// Get the display text, masking passwords with `mask`
+    fn display_text(&self, mask: char) -> String {
         if self.is_password {
-            "•".repeat(self.text.len())
+            mask.to_string().repeat(self.text.chars().count())
         } else {
             self.text.clone()
         }
     }
 
+    /// Get the number of characters in the text
+    fn char_count(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Get the byte index of the character at `position`
+    fn byte_index(&self, position: usize) -> usize {
+        self.text.char_indices().nth(position).map_or(self.text.len(), |(i, _)| i)
+    }
+
+    /// Move the cursor to a character position, clamped to the text
+    fn set_cursor(&mut self, position: usize) {
+        let position = position.min(self.char_count());
+        if position != self.cursor_position {
+            self.cursor_position = position;
+            self.base.mark_dirty();
+        }
+    }
+
     /// Insert a character at the cursor position
     fn insert_char(&mut self, c: char) {
         if let Some(max_len) = self.max_length {
-            if self.text.len() >= max_len {
+            if self.char_count() >= max_len {
                 return;
             }
         }
         
-        self.text.insert(self.cursor_position, c);
+        let index = self.byte_index(self.cursor_position);
+        self.text.insert(index, c);
         self.cursor_position += 1;
+        self.base.mark_dirty();
     }
 
     /// Delete character at cursor position
     fn delete_char(&mut self) {
-        if self.cursor_position < self.text.len() {
-            self.text.remove(self.cursor_position);
+        if self.cursor_position < self.char_count() {
+            let index = self.byte_index(self.cursor_position);
+            self.text.remove(index);
+            self.base.mark_dirty();
         }
     }
 
@@ -134,22 +198,20 @@ impl TextInput {
     fn backspace(&mut self) {
         if self.cursor_position > 0 {
             self.cursor_position -= 1;
-            self.text.remove(self.cursor_position);
+            let index = self.byte_index(self.cursor_position);
+            self.text.remove(index);
+            self.base.mark_dirty();
         }
     }
 
     /// Move cursor left
     fn move_cursor_left(&mut self) {
-        if self.cursor_position > 0 {
-            self.cursor_position -= 1;
-        }
+        self.set_cursor(self.cursor_position.saturating_sub(1));
     }
 
     /// Move cursor right
     fn move_cursor_right(&mut self) {
-        if self.cursor_position < self.text.len() {
-            self.cursor_position += 1;
-        }
+        self.set_cursor(self.cursor_position + 1);
     }
 }
 
@@ -160,22 +222,23 @@ impl Component for TextInput {
         }
 
         let (x, y, width, height) = self.bounds();
+        let style = renderer.resolve_style(self, &self.style);
         
         // Draw background
         for row in 0..height {
-            renderer.draw_rect(x, y + row, width, 1, &self.style)?;
+            renderer.draw_rect(x, y + row, width, 1, &style)?;
         }
         
         // Draw text or placeholder
         let display_text = if self.text.is_empty() {
             self.placeholder.clone()
         } else {
-            self.display_text()
+            self.display_text(renderer.symbols().bullet)
         };
         
         // Truncate text if it's too long
         let max_text_len = width.saturating_sub(2) as usize; // Leave room for borders
-        let truncated_text = if display_text.len() > max_text_len {
+        let truncated_text = if display_text.chars().count() > max_text_len {
             let start = if self.cursor_position > max_text_len {
                 self.cursor_position - max_text_len
             } else {
@@ -186,11 +249,11 @@ impl Component for TextInput {
             display_text
         };
         
-        renderer.draw_text(x + 1, y, &truncated_text, &self.style, Some(width - 2))?;
+        renderer.draw_text(x + 1, y, &truncated_text, &style, Some(width - 2))?;
         
         // Draw cursor if focused
-        if self.is_focused && y < height {
-            let cursor_x = x + 1 + (self.cursor_position.min(truncated_text.len()) as u16);
+        if self.is_focused() && y < height {
+            let cursor_x = x + 1 + (self.cursor_position.min(truncated_text.chars().count()) as u16);
             renderer.draw_text(cursor_x, y, " ", &self.cursor_style, Some(1))?;
         }
         
@@ -198,103 +261,58 @@ impl Component for TextInput {
     }
 
     fn handle_event(&mut self, event: &Event) -> bool {
-        if !self.is_focused {
-            match event {
-                Event::MouseClick { x, y, .. } => {
-                    let (bx, by, bw, bh) = self.bounds();
-                    if *x >= bx && *x < bx + bw && *y >= by && *y < by + bh {
-                        self.is_focused = true;
-                        return true;
-                    }
-                }
-                _ => {}
-            }
+        // Focus is given and taken by the application
+        if !self.is_focused() {
             return false;
         }
 
-        match event {
-            Event::KeyPress { key, .. } => {
-                use crate::events::Key;
-                match key {
-                    Key::Char(c) => {
-                        self.insert_char(*c);
-                        return true;
-                    }
-                    Key::Backspace => {
-                        self.backspace();
-                        return true;
-                    }
-                    Key::Delete => {
-                        self.delete_char();
-                        return true;
-                    }
-                    Key::Left => {
-                        self.move_cursor_left();
-                        return true;
-                    }
-                    Key::Right => {
-                        self.move_cursor_right();
-                        return true;
-                    }
-                    Key::Home => {
-                        self.cursor_position = 0;
-                        return true;
-                    }
-                    Key::End => {
-                        self.cursor_position = self.text.len();
-                        return true;
-                    }
-                    Key::Esc => {
-                        self.is_focused = false;
-                        return true;
-                    }
-                    _ => {}
+        if let Event::KeyPress { key, .. } = event {
+            use crate::events::Key;
+            match key {
+                Key::Char(c) => {
+                    self.insert_char(*c);
+                    self.push_binding();
+                    return true;
                 }
-            }
-            Event::MouseClick { x, y, .. } => {
-                let (bx, by, bw, bh) = self.bounds();
-                if !(*x >= bx && *x < bx + bw && *y >= by && *y < by + bh) {
-                    self.is_focused = false;
+                Key::Backspace => {
+                    self.backspace();
+                    self.push_binding();
+                    return true;
+                }
+                Key::Delete => {
+                    self.delete_char();
+                    self.push_binding();
                     return true;
                 }
+                Key::Left => {
+                    self.move_cursor_left();
+                    return true;
+                }
+                Key::Right => {
+                    self.move_cursor_right();
+                    return true;
+                }
+                Key::Home => {
+                    self.set_cursor(0);
+                    return true;
+                }
+                Key::End => {
+                    self.set_cursor(self.char_count());
+                    return true;
+                }
+                _ => {}
             }
-            _ => {}
         }
         false
     }
 
-    fn update(&mut self) -> Result<()> {
-        // Text inputs don't need to update
-        Ok(())
-    }
-
-    fn bounds(&self) -> (u16, u16, u16, u16) {
-        (
-            self.base.position().0,
-            self.base.position().1,
-            self.base.size().0,
-            self.base.size().1,
-        )
-    }
-
-    fn set_position(&mut self, x: u16, y: u16) {
-        self.base.set_position(x, y);
-    }
-
-    fn set_size(&mut self, width: u16, height: u16) {
-        self.base.set_size(width, height);
-    }
-
-    fn is_visible(&self) -> bool {
-        self.base.is_visible()
-    }
-
-    fn set_visible(&mut self, visible: bool) {
-        self.base.set_visible(visible);
+    fn is_focusable(&self) -> bool {
+        true
     }
 
-    fn id(&self) -> &str {
-        self.base.id()
+    fn update(&mut self) -> Result<()> {
+        self.sync_binding();
+        Ok(())
     }
 }
 
@@ -328,6 +346,61 @@ mod tests {
         assert!(input.is_focused());
     }
 
+    #[test]
+    fn test_two_way_value_binding() {
+        use crate::events::{Key, Modifiers};
+        use crate::reactive::Signal;
+
+        let value = Signal::new("ab".to_string());
+        let mut input = TextInput::new().bind_value(&value);
+        assert_eq!(input.text(), "ab");
+
+        input.set_focused(true);
+        input.cursor_position = 2;
+        input.handle_event(&Event::KeyPress { key: Key::Char('c'), modifiers: Modifiers::default() });
+        assert_eq!(value.get(), "abc");
+
+        value.set("reset".to_string());
+        input.update().unwrap();
+        assert_eq!(input.text(), "reset");
+        assert!(input.is_dirty());
+    }
+
+    #[test]
+    fn test_edit_non_ascii_text() {
+        use crate::events::{Key, Modifiers};
+
+        fn press(input: &mut TextInput, key: Key) {
+            input.handle_event(&Event::KeyPress { key, modifiers: Modifiers::default() });
+        }
+
+        let mut input = TextInput::new().with_max_length(4);
+        input.set_focused(true);
+        input.set_text("héé");
+        input.set_cursor(3);
+        input.base.clear_dirty();
+
+        press(&mut input, Key::Char('!'));
+        assert_eq!(input.text(), "héé!");
+        assert!(input.is_dirty());
+
+        // The limit counts characters, not bytes
+        press(&mut input, Key::Char('?'));
+        assert_eq!(input.text(), "héé!");
+
+        input.base.clear_dirty();
+        press(&mut input, Key::Left);
+        assert!(input.is_dirty());
+        press(&mut input, Key::Backspace);
+        assert_eq!(input.text(), "hé!");
+        press(&mut input, Key::Delete);
+        assert_eq!(input.text(), "hé");
+
+        press(&mut input, Key::Home);
+        press(&mut input, Key::Char('ü'));
+        assert_eq!(input.text(), "ühé");
+    }
+
     #[test]
     fn test_password_field() {
         let input = TextInput::new().with_password(true);
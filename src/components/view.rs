@@ -0,0 +1,88 @@
+//! Declarative component tree builder
+
+/// Build a component tree from a nested literal
+///
+/// Each node is an ordinary component expression, so ids, layout, styles
+/// and event handlers use the usual constructors and `with_*` builders. A
+/// node followed by `=> { ... }` must be a `Container` and gets the listed
+/// children, each written as `name: node` and added with `add_child`.
+///
+/// ```ignore
+/// use vibeui::components::{Button, Container, Label, LayoutType};
+/// use vibeui::{view, Color};
+///
+/// let main = view! {
+///     Container::with_id("main").with_layout(LayoutType::Vertical) => {
+///         title: Label::with_id("title", "Hello").with_color(Color::Cyan),
+///         buttons: Container::with_id("buttons").with_layout(LayoutType::Horizontal) => {
+///             ok: Button::with_id("ok", "OK").on_click(|_| println!("OK")),
+///             cancel: Button::with_id("cancel", "Cancel"),
+///         },
+///     }
+/// };
+/// ```
+///
+/// Errors point at the node they come from: a builder that doesn't exist is
+/// reported on its call, and giving children to something other than a
+/// `Container` is a type mismatch on that node.
+#[macro_export]
+macro_rules! view {
+    (@children $parent:ident;) => {};
+    (@children $parent:ident; $name:ident : $node:expr => { $($children:tt)* } $(, $($rest:tt)*)?) => {
+        $parent.add_child(stringify!($name), $crate::view!($node => { $($children)* }));
+        $crate::view!(@children $parent; $($($rest)*)?);
+    };
+    (@children $parent:ident; $name:ident : $node:expr $(, $($rest:tt)*)?) => {
+        $parent.add_child(stringify!($name), $node);
+        $crate::view!(@children $parent; $($($rest)*)?);
+    };
+    (@children $parent:ident; $unexpected:tt $($rest:tt)*) => {
+        ::core::compile_error!(concat!(
+            "expected `name: component` in view!, found `",
+            stringify!($unexpected),
+            "`"
+        ))
+    };
+
+    ($node:expr => { $($children:tt)* }) => {{
+        #[allow(unused_mut)]
+        let mut container: $crate::components::Container = $node;
+        $crate::view!(@children container; $($children)*);
+        container
+    }};
+    ($node:expr) => {
+        $node
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::components::{Button, Component, Container, Label, LayoutType};
+
+    #[test]
+    fn test_view_builds_tree() {
+        let main = view! {
+            Container::with_id("main").with_layout(LayoutType::Vertical) => {
+                title: Label::with_id("title", "Hello"),
+                buttons: Container::with_id("buttons").with_layout(LayoutType::Horizontal) => {
+                    ok: Button::with_id("ok", "OK").on_click(|button| button.set_text("Done")),
+                    cancel: Button::with_id("cancel", "Cancel")
+                },
+                empty: Container::with_id("empty") => {},
+            }
+        };
+
+        assert_eq!(main.child_names(), vec!["title", "buttons", "empty"]);
+
+        let root: &dyn Component = &main;
+        assert_eq!(root.query::<Label>("title").unwrap().text(), "Hello");
+        assert_eq!(root.query::<Button>("buttons/ok").unwrap().text(), "OK");
+        assert!(root.query::<Button>("#cancel").is_some());
+    }
+
+    #[test]
+    fn test_view_leaf() {
+        let label = view!(Label::new("Leaf"));
+        assert_eq!(label.text(), "Leaf");
+    }
+}
@@ -72,6 +72,17 @@ impl EventHandler {
         }
     }
 
+    /// Wait for the next event, blocking for at most `timeout`
+    pub fn wait_event(&mut self, timeout: Duration) -> Result<Option<Event>> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(event) => Ok(Some(event)),
+            Err(mpsc::RecvTimeoutError::Timeout) => Ok(None),
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                Err(crate::events::Error::Channel("Event channel disconnected".to_string()))
+            }
+        }
+    }
+
     /// Process a single event
     pub fn process_event(&mut self, event: Event) -> Result<bool> {
         // Apply filters
@@ -129,6 +140,11 @@ impl EventHandler {
         })
     }
 
+    /// Get a sender that queues events for this handler
+    pub fn sender(&self) -> Sender<Event> {
+        self.sender.clone()
+    }
+
     /// Start the event processing loop in a separate thread
     pub fn start_processing_thread(mut self) -> thread::JoinHandle<()> {
         thread::spawn(move || {
@@ -253,6 +269,16 @@ mod tests {
         assert!(handler.is_ok());
     }
 
+    #[test]
+    fn test_wait_event() {
+        let mut handler = EventHandler::new().unwrap();
+        assert_eq!(handler.wait_event(Duration::from_millis(1)).unwrap(), None);
+
+        handler.send_event(Event::Timer { id: "tick".to_string() }).unwrap();
+        let event = handler.wait_event(Duration::from_millis(100)).unwrap();
+        assert_eq!(event, Some(Event::Timer { id: "tick".to_string() }));
+    }
+
     #[test]
     fn test_closure_listener() {
         let mut listener = ClosureListener::new(|event| {
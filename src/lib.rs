@@ -23,20 +23,28 @@
 //! }
 //! ```
 
+// Lets `#[derive(Component)]` refer to `::vibeui` from inside this crate
+extern crate self as vibeui;
+
 // Public modules
+pub mod animation;
 pub mod app;
 pub mod components;
 pub mod error;
 pub mod events;
 pub mod platform;
+pub mod program;
+pub mod reactive;
 pub mod render;
 pub mod style;
+pub mod widgets;
 
 // Re-export commonly used types
 pub use app::App;
-pub use components::Component;
+pub use components::{Component, ComponentBase};
 pub use error::{Error, Result};
 pub use events::{Event, EventHandler};
+pub use program::{Command, Element, Program};
 pub use render::{RenderContext, Renderer};
 pub use style::{Style, Color};
 
@@ -0,0 +1,311 @@
+//! Terminal color detection
+//!
+//! Asks the terminal for its foreground and background colors with the
+//! OSC 10 and OSC 11 queries, falling back to the `COLORFGBG` variable set
+//! by some terminals when there is no answer. The number of supported
+//! colors is read from environment variables.
+
+use crate::style::{Appearance, Color, ColorDepth};
+use std::time::Duration;
+
+/// How long to wait for the terminal to answer a color query by default
+///
+/// The wait normally ends as soon as the replies arrive, so this only
+/// matters for terminals that don't answer at all.
+pub const DEFAULT_QUERY_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// The default colors of the terminal, where known
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TerminalColors {
+    /// Default foreground color
+    pub foreground: Option<Color>,
+    /// Default background color
+    pub background: Option<Color>,
+}
+
+impl TerminalColors {
+    /// Detect the terminal colors
+    ///
+    /// Queries the terminal and waits up to `timeout` for an answer. Colors
+    /// the terminal did not report are taken from `COLORFGBG`.
+    pub fn detect(timeout: Duration) -> Self {
+        query(timeout)
+            .unwrap_or_default()
+            .with_fallback(|name| std::env::var(name).ok())
+    }
+
+    /// Fill in the colors that are missing from `COLORFGBG`, read with `var`
+    pub(crate) fn with_fallback(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        if self.foreground.is_none() || self.background.is_none() {
+            if let Some(value) = var("COLORFGBG") {
+                let fallback = parse_colorfgbg(&value);
+                self.foreground = self.foreground.or(fallback.foreground);
+                self.background = self.background.or(fallback.background);
+            }
+        }
+        self
+    }
+
+    /// Classify the terminal as dark or light
+    ///
+    /// Uses the background color, or the foreground color when only that
+    /// is known: light text implies a dark background.
+    pub fn appearance(&self) -> Option<Appearance> {
+        match (self.background, self.foreground) {
+            (Some(background), _) => Some(Appearance::of(background)),
+            (None, Some(foreground)) if foreground.is_dark() => Some(Appearance::Light),
+            (None, Some(_)) => Some(Appearance::Dark),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Detect the color depth from environment variables read with `var`
+///
+/// `FORCE_COLOR` wins over everything else: `0` disables colors, `2` and
+/// `3` select 256 colors and truecolor, and other values ensure at least
+/// 16 colors. A non-empty `NO_COLOR` disables colors. Otherwise the depth
+/// comes from `COLORTERM`, `TERM_PROGRAM` and `TERM`.
+pub(crate) fn detect_color_depth(var: impl Fn(&str) -> Option<String>) -> ColorDepth {
+    let detected = terminal_color_depth(&var);
+    if let Some(force) = var("FORCE_COLOR") {
+        return match force.trim() {
+            "0" | "false" => ColorDepth::Monochrome,
+            "2" => ColorDepth::Ansi256,
+            "3" => ColorDepth::TrueColor,
+            _ => detected.max(ColorDepth::Ansi16),
+        };
+    }
+    if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return ColorDepth::Monochrome;
+    }
+    detected
+}
+
+/// Get the color depth advertised by the terminal
+fn terminal_color_depth(var: &impl Fn(&str) -> Option<String>) -> ColorDepth {
+    if var("COLORTERM").is_some_and(|value| matches!(value.to_lowercase().as_str(), "truecolor" | "24bit")) {
+        return ColorDepth::TrueColor;
+    }
+    if var("TERM_PROGRAM").is_some_and(|value| matches!(value.as_str(), "iTerm.app" | "WezTerm" | "vscode" | "ghostty"))
+        || var("WT_SESSION").is_some()
+    {
+        return ColorDepth::TrueColor;
+    }
+
+    let Some(term) = var("TERM") else {
+        // The Windows console understands colors without setting TERM
+        return if cfg!(windows) { ColorDepth::Ansi16 } else { ColorDepth::Monochrome };
+    };
+    let term = term.to_lowercase();
+    if term == "dumb" {
+        ColorDepth::Monochrome
+    } else if ["direct", "truecolor", "24bit"].iter().any(|name| term.contains(name)) {
+        ColorDepth::TrueColor
+    } else if term.contains("256") {
+        ColorDepth::Ansi256
+    } else if ["color", "xterm", "screen", "tmux", "rxvt", "linux", "vt100", "ansi", "cygwin", "konsole", "kitty", "alacritty"]
+        .iter()
+        .any(|name| term.contains(name))
+    {
+        ColorDepth::Ansi16
+    } else {
+        ColorDepth::Monochrome
+    }
+}
+
+/// Parse the `fg;bg` or `fg;other;bg` ANSI color indices of `COLORFGBG`
+pub(crate) fn parse_colorfgbg(value: &str) -> TerminalColors {
+    let parts: Vec<&str> = value.split(';').collect();
+    let color = |part: Option<&&str>| part.and_then(|part| part.trim().parse::<u8>().ok()).map(Color::AnsiValue);
+    if parts.len() < 2 {
+        return TerminalColors::default();
+    }
+    TerminalColors {
+        foreground: color(parts.first()),
+        background: color(parts.last()),
+    }
+}
+
+/// Parse the replies to OSC 10 and OSC 11 queries
+///
+/// Replies look like `ESC ] 11 ; rgb:RRRR/GGGG/BBBB` terminated by BEL or
+/// `ESC \`, with one to four hex digits per channel. Other bytes are
+/// skipped.
+pub(crate) fn parse_osc_replies(bytes: &[u8]) -> TerminalColors {
+    let mut colors = TerminalColors::default();
+    let text = String::from_utf8_lossy(bytes);
+    for reply in text.split("\x1b]").skip(1) {
+        let end = reply.find(['\x07', '\x1b']).unwrap_or(reply.len());
+        let Some((code, value)) = reply[..end].split_once(';') else {
+            continue;
+        };
+        let color = parse_osc_color(value);
+        match code {
+            "10" => colors.foreground = color.or(colors.foreground),
+            "11" => colors.background = color.or(colors.background),
+            _ => {}
+        }
+    }
+    colors
+}
+
+/// Parse an X11 `rgb:` color specification
+fn parse_osc_color(value: &str) -> Option<Color> {
+    let channels = value.strip_prefix("rgb:")?;
+    let mut rgb = [0u8; 3];
+    let mut parts = channels.split('/');
+    for channel in rgb.iter_mut() {
+        let hex = parts.next()?;
+        if hex.is_empty() || hex.len() > 4 {
+            return None;
+        }
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        let max = (1u32 << (4 * hex.len())) - 1;
+        *channel = (value * 255 / max) as u8;
+    }
+    Some(Color::Rgb(rgb[0], rgb[1], rgb[2]))
+}
+
+/// Send the OSC 10 and OSC 11 queries and read the replies
+///
+/// A device attributes query is sent last and reading stops at its reply.
+/// Every terminal answers it after the color queries, so no late reply is
+/// left behind for the event reader, and terminals that ignore the color
+/// queries don't make us wait for the timeout.
+///
+/// The terminal is read unbuffered straight from the file descriptor:
+/// bytes held in `std::io::Stdin`'s buffer would never reach crossterm.
+#[cfg(unix)]
+fn query(timeout: Duration) -> Option<TerminalColors> {
+    use std::io::{IsTerminal, Write};
+    use std::time::Instant;
+
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    if !stdin.is_terminal() || !stdout.is_terminal() {
+        return None;
+    }
+
+    let was_raw = crossterm::terminal::is_raw_mode_enabled().ok()?;
+    if !was_raw {
+        crossterm::terminal::enable_raw_mode().ok()?;
+    }
+
+    let mut replies = Vec::new();
+    let sent = stdout
+        .write_all(b"\x1b]10;?\x1b\\\x1b]11;?\x1b\\\x1b[c")
+        .and_then(|_| stdout.flush())
+        .is_ok();
+    if sent {
+        let deadline = Instant::now() + timeout;
+        let mut buffer = [0u8; 256];
+        while !device_attributes_received(&replies) {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let mut poll = libc::pollfd { fd: libc::STDIN_FILENO, events: libc::POLLIN, revents: 0 };
+            // SAFETY: `poll` points to a single valid pollfd
+            let ready = unsafe { libc::poll(&mut poll, 1, remaining.as_millis() as libc::c_int) };
+            if ready <= 0 {
+                break;
+            }
+            // SAFETY: `buffer` is valid for writes of its whole length
+            let read = unsafe { libc::read(libc::STDIN_FILENO, buffer.as_mut_ptr().cast(), buffer.len()) };
+            if read <= 0 {
+                break;
+            }
+            replies.extend_from_slice(&buffer[..read as usize]);
+        }
+    }
+
+    if !was_raw {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+    Some(parse_osc_replies(&replies))
+}
+
+/// Color queries are only supported on Unix terminals
+#[cfg(not(unix))]
+fn query(_timeout: Duration) -> Option<TerminalColors> {
+    None
+}
+
+/// Check whether a device attributes reply, `ESC [ ? ... c`, was read
+#[cfg(unix)]
+fn device_attributes_received(bytes: &[u8]) -> bool {
+    bytes
+        .windows(3)
+        .position(|window| window == b"\x1b[?")
+        .is_some_and(|start| bytes[start..].contains(&b'c'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_osc_replies() {
+        let colors = parse_osc_replies(b"\x1b]10;rgb:ffff/ffff/ffff\x1b\\\x1b]11;rgb:1e1e/1e1e/2e2e\x07\x1b[?62;c");
+        assert_eq!(colors.foreground, Some(Color::Rgb(255, 255, 255)));
+        assert_eq!(colors.background, Some(Color::Rgb(0x1e, 0x1e, 0x2e)));
+        assert_eq!(colors.appearance(), Some(Appearance::Dark));
+
+        let colors = parse_osc_replies(b"\x1b]11;rgb:f/f/e\x07");
+        assert_eq!(colors.background, Some(Color::Rgb(255, 255, 238)));
+        assert_eq!(colors.appearance(), Some(Appearance::Light));
+
+        assert_eq!(parse_osc_replies(b"\x1b[?62;c"), TerminalColors::default());
+        assert_eq!(parse_osc_replies(b"\x1b]11;garbage\x07").background, None);
+    }
+
+    #[test]
+    fn test_detect_color_depth() {
+        let detect = |vars: &[(&str, &str)]| {
+            detect_color_depth(|name| vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string()))
+        };
+        assert_eq!(detect(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]), ColorDepth::TrueColor);
+        assert_eq!(detect(&[("TERM", "xterm-256color")]), ColorDepth::Ansi256);
+        assert_eq!(detect(&[("TERM", "screen")]), ColorDepth::Ansi16);
+        assert_eq!(detect(&[("TERM", "dumb")]), ColorDepth::Monochrome);
+        assert_eq!(detect(&[("TERM", "xterm-direct")]), ColorDepth::TrueColor);
+
+        assert_eq!(detect(&[("TERM", "xterm-256color"), ("NO_COLOR", "1")]), ColorDepth::Monochrome);
+        assert_eq!(detect(&[("TERM", "xterm-256color"), ("NO_COLOR", "")]), ColorDepth::Ansi256);
+        assert_eq!(detect(&[("TERM", "dumb"), ("FORCE_COLOR", "1")]), ColorDepth::Ansi16);
+        assert_eq!(detect(&[("TERM", "xterm-256color"), ("FORCE_COLOR", "true")]), ColorDepth::Ansi256);
+        assert_eq!(detect(&[("NO_COLOR", "1"), ("FORCE_COLOR", "3")]), ColorDepth::TrueColor);
+        assert_eq!(detect(&[("COLORTERM", "truecolor"), ("FORCE_COLOR", "0")]), ColorDepth::Monochrome);
+    }
+
+    #[test]
+    fn test_parse_colorfgbg() {
+        let colors = parse_colorfgbg("15;0");
+        assert_eq!(colors.foreground, Some(Color::AnsiValue(15)));
+        assert_eq!(colors.background, Some(Color::AnsiValue(0)));
+        assert_eq!(colors.appearance(), Some(Appearance::Dark));
+
+        let colors = parse_colorfgbg("0;default;15");
+        assert_eq!(colors.appearance(), Some(Appearance::Light));
+
+        let colors = parse_colorfgbg("15;default");
+        assert_eq!(colors.background, None);
+        assert_eq!(colors.appearance(), Some(Appearance::Dark));
+
+        assert_eq!(parse_colorfgbg("garbage"), TerminalColors::default());
+    }
+
+    #[test]
+    fn test_colorfgbg_fallback() {
+        let set = |name: &str| (name == "COLORFGBG").then(|| "0;15".to_string());
+        let unset = |_: &str| None;
+
+        let colors = TerminalColors::default().with_fallback(set);
+        assert_eq!(colors.appearance(), Some(Appearance::Light));
+        assert_eq!(TerminalColors::default().with_fallback(unset), TerminalColors::default());
+
+        // Queried colors win over the variable
+        let queried = TerminalColors { foreground: None, background: Some(Color::Black) };
+        let colors = queried.with_fallback(set);
+        assert_eq!(colors.background, Some(Color::Black));
+        assert_eq!(colors.foreground, Some(Color::AnsiValue(0)));
+    }
+}
@@ -0,0 +1,40 @@
+//! Asynchronous stream of terminal events
+
+use crate::events::Event;
+use crate::platform::{InputReader, Result};
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+/// Stream of terminal events that can be awaited
+///
+/// Terminal input is read by an `InputReader` and forwarded to the stream,
+/// so awaiting the next event never blocks the async runtime. Reading stops
+/// shortly after the stream is dropped. Read errors are forwarded and
+/// retried with a growing delay, and reading stops after too many of them
+/// in a row.
+#[derive(Debug)]
+pub struct EventStream {
+    receiver: UnboundedReceiver<Result<Event>>,
+    _reader: InputReader,
+}
+
+impl EventStream {
+    /// Start reading terminal events
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let reader = InputReader::spawn(move |result| sender.send(result).is_ok());
+        Self { receiver, _reader: reader }
+    }
+
+    /// Wait for the next terminal event
+    ///
+    /// Returns `None` if reading has stopped.
+    pub async fn next(&mut self) -> Option<Result<Event>> {
+        self.receiver.recv().await
+    }
+}
+
+impl Default for EventStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
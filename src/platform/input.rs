@@ -0,0 +1,136 @@
+//! Reading terminal input on a background thread
+
+use crate::events::event::MouseButton;
+use crate::events::Event;
+use crate::platform::{Result, Terminal};
+use crossterm::event;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// How long the reader thread waits for input before checking if the
+/// reader was dropped
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Consecutive read errors after which the reader thread stops
+const MAX_ERRORS: u32 = 10;
+
+/// Reader of terminal input on a background thread
+///
+/// Every event or read error is passed to a callback on the reader thread,
+/// so the caller can forward it to a channel it already waits on. A mouse
+/// button released on the cell it was pressed on is followed by a
+/// `MouseClick`, see `ClickTracker`. Read
+/// errors are retried with a growing delay. The thread stops shortly after
+/// the reader is dropped, when the callback returns `false`, or after too
+/// many read errors in a row.
+#[derive(Debug)]
+pub struct InputReader {
+    stopped: Arc<AtomicBool>,
+}
+
+impl InputReader {
+    /// Start reading terminal input
+    pub fn spawn<F>(mut forward: F) -> Self
+    where
+        F: FnMut(Result<Event>) -> bool + Send + 'static,
+    {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let stop = Arc::clone(&stopped);
+
+        thread::spawn(move || {
+            let mut clicks = ClickTracker::new();
+            let mut errors = 0;
+            while !stop.load(Ordering::SeqCst) {
+                let read = match event::poll(POLL_INTERVAL) {
+                    Ok(true) => event::read(),
+                    Ok(false) => continue,
+                    Err(e) => Err(e),
+                };
+
+                errors = if read.is_ok() { 0 } else { errors + 1 };
+                let results = match read.map_err(Into::into).and_then(Terminal::convert_crossterm_event) {
+                    Ok(event) => clicks.track(event).into_iter().map(Ok).collect(),
+                    Err(e) => vec![Err(e)],
+                };
+                if !results.into_iter().all(&mut forward) || errors >= MAX_ERRORS {
+                    break;
+                }
+                if errors > 0 {
+                    thread::sleep(POLL_INTERVAL * errors);
+                }
+            }
+        });
+
+        Self { stopped }
+    }
+}
+
+impl Drop for InputReader {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Tracker that turns a press and release of a mouse button into a click
+///
+/// Terminals only report presses and releases, so a `MouseClick` is made
+/// when a button is released on the same cell it was pressed on.
+#[derive(Debug, Default)]
+pub struct ClickTracker {
+    pressed: Option<(MouseButton, u16, u16)>,
+}
+
+impl ClickTracker {
+    /// Create a tracker with no button pressed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get an event followed by the click it completes, if any
+    pub fn track(&mut self, event: Event) -> Vec<Event> {
+        let click = match event {
+            Event::MousePress { button, x, y, .. } => {
+                self.pressed = Some((button, x, y));
+                None
+            }
+            Event::MouseRelease { button, x, y, modifiers } => (self.pressed.take() == Some((button, x, y)))
+                .then_some(Event::MouseClick { button, x, y, modifiers }),
+            _ => None,
+        };
+        std::iter::once(event).chain(click).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::event::Modifiers;
+
+    fn press(x: u16, y: u16) -> Event {
+        Event::MousePress { button: MouseButton::Left, x, y, modifiers: Modifiers::default() }
+    }
+
+    fn release(x: u16, y: u16) -> Event {
+        Event::MouseRelease { button: MouseButton::Left, x, y, modifiers: Modifiers::default() }
+    }
+
+    #[test]
+    fn test_click_tracker() {
+        let mut clicks = ClickTracker::new();
+        assert_eq!(clicks.track(press(1, 2)), vec![press(1, 2)]);
+        let click = Event::MouseClick { button: MouseButton::Left, x: 1, y: 2, modifiers: Modifiers::default() };
+        assert_eq!(clicks.track(release(1, 2)), vec![release(1, 2), click]);
+
+        // A release elsewhere, or without a press, is not a click
+        clicks.track(press(1, 2));
+        assert_eq!(clicks.track(release(3, 2)), vec![release(3, 2)]);
+        assert_eq!(clicks.track(release(1, 2)), vec![release(1, 2)]);
+
+        // Neither is releasing another button
+        clicks.track(press(1, 2));
+        let right = Event::MouseRelease { button: MouseButton::Right, x: 1, y: 2, modifiers: Modifiers::default() };
+        assert_eq!(clicks.track(right.clone()), vec![right]);
+    }
+}
@@ -3,9 +3,17 @@
 //! This module contains platform-specific code for terminal handling,
 //! including initialization, cleanup, and platform-specific optimizations.
 
+pub mod colors;
+pub mod input;
 pub mod terminal;
+#[cfg(feature = "async")]
+pub mod event_stream;
 
+pub use colors::TerminalColors;
+pub use input::{ClickTracker, InputReader};
 pub use terminal::Terminal;
+#[cfg(feature = "async")]
+pub use event_stream::EventStream;
 
 /// Platform-wide error type
 #[derive(Debug, thiserror::Error)]
@@ -46,6 +54,16 @@ pub fn supports_color() -> bool {
     Terminal::supports_color()
 }
 
+/// Detect the terminal's default foreground and background colors
+pub fn terminal_colors() -> TerminalColors {
+    TerminalColors::detect(colors::DEFAULT_QUERY_TIMEOUT)
+}
+
+/// Detect how many colors the terminal supports
+pub fn color_depth() -> crate::style::ColorDepth {
+    Terminal::color_depth()
+}
+
 /// Check if the terminal supports mouse events
 pub fn supports_mouse() -> bool {
     Terminal::supports_mouse()
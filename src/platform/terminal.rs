@@ -1,6 +1,7 @@
 //! Terminal handling for cross-platform support
 
 use crate::platform::{Error, Result};
+use crate::style::ColorDepth;
 use crossterm::{
     event::{self, Event as CrosstermEvent, KeyCode, KeyEvent, MouseEvent, MouseEventKind},
     execute, queue,
@@ -133,15 +134,14 @@ impl Terminal {
 
     /// Check if terminal supports colors
     pub fn supports_color() -> bool {
-        // Check if the TERM environment variable indicates color support
-        std::env::var("TERM")
-            .map(|term| {
-                term.contains("color") 
-                    || term.contains("256") 
-                    || term.contains("xterm") 
-                    || term.contains("screen")
-            })
-            .unwrap_or(false)
+        Self::color_depth() != ColorDepth::Monochrome
+    }
+
+    /// Detect how many colors the terminal supports
+    ///
+    /// Reads `COLORTERM`, `TERM`, `NO_COLOR` and `FORCE_COLOR`.
+    pub fn color_depth() -> ColorDepth {
+        crate::platform::colors::detect_color_depth(|name| std::env::var(name).ok())
     }
 
     /// Check if terminal supports mouse events
@@ -186,6 +186,20 @@ impl Terminal {
         Ok(())
     }
 
+    /// Switch to the alternate screen, keeping the shell's output intact
+    pub fn enter_alternate_screen() -> Result<()> {
+        execute!(stdout(), terminal::EnterAlternateScreen).map_err(|e| {
+            Error::Io(e)
+        })
+    }
+
+    /// Switch back from the alternate screen
+    pub fn leave_alternate_screen() -> Result<()> {
+        execute!(stdout(), terminal::LeaveAlternateScreen).map_err(|e| {
+            Error::Io(e)
+        })
+    }
+
     /// Clear the terminal
     pub fn clear() -> Result<()> {
         terminal::clear(ClearType::All).map_err(|e| {
@@ -232,18 +246,18 @@ impl Terminal {
     pub fn read_event() -> Result<Option<crate::events::Event>> {
         if event::poll(std::time::Duration::from_millis(0))? {
             let crossterm_event = event::read()?;
-            Ok(Some(self.convert_crossterm_event(crossterm_event)?))
+            Ok(Some(Self::convert_crossterm_event(crossterm_event)?))
         } else {
             Ok(None)
         }
     }
 
     /// Convert crossterm event to VibeUI event
-    fn convert_crossterm_event(&self, event: CrosstermEvent) -> Result<crate::events::Event> {
+    pub(crate) fn convert_crossterm_event(event: CrosstermEvent) -> Result<crate::events::Event> {
         match event {
-            CrosstermEvent::Key(KeyEvent { code, modifiers }) => {
-                let key = self.convert_key_code(code)?;
-                let vibe_modifiers = self.convert_modifiers(modifiers);
+            CrosstermEvent::Key(KeyEvent { code, modifiers, .. }) => {
+                let key = Self::convert_key_code(code)?;
+                let vibe_modifiers = Self::convert_modifiers(modifiers);
                 
                 Ok(crate::events::Event::KeyPress {
                     key,
@@ -251,13 +265,13 @@ impl Terminal {
                 })
             }
             CrosstermEvent::Mouse(MouseEvent { kind, column, row, modifiers }) => {
-                let vibe_modifiers = self.convert_modifiers(modifiers);
+                let vibe_modifiers = Self::convert_modifiers(modifiers);
                 let x = column;
                 let y = row;
                 
                 match kind {
                     MouseEventKind::Down(button) => {
-                        let vibe_button = self.convert_mouse_button(button);
+                        let vibe_button = Self::convert_mouse_button(button);
                         Ok(crate::events::Event::MousePress {
                             button: vibe_button,
                             x,
@@ -266,7 +280,7 @@ impl Terminal {
                         })
                     }
                     MouseEventKind::Up(button) => {
-                        let vibe_button = self.convert_mouse_button(button);
+                        let vibe_button = Self::convert_mouse_button(button);
                         Ok(crate::events::Event::MouseRelease {
                             button: vibe_button,
                             x,
@@ -275,7 +289,7 @@ impl Terminal {
                         })
                     }
                     MouseEventKind::Drag(button) => {
-                        let vibe_button = self.convert_mouse_button(button);
+                        let vibe_button = Self::convert_mouse_button(button);
                         Ok(crate::events::Event::MouseMove {
                             x,
                             y,
@@ -347,7 +361,7 @@ impl Terminal {
     }
 
     /// Convert crossterm key code to VibeUI key
-    fn convert_key_code(&self, code: KeyCode) -> Result<crate::events::Key> {
+    fn convert_key_code(code: KeyCode) -> Result<crate::events::Key> {
         match code {
             KeyCode::Char(c) => Ok(crate::events::Key::Char(c)),
             KeyCode::Backspace => Ok(crate::events::Key::Backspace),
@@ -371,7 +385,7 @@ impl Terminal {
     }
 
     /// Convert crossterm modifiers to VibeUI modifiers
-    fn convert_modifiers(&self, modifiers: event::KeyModifiers) -> crate::events::Modifiers {
+    fn convert_modifiers(modifiers: event::KeyModifiers) -> crate::events::Modifiers {
         crate::events::Modifiers {
             shift: modifiers.contains(event::KeyModifiers::SHIFT),
             ctrl: modifiers.contains(event::KeyModifiers::CONTROL),
@@ -381,7 +395,7 @@ impl Terminal {
     }
 
     /// Convert crossterm mouse button to VibeUI mouse button
-    fn convert_mouse_button(&self, button: event::MouseButton) -> crate::events::MouseButton {
+    fn convert_mouse_button(button: event::MouseButton) -> crate::events::MouseButton {
         match button {
             event::MouseButton::Left => crate::events::MouseButton::Left,
             event::MouseButton::Right => crate::events::MouseButton::Right,
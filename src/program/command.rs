@@ -0,0 +1,67 @@
+//! Commands returned by `Program::update`
+
+use std::fmt;
+
+/// Work requested by a program after handling a message
+#[derive(Default)]
+pub enum Command<Msg> {
+    /// Nothing to do
+    #[default]
+    None,
+    /// Stop the program
+    Quit,
+    /// Handle another message
+    Message(Msg),
+    /// Run several commands in order
+    Batch(Vec<Command<Msg>>),
+    /// Run a function and handle the message it returns
+    Perform(Box<dyn FnOnce() -> Msg>),
+}
+
+impl<Msg> Command<Msg> {
+    /// Create a command that does nothing
+    pub fn none() -> Self {
+        Command::None
+    }
+
+    /// Create a command that stops the program
+    pub fn quit() -> Self {
+        Command::Quit
+    }
+
+    /// Create a command that handles another message
+    pub fn message(message: Msg) -> Self {
+        Command::Message(message)
+    }
+
+    /// Create a command running several commands in order
+    pub fn batch(commands: Vec<Command<Msg>>) -> Self {
+        Command::Batch(commands)
+    }
+
+    /// Create a command that runs a function and handles its result
+    pub fn perform<F: FnOnce() -> Msg + 'static>(f: F) -> Self {
+        Command::Perform(Box::new(f))
+    }
+
+    /// Check if the command does nothing
+    pub fn is_none(&self) -> bool {
+        match self {
+            Command::None => true,
+            Command::Batch(commands) => commands.iter().all(|command| command.is_none()),
+            _ => false,
+        }
+    }
+}
+
+impl<Msg: fmt::Debug> fmt::Debug for Command<Msg> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::None => write!(f, "None"),
+            Command::Quit => write!(f, "Quit"),
+            Command::Message(message) => f.debug_tuple("Message").field(message).finish(),
+            Command::Batch(commands) => f.debug_tuple("Batch").field(commands).finish(),
+            Command::Perform(_) => write!(f, "Perform(..)"),
+        }
+    }
+}
@@ -0,0 +1,418 @@
+//! Declarative view elements produced by `Program::view`
+
+use crate::events::{Event, Key};
+use crate::render::{RenderBuffer, Result};
+use crate::style::{Color, Style};
+use std::fmt;
+use std::rc::Rc;
+
+/// Callback turning the edited value of a text input into a message
+type InputCallback<Msg> = Rc<dyn Fn(String) -> Msg>;
+
+/// Node of a view tree
+///
+/// Elements do not hold state of their own. Interactive elements describe
+/// which message to emit, and the program decides how the model changes.
+pub enum Element<Msg> {
+    /// Static text
+    Text {
+        text: String,
+        style: Style,
+    },
+    /// Clickable button
+    Button {
+        label: String,
+        style: Style,
+        on_press: Option<Msg>,
+    },
+    /// Single line text input showing a value held by the model
+    TextInput {
+        value: String,
+        placeholder: String,
+        width: u16,
+        style: Style,
+        focused: bool,
+        on_input: Option<InputCallback<Msg>>,
+        on_submit: Option<Msg>,
+    },
+    /// Children stacked vertically
+    Column(Vec<Element<Msg>>),
+    /// Children placed side by side, separated by one column
+    Row(Vec<Element<Msg>>),
+    /// Child surrounded by a border
+    Bordered {
+        child: Box<Element<Msg>>,
+        style: Style,
+    },
+    /// Empty space
+    Space {
+        width: u16,
+        height: u16,
+    },
+}
+
+impl<Msg> Element<Msg> {
+    /// Create a text element
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        Element::Text {
+            text: text.into(),
+            style: Style::default(),
+        }
+    }
+
+    /// Create a button element
+    pub fn button<S: Into<String>>(label: S) -> Self {
+        Element::Button {
+            label: label.into(),
+            style: Style::default()
+                .with_foreground(Color::White)
+                .with_background(Color::Blue)
+                .with_bold(true),
+            on_press: None,
+        }
+    }
+
+    /// Create a text input element showing `value`
+    pub fn text_input<S: Into<String>>(value: S) -> Self {
+        Element::TextInput {
+            value: value.into(),
+            placeholder: String::new(),
+            width: 20,
+            style: Style::default()
+                .with_foreground(Color::White)
+                .with_background(Color::Black),
+            focused: false,
+            on_input: None,
+            on_submit: None,
+        }
+    }
+
+    /// Create a column of elements
+    pub fn column(children: Vec<Element<Msg>>) -> Self {
+        Element::Column(children)
+    }
+
+    /// Create a row of elements
+    pub fn row(children: Vec<Element<Msg>>) -> Self {
+        Element::Row(children)
+    }
+
+    /// Create empty space
+    pub fn space(width: u16, height: u16) -> Self {
+        Element::Space { width, height }
+    }
+
+    /// Surround the element with a border
+    pub fn bordered(self) -> Self {
+        Element::Bordered {
+            child: Box::new(self),
+            style: Style::default(),
+        }
+    }
+
+    /// Set the style of a text, button, text input or border
+    pub fn with_style(mut self, new_style: Style) -> Self {
+        match &mut self {
+            Element::Text { style, .. }
+            | Element::Button { style, .. }
+            | Element::TextInput { style, .. }
+            | Element::Bordered { style, .. } => *style = new_style,
+            _ => {}
+        }
+        self
+    }
+
+    /// Set the message emitted when a button is pressed
+    pub fn on_press(mut self, message: Msg) -> Self {
+        if let Element::Button { on_press, .. } = &mut self {
+            *on_press = Some(message);
+        }
+        self
+    }
+
+    /// Set the placeholder of a text input
+    pub fn placeholder<S: Into<String>>(mut self, text: S) -> Self {
+        if let Element::TextInput { placeholder, .. } = &mut self {
+            *placeholder = text.into();
+        }
+        self
+    }
+
+    /// Set the width of a text input
+    pub fn width(mut self, new_width: u16) -> Self {
+        if let Element::TextInput { width, .. } = &mut self {
+            *width = new_width;
+        }
+        self
+    }
+
+    /// Set whether a text input receives keyboard input
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        if let Element::TextInput { focused, .. } = &mut self {
+            *focused = is_focused;
+        }
+        self
+    }
+
+    /// Set the message emitted when a text input is edited
+    pub fn on_input<F: Fn(String) -> Msg + 'static>(mut self, f: F) -> Self {
+        if let Element::TextInput { on_input, .. } = &mut self {
+            *on_input = Some(Rc::new(f));
+        }
+        self
+    }
+
+    /// Set the message emitted when Enter is pressed in a text input
+    pub fn on_submit(mut self, message: Msg) -> Self {
+        if let Element::TextInput { on_submit, .. } = &mut self {
+            *on_submit = Some(message);
+        }
+        self
+    }
+
+    /// Get the size the element occupies
+    pub fn size(&self) -> (u16, u16) {
+        match self {
+            Element::Text { text, .. } => (text.chars().count() as u16, 1),
+            Element::Button { label, .. } => (label.chars().count() as u16 + 4, 1),
+            Element::TextInput { width, .. } => (*width, 1),
+            Element::Column(children) => children.iter().fold((0, 0), |(w, h), child| {
+                let (child_w, child_h) = child.size();
+                (w.max(child_w), h.saturating_add(child_h))
+            }),
+            Element::Row(children) => {
+                let (w, h) = children.iter().fold((0u16, 0), |(w, h), child| {
+                    let (child_w, child_h) = child.size();
+                    (w.saturating_add(child_w).saturating_add(1), h.max(child_h))
+                });
+                (w.saturating_sub(1), h)
+            }
+            Element::Bordered { child, .. } => {
+                let (w, h) = child.size();
+                (w.saturating_add(2), h.saturating_add(2))
+            }
+            Element::Space { width, height } => (*width, *height),
+        }
+    }
+
+    /// Get the position of each child of a column or row placed at (x, y)
+    fn child_positions(&self, x: u16, y: u16) -> Vec<(u16, u16)> {
+        let mut positions = Vec::new();
+        match self {
+            Element::Column(children) => {
+                let mut child_y = y;
+                for child in children {
+                    positions.push((x, child_y));
+                    child_y = child_y.saturating_add(child.size().1);
+                }
+            }
+            Element::Row(children) => {
+                let mut child_x = x;
+                for child in children {
+                    positions.push((child_x, y));
+                    child_x = child_x.saturating_add(child.size().0).saturating_add(1);
+                }
+            }
+            Element::Bordered { .. } => positions.push((x.saturating_add(1), y.saturating_add(1))),
+            _ => {}
+        }
+        positions
+    }
+
+    /// Get the children of a container element
+    fn children(&self) -> Vec<&Element<Msg>> {
+        match self {
+            Element::Column(children) | Element::Row(children) => children.iter().collect(),
+            Element::Bordered { child, .. } => vec![child.as_ref()],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Render the element into a buffer with its top-left corner at (x, y)
+    pub fn render(&self, buffer: &mut RenderBuffer, x: u16, y: u16) -> Result<()> {
+        match self {
+            Element::Text { text, style } => buffer.draw_text(x, y, text, style)?,
+            Element::Button { label, style, .. } => {
+                buffer.draw_text(x, y, &format!("[ {} ]", label), style)?
+            }
+            Element::TextInput { value, placeholder, width, style, focused, .. } => {
+                buffer.draw_rect(x, y, *width, 1, style)?;
+                let (text, text_style) = if value.is_empty() {
+                    (placeholder.as_str(), style.with_italic(true))
+                } else {
+                    (value.as_str(), *style)
+                };
+                let visible: String = text.chars().take(*width as usize).collect();
+                buffer.draw_text(x, y, &visible, &text_style)?;
+                if *focused {
+                    let cursor_x = x.saturating_add((value.chars().count() as u16).min(width.saturating_sub(1)));
+                    let cursor = buffer.symbols().cursor;
+                    buffer.draw_char(cursor_x, y, cursor, style)?;
+                }
+            }
+            Element::Bordered { style, .. } => {
+                let (width, height) = self.size();
+                buffer.draw_border(x, y, width, height, style)?;
+            }
+            Element::Column(_) | Element::Row(_) | Element::Space { .. } => {}
+        }
+
+        for (child, (child_x, child_y)) in self.children().into_iter().zip(self.child_positions(x, y)) {
+            child.render(buffer, child_x, child_y)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Msg: Clone> Element<Msg> {
+    /// Map an input event to the messages of the element placed at (x, y)
+    ///
+    /// Mouse clicks trigger the button under the pointer and key presses
+    /// edit the focused text input.
+    pub fn on_event(&self, event: &Event, x: u16, y: u16) -> Vec<Msg> {
+        let mut messages = Vec::new();
+        self.collect_messages(event, x, y, &mut messages);
+        messages
+    }
+
+    /// Collect the messages produced by this element and its children
+    fn collect_messages(&self, event: &Event, x: u16, y: u16, messages: &mut Vec<Msg>) {
+        match self {
+            Element::Button { on_press: Some(message), .. } => {
+                if let Event::MouseClick { x: mx, y: my, .. } = event {
+                    let (width, height) = self.size();
+                    if *mx >= x && *mx < x.saturating_add(width) && *my >= y && *my < y.saturating_add(height) {
+                        messages.push(message.clone());
+                    }
+                }
+            }
+            Element::TextInput { value, focused: true, on_input, on_submit, .. } => {
+                if let Event::KeyPress { key, modifiers } = event {
+                    let mut edited = value.clone();
+                    match key {
+                        Key::Char(c) if !modifiers.ctrl && !modifiers.alt => edited.push(*c),
+                        Key::Backspace => {
+                            edited.pop();
+                        }
+                        Key::Enter => {
+                            if let Some(message) = on_submit {
+                                messages.push(message.clone());
+                            }
+                        }
+                        _ => {}
+                    }
+                    if edited != *value {
+                        if let Some(f) = on_input {
+                            messages.push(f(edited));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        for (child, (child_x, child_y)) in self.children().into_iter().zip(self.child_positions(x, y)) {
+            child.collect_messages(event, child_x, child_y, messages);
+        }
+    }
+}
+
+impl<Msg: fmt::Debug> fmt::Debug for Element<Msg> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Element::Text { text, .. } => f.debug_tuple("Text").field(text).finish(),
+            Element::Button { label, on_press, .. } => f
+                .debug_struct("Button")
+                .field("label", label)
+                .field("on_press", on_press)
+                .finish(),
+            Element::TextInput { value, focused, .. } => f
+                .debug_struct("TextInput")
+                .field("value", value)
+                .field("focused", focused)
+                .finish(),
+            Element::Column(children) => f.debug_tuple("Column").field(children).finish(),
+            Element::Row(children) => f.debug_tuple("Row").field(children).finish(),
+            Element::Bordered { child, .. } => f.debug_tuple("Bordered").field(child).finish(),
+            Element::Space { width, height } => f.debug_tuple("Space").field(width).field(height).finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Modifiers, MouseButton};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Msg {
+        Increment,
+        Edit(String),
+        Submit,
+    }
+
+    fn click(x: u16, y: u16) -> Event {
+        Event::MouseClick {
+            button: MouseButton::Left,
+            x,
+            y,
+            modifiers: Modifiers::default(),
+        }
+    }
+
+    #[test]
+    fn test_element_size() {
+        let view: Element<Msg> = Element::column(vec![
+            Element::text("Count: 1"),
+            Element::row(vec![Element::button("+"), Element::button("-")]),
+        ])
+        .bordered();
+
+        // Row: "[ + ]" + gap + "[ - ]" = 11 columns
+        assert_eq!(view.size(), (13, 4));
+    }
+
+    #[test]
+    fn test_button_click() {
+        let view = Element::column(vec![
+            Element::text("Count"),
+            Element::button("+").on_press(Msg::Increment),
+        ]);
+
+        assert_eq!(view.on_event(&click(2, 1), 0, 0), vec![Msg::Increment]);
+        assert!(view.on_event(&click(2, 0), 0, 0).is_empty());
+        assert!(view.on_event(&click(5, 1), 0, 0).is_empty());
+
+        // Only the click activates the button, not the press before it
+        let press = Event::MousePress { button: MouseButton::Left, x: 2, y: 1, modifiers: Modifiers::default() };
+        assert!(view.on_event(&press, 0, 0).is_empty());
+
+        // Placed at the edge of the screen, the hit test doesn't overflow
+        let button = Element::button("+").on_press(Msg::Increment);
+        assert_eq!(button.on_event(&click(u16::MAX - 1, 0), u16::MAX - 2, 0), vec![Msg::Increment]);
+    }
+
+    #[test]
+    fn test_text_input_messages() {
+        let input = Element::text_input("ab")
+            .focused(true)
+            .on_input(Msg::Edit)
+            .on_submit(Msg::Submit);
+
+        let key = |key| Event::KeyPress { key, modifiers: Modifiers::default() };
+        assert_eq!(input.on_event(&key(Key::Char('c')), 0, 0), vec![Msg::Edit("abc".to_string())]);
+        assert_eq!(input.on_event(&key(Key::Backspace), 0, 0), vec![Msg::Edit("a".to_string())]);
+        assert_eq!(input.on_event(&key(Key::Enter), 0, 0), vec![Msg::Submit]);
+
+        let unfocused = Element::text_input("ab").on_input(Msg::Edit);
+        assert!(unfocused.on_event(&key(Key::Char('c')), 0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_render_element() {
+        let view: Element<Msg> = Element::row(vec![Element::text("Hi"), Element::button("Ok")]);
+        let mut buffer = RenderBuffer::new(12, 1);
+        view.render(&mut buffer, 0, 0).unwrap();
+        assert_eq!(buffer.as_string().trim_end(), "Hi [ Ok ]");
+    }
+}
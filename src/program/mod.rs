@@ -0,0 +1,256 @@
+//! Elm-style program module
+//!
+//! This module contains an alternative to component-based applications
+//! where all state lives in a single model. Views are rebuilt from the model
+//! and emit messages, and only `Program::update` changes the model.
+
+pub mod command;
+pub mod element;
+
+pub use command::Command;
+pub use element::Element;
+
+use crate::events::Event;
+use std::collections::VecDeque;
+
+/// Application following the Model/Update/View architecture
+pub trait Program {
+    /// Application state
+    type Model;
+
+    /// Messages emitted by the view and by events
+    type Message: Clone;
+
+    /// Create the initial model and a command to run at startup
+    fn init(&self) -> (Self::Model, Command<Self::Message>);
+
+    /// Update the model with a message
+    fn update(&self, model: &mut Self::Model, message: Self::Message) -> Command<Self::Message>;
+
+    /// Build the view for the current model
+    fn view(&self, model: &Self::Model) -> Element<Self::Message>;
+
+    /// Map an event not handled by the view to a message
+    ///
+    /// Override this to react to keyboard shortcuts, timers, resizes or
+    /// custom events.
+    fn on_event(&self, _model: &Self::Model, _event: &Event) -> Option<Self::Message> {
+        None
+    }
+}
+
+/// Running instance of a program holding its model
+///
+/// The instance dispatches messages and events without touching the
+/// terminal, so programs can be driven in tests.
+pub struct ProgramState<P: Program> {
+    program: P,
+    model: P::Model,
+    quit: bool,
+}
+
+impl<P: Program> ProgramState<P> {
+    /// Initialize a program, running its startup command
+    pub fn new(program: P) -> Self {
+        let (model, command) = program.init();
+        let mut state = Self {
+            program,
+            model,
+            quit: false,
+        };
+        state.run_command(command);
+        state
+    }
+
+    /// Get the program
+    pub fn program(&self) -> &P {
+        &self.program
+    }
+
+    /// Get the current model
+    pub fn model(&self) -> &P::Model {
+        &self.model
+    }
+
+    /// Consume the instance, returning the final model
+    pub fn into_model(self) -> P::Model {
+        self.model
+    }
+
+    /// Check if the program requested to quit
+    pub fn should_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Build the view for the current model
+    pub fn view(&self) -> Element<P::Message> {
+        self.program.view(&self.model)
+    }
+
+    /// Update the model with a message and run the resulting commands
+    pub fn dispatch(&mut self, message: P::Message) {
+        let command = self.program.update(&mut self.model, message);
+        self.run_command(command);
+    }
+
+    /// Handle an event, returning whether any message was dispatched
+    ///
+    /// The event is first offered to the view placed at the top-left
+    /// corner, then to `Program::on_event`.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        if matches!(event, Event::Quit) {
+            self.quit = true;
+            return true;
+        }
+
+        let mut messages = self.view().on_event(event, 0, 0);
+        if messages.is_empty() {
+            messages.extend(self.program.on_event(&self.model, event));
+        }
+
+        let handled = !messages.is_empty();
+        for message in messages {
+            self.dispatch(message);
+        }
+        handled
+    }
+
+    /// Run a command and every command it produces
+    fn run_command(&mut self, command: Command<P::Message>) {
+        let mut queue = VecDeque::from([command]);
+        while let Some(command) = queue.pop_front() {
+            match command {
+                Command::None => {}
+                Command::Quit => self.quit = true,
+                Command::Message(message) => {
+                    queue.push_back(self.program.update(&mut self.model, message));
+                }
+                Command::Batch(commands) => queue.extend(commands),
+                Command::Perform(f) => {
+                    let message = f();
+                    queue.push_back(self.program.update(&mut self.model, message));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Key, Modifiers, MouseButton};
+
+    struct Counter;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Msg {
+        Increment,
+        Reset,
+        Loaded(i32),
+        Quit,
+    }
+
+    impl Program for Counter {
+        type Model = i32;
+        type Message = Msg;
+
+        fn init(&self) -> (i32, Command<Msg>) {
+            (0, Command::perform(|| Msg::Loaded(10)))
+        }
+
+        fn update(&self, model: &mut i32, message: Msg) -> Command<Msg> {
+            match message {
+                Msg::Increment => *model += 1,
+                Msg::Reset => *model = 0,
+                Msg::Loaded(value) => *model = value,
+                Msg::Quit => return Command::quit(),
+            }
+            Command::none()
+        }
+
+        fn view(&self, model: &i32) -> Element<Msg> {
+            Element::column(vec![
+                Element::text(format!("Count: {}", model)),
+                Element::row(vec![
+                    Element::button("+").on_press(Msg::Increment),
+                    Element::button("Reset").on_press(Msg::Reset),
+                ]),
+            ])
+        }
+
+        fn on_event(&self, _model: &i32, event: &Event) -> Option<Msg> {
+            match event {
+                Event::KeyPress { key: Key::Char('q'), .. } => Some(Msg::Quit),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_program_update() {
+        let mut state = ProgramState::new(Counter);
+        assert_eq!(*state.model(), 10);
+
+        state.dispatch(Msg::Increment);
+        assert_eq!(*state.model(), 11);
+
+        state.dispatch(Msg::Reset);
+        assert_eq!(*state.model(), 0);
+        assert!(!state.should_quit());
+    }
+
+    #[test]
+    fn test_program_events() {
+        let mut state = ProgramState::new(Counter);
+        let click = Event::MouseClick {
+            button: MouseButton::Left,
+            x: 1,
+            y: 1,
+            modifiers: Modifiers::default(),
+        };
+        assert!(state.handle_event(&click));
+        assert_eq!(*state.model(), 11);
+
+        let quit = Event::KeyPress {
+            key: Key::Char('q'),
+            modifiers: Modifiers::default(),
+        };
+        assert!(state.handle_event(&quit));
+        assert!(state.should_quit());
+    }
+
+    #[test]
+    fn test_program_press_and_release() {
+        use crate::platform::ClickTracker;
+
+        let mut state = ProgramState::new(Counter);
+        let mut clicks = ClickTracker::new();
+        let mut mouse = |press: (u16, u16), release: (u16, u16)| {
+            let events = [
+                Event::MousePress { button: MouseButton::Left, x: press.0, y: press.1, modifiers: Modifiers::default() },
+                Event::MouseRelease { button: MouseButton::Left, x: release.0, y: release.1, modifiers: Modifiers::default() },
+            ];
+            for event in events.into_iter().flat_map(|event| clicks.track(event)) {
+                state.handle_event(&event);
+            }
+            *state.model()
+        };
+
+        // Terminals report a press and a release, which click the button once
+        assert_eq!(mouse((1, 1), (1, 1)), 11);
+        // Releasing off the button cancels the click
+        assert_eq!(mouse((1, 1), (9, 9)), 11);
+    }
+
+    #[test]
+    fn test_command_batch() {
+        let mut state = ProgramState::new(Counter);
+        state.run_command(Command::batch(vec![
+            Command::message(Msg::Reset),
+            Command::message(Msg::Increment),
+            Command::message(Msg::Increment),
+        ]));
+        assert_eq!(*state.model(), 2);
+        assert!(Command::<Msg>::batch(vec![Command::none()]).is_none());
+    }
+}
@@ -0,0 +1,163 @@
+//! Bindings connecting reactive values to component properties
+
+use crate::reactive::{Computed, Signal};
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+/// Reactive value a binding reads from and possibly writes to
+trait Source<T> {
+    fn get(&self) -> T;
+    fn version(&self) -> u64;
+    fn set(&self, value: T) -> bool;
+}
+
+impl<T: Clone + 'static> Source<T> for Signal<T> {
+    fn get(&self) -> T {
+        Signal::get(self)
+    }
+
+    fn version(&self) -> u64 {
+        Signal::version(self)
+    }
+
+    fn set(&self, value: T) -> bool {
+        Signal::set(self, value);
+        true
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Source<T> for Computed<T> {
+    fn get(&self) -> T {
+        Computed::get(self)
+    }
+
+    fn version(&self) -> u64 {
+        Computed::version(self)
+    }
+
+    fn set(&self, _value: T) -> bool {
+        false
+    }
+}
+
+/// Connection between a component property and a signal or computed value
+///
+/// A component polls its bindings with `changed` during `update`. Bindings
+/// to signals are two-way, so components can write user input back.
+pub struct Binding<T> {
+    source: Rc<dyn Source<T>>,
+    seen_version: Cell<Option<u64>>,
+}
+
+impl<T> Binding<T> {
+    /// Get the current value
+    pub fn get(&self) -> T {
+        self.source.get()
+    }
+
+    /// Get the value if it changed since the last call
+    ///
+    /// The first call always returns the value.
+    pub fn changed(&self) -> Option<T> {
+        let version = self.source.version();
+        if self.seen_version.get() == Some(version) {
+            return None;
+        }
+        self.seen_version.set(Some(version));
+        Some(self.source.get())
+    }
+
+    /// Write a value back to the source
+    ///
+    /// Returns false for read-only sources such as computed values. The
+    /// written value is not reported by `changed`.
+    pub fn set(&self, value: T) -> bool {
+        if !self.source.set(value) {
+            return false;
+        }
+        self.seen_version.set(Some(self.source.version()));
+        true
+    }
+}
+
+impl<T: Clone + 'static> From<Signal<T>> for Binding<T> {
+    fn from(signal: Signal<T>) -> Self {
+        Self {
+            source: Rc::new(signal),
+            seen_version: Cell::new(None),
+        }
+    }
+}
+
+impl<T: Clone + 'static> From<&Signal<T>> for Binding<T> {
+    fn from(signal: &Signal<T>) -> Self {
+        Self::from(signal.clone())
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> From<Computed<T>> for Binding<T> {
+    fn from(computed: Computed<T>) -> Self {
+        Self {
+            source: Rc::new(computed),
+            seen_version: Cell::new(None),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> From<&Computed<T>> for Binding<T> {
+    fn from(computed: &Computed<T>) -> Self {
+        Self::from(computed.clone())
+    }
+}
+
+impl<T> Clone for Binding<T> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            seen_version: Cell::new(self.seen_version.get()),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Binding<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Binding")
+            .field("version", &self.source.version())
+            .field("seen_version", &self.seen_version.get())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binding_changed() {
+        let text = Signal::new("0".to_string());
+        let binding = Binding::from(&text);
+
+        assert_eq!(binding.changed(), Some("0".to_string()));
+        assert_eq!(binding.changed(), None);
+
+        text.set("42".to_string());
+        assert_eq!(binding.changed(), Some("42".to_string()));
+    }
+
+    #[test]
+    fn test_two_way_binding() {
+        let value = Signal::new(String::new());
+        let binding = Binding::from(&value);
+        binding.changed();
+
+        assert!(binding.set("typed".to_string()));
+        assert_eq!(value.get(), "typed");
+        assert_eq!(binding.changed(), None); // Own writes are not echoed back
+
+        let text = value.clone();
+        let length: Binding<usize> = Computed::new(move || text.with(|s| s.len())).into();
+        assert!(!length.set(1));
+        assert_eq!(length.get(), 5);
+    }
+}
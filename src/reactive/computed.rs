@@ -0,0 +1,193 @@
+//! Values derived from signals
+
+use crate::reactive::{track, with_tracking, Subscription, Tracked};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+struct ComputedInner<T> {
+    compute: Box<dyn Fn() -> T>,
+    value: RefCell<Option<T>>,
+    dependencies: RefCell<Vec<(Rc<dyn Tracked>, u64)>>,
+    version: Cell<u64>,
+}
+
+/// Value computed from signals and other computed values
+///
+/// Dependencies are discovered automatically from the values read while
+/// computing. The result is cached and only recomputed after a dependency
+/// changed, and the version only changes when the result is different.
+pub struct Computed<T> {
+    inner: Rc<ComputedInner<T>>,
+}
+
+impl<T: Clone + PartialEq + 'static> Computed<T> {
+    /// Create a computed value
+    pub fn new<F: Fn() -> T + 'static>(compute: F) -> Self {
+        Self {
+            inner: Rc::new(ComputedInner {
+                compute: Box::new(compute),
+                value: RefCell::new(None),
+                dependencies: RefCell::new(Vec::new()),
+                version: Cell::new(0),
+            }),
+        }
+    }
+
+    /// Get the current value, recomputing it if a dependency changed
+    pub fn get(&self) -> T {
+        track(Rc::new(self.clone()));
+        self.refresh();
+        self.inner.value.borrow().clone().expect("computed value after refresh")
+    }
+
+    /// Get a number that changes whenever the value changes
+    pub fn version(&self) -> u64 {
+        self.refresh();
+        self.inner.version.get()
+    }
+
+    /// Call `f` with the new value whenever it changes
+    ///
+    /// Dependencies are captured when subscribing. The subscription ends
+    /// when the returned handle is dropped.
+    pub fn subscribe<F: Fn(&T) + 'static>(&self, f: F) -> Subscription {
+        self.refresh();
+        let computed = self.clone();
+        let last_version = Cell::new(self.inner.version.get());
+        let callback: Rc<dyn Fn()> = Rc::new(move || {
+            let version = computed.version();
+            if version != last_version.get() {
+                last_version.set(version);
+                if let Some(value) = computed.inner.value.borrow().clone() {
+                    f(&value);
+                }
+            }
+        });
+
+        let subscriptions: Vec<Subscription> = self
+            .inner
+            .dependencies
+            .borrow()
+            .iter()
+            .map(|(dependency, _)| dependency.subscribe_any(callback.clone()))
+            .collect();
+        Subscription::new(move || drop(subscriptions))
+    }
+
+    /// Recompute the value if it was never computed or a dependency changed
+    fn refresh(&self) {
+        let stale = self.inner.value.borrow().is_none()
+            || self
+                .inner
+                .dependencies
+                .borrow()
+                .iter()
+                .any(|(dependency, version)| dependency.version() != *version);
+        if !stale {
+            return;
+        }
+
+        let (value, dependencies) = with_tracking(|| (self.inner.compute)());
+        *self.inner.dependencies.borrow_mut() = dependencies
+            .into_iter()
+            .map(|dependency| {
+                let version = dependency.version();
+                (dependency, version)
+            })
+            .collect();
+
+        let mut cached = self.inner.value.borrow_mut();
+        if cached.as_ref() != Some(&value) {
+            self.inner.version.set(self.inner.version.get() + 1);
+            *cached = Some(value);
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Tracked for Computed<T> {
+    fn version(&self) -> u64 {
+        Computed::version(self)
+    }
+
+    fn subscribe_any(&self, f: Rc<dyn Fn()>) -> Subscription {
+        self.subscribe(move |_| f())
+    }
+}
+
+impl<T> Clone for Computed<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Computed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Computed")
+            .field("value", &self.inner.value.borrow())
+            .field("version", &self.inner.version.get())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reactive::Signal;
+
+    #[test]
+    fn test_computed_tracks_dependencies() {
+        let a = Signal::new(2);
+        let b = Signal::new(3);
+        let calls = Rc::new(Cell::new(0));
+
+        let (a_ref, b_ref, calls_ref) = (a.clone(), b.clone(), calls.clone());
+        let sum = Computed::new(move || {
+            calls_ref.set(calls_ref.get() + 1);
+            a_ref.get() + b_ref.get()
+        });
+
+        assert_eq!(sum.get(), 5);
+        assert_eq!(sum.get(), 5);
+        assert_eq!(calls.get(), 1); // Cached until a dependency changes
+
+        a.set(10);
+        assert_eq!(sum.get(), 13);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_computed_version_changes_with_value() {
+        let n = Signal::new(4);
+        let n_ref = n.clone();
+        let is_even = Computed::new(move || n_ref.get() % 2 == 0);
+        let version = is_even.version();
+
+        n.set(6);
+        assert_eq!(is_even.version(), version); // Same result
+
+        n.set(7);
+        assert!(is_even.version() > version);
+        assert!(!is_even.get());
+    }
+
+    #[test]
+    fn test_nested_computed_subscription() {
+        let n = Signal::new(1);
+        let n_ref = n.clone();
+        let doubled = Computed::new(move || n_ref.get() * 2);
+        let doubled_ref = doubled.clone();
+        let label = Computed::new(move || format!("{}", doubled_ref.get()));
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_ref = seen.clone();
+        let _subscription = label.subscribe(move |value| seen_ref.borrow_mut().push(value.clone()));
+
+        n.set(2);
+        n.set(2);
+        n.set(5);
+        assert_eq!(*seen.borrow(), vec!["4".to_string(), "10".to_string()]);
+    }
+}
@@ -0,0 +1,48 @@
+//! Reactive state module
+//!
+//! This module contains signals holding shared state, computed values
+//! derived from them, and bindings that connect both to component
+//! properties. Bound components pick up changes on their next update and
+//! mark themselves dirty so the application re-renders them.
+
+pub mod binding;
+pub mod computed;
+pub mod signal;
+
+pub use binding::Binding;
+pub use computed::Computed;
+pub use signal::{Signal, Subscription};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Reactive value that can be observed without knowing its type
+pub(crate) trait Tracked {
+    /// Get a number that changes whenever the value changes
+    fn version(&self) -> u64;
+
+    /// Call `f` whenever the value changes
+    fn subscribe_any(&self, f: Rc<dyn Fn()>) -> Subscription;
+}
+
+thread_local! {
+    /// Dependencies read by the computed values currently being evaluated
+    static TRACKING: RefCell<Vec<Vec<Rc<dyn Tracked>>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Record a read of `dependency` by the computed value being evaluated
+pub(crate) fn track(dependency: Rc<dyn Tracked>) {
+    TRACKING.with(|tracking| {
+        if let Some(frame) = tracking.borrow_mut().last_mut() {
+            frame.push(dependency);
+        }
+    });
+}
+
+/// Run `f`, returning its result and every reactive value it read
+pub(crate) fn with_tracking<R>(f: impl FnOnce() -> R) -> (R, Vec<Rc<dyn Tracked>>) {
+    TRACKING.with(|tracking| tracking.borrow_mut().push(Vec::new()));
+    let result = f();
+    let dependencies = TRACKING.with(|tracking| tracking.borrow_mut().pop().unwrap_or_default());
+    (result, dependencies)
+}
@@ -0,0 +1,216 @@
+//! Signals holding shared, observable state
+
+use crate::reactive::{track, Tracked};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+/// Callback receiving the new value of a signal
+type Subscriber<T> = Rc<dyn Fn(&T)>;
+
+struct SignalInner<T> {
+    value: T,
+    version: u64,
+    next_id: u64,
+    subscribers: Vec<(u64, Subscriber<T>)>,
+}
+
+/// Shared value that notifies subscribers and bindings when it changes
+///
+/// Cloning a signal creates another handle to the same value.
+pub struct Signal<T> {
+    inner: Rc<RefCell<SignalInner<T>>>,
+}
+
+impl<T: Clone + 'static> Signal<T> {
+    /// Create a new signal
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(SignalInner {
+                value,
+                version: 0,
+                next_id: 0,
+                subscribers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Get the current value
+    pub fn get(&self) -> T {
+        self.with(|value| value.clone())
+    }
+
+    /// Read the current value without cloning it
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        track(Rc::new(self.clone()));
+        f(&self.inner.borrow().value)
+    }
+
+    /// Set a new value and notify subscribers
+    pub fn set(&self, value: T) {
+        self.update(|current| *current = value);
+    }
+
+    /// Modify the value in place and notify subscribers
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            f(&mut inner.value);
+            inner.version += 1;
+        }
+        self.notify();
+    }
+
+    /// Get a number that changes whenever the value is set
+    pub fn version(&self) -> u64 {
+        self.inner.borrow().version
+    }
+
+    /// Call `f` with the new value whenever the signal is set
+    ///
+    /// The subscription ends when the returned handle is dropped.
+    pub fn subscribe<F: Fn(&T) + 'static>(&self, f: F) -> Subscription {
+        let id = {
+            let mut inner = self.inner.borrow_mut();
+            inner.next_id += 1;
+            let id = inner.next_id;
+            inner.subscribers.push((id, Rc::new(f)));
+            id
+        };
+
+        let weak: Weak<RefCell<SignalInner<T>>> = Rc::downgrade(&self.inner);
+        Subscription::new(move || {
+            if let Some(inner) = weak.upgrade() {
+                inner.borrow_mut().subscribers.retain(|(subscriber, _)| *subscriber != id);
+            }
+        })
+    }
+
+    /// Call every subscriber with the current value
+    fn notify(&self) {
+        // Subscribers run without the signal borrowed so they can read it
+        let (value, subscribers): (T, Vec<Subscriber<T>>) = {
+            let inner = self.inner.borrow();
+            let subscribers = inner.subscribers.iter().map(|(_, f)| f.clone()).collect();
+            (inner.value.clone(), subscribers)
+        };
+        for subscriber in subscribers {
+            subscriber(&value);
+        }
+    }
+}
+
+impl<T: Clone + 'static> Tracked for Signal<T> {
+    fn version(&self) -> u64 {
+        Signal::version(self)
+    }
+
+    fn subscribe_any(&self, f: Rc<dyn Fn()>) -> Subscription {
+        self.subscribe(move |_| f())
+    }
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Default + 'static> Default for Signal<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Signal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("Signal")
+            .field("value", &inner.value)
+            .field("version", &inner.version)
+            .finish()
+    }
+}
+
+/// Handle keeping a subscription alive
+#[must_use = "the subscription ends when the handle is dropped"]
+pub struct Subscription {
+    unsubscribe: Option<Box<dyn FnOnce()>>,
+}
+
+impl Subscription {
+    /// Create a subscription calling `unsubscribe` when it ends
+    pub(crate) fn new<F: FnOnce() + 'static>(unsubscribe: F) -> Self {
+        Self {
+            unsubscribe: Some(Box::new(unsubscribe)),
+        }
+    }
+
+    /// Keep the subscription alive for as long as the value exists
+    pub fn detach(mut self) {
+        self.unsubscribe = None;
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
+
+impl fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscription")
+            .field("active", &self.unsubscribe.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_signal_get_set() {
+        let count = Signal::new(1);
+        let shared = count.clone();
+        assert_eq!(count.version(), 0);
+
+        shared.set(5);
+        assert_eq!(count.get(), 5);
+        assert_eq!(count.version(), 1);
+
+        count.update(|value| *value += 1);
+        assert_eq!(shared.get(), 6);
+    }
+
+    #[test]
+    fn test_signal_subscription() {
+        let name = Signal::new(String::from("a"));
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_ref = seen.clone();
+
+        let subscription = name.subscribe(move |value: &String| seen_ref.borrow_mut().push(value.clone()));
+        name.set("b".to_string());
+        drop(subscription);
+        name.set("c".to_string());
+
+        assert_eq!(*seen.borrow(), vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn test_subscriber_can_read_signal() {
+        let value = Signal::new(0);
+        let reads = Rc::new(Cell::new(0));
+        let (reader, reads_ref) = (value.clone(), reads.clone());
+
+        value.subscribe(move |_| reads_ref.set(reader.get())).detach();
+        value.set(3);
+        assert_eq!(reads.get(), 3);
+    }
+}
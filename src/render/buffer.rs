@@ -1,7 +1,7 @@
 //! Render buffer for double buffering
 
-use crate::render::{Result, Renderer};
-use crate::style::Style;
+use crate::render::{Result, Renderer, Sanitizer};
+use crate::style::{Color, ColorDepth, ColorVision, Span, Style, Symbols};
 use crossterm::{
     execute, queue,
     style::{Print, ResetColor},
@@ -9,6 +9,10 @@ use crossterm::{
     Command,
 };
 use std::io::{stdout, Write};
+use unicode_width::UnicodeWidthChar;
+
+/// Character of the cell covered by the right half of a wide character
+const CONTINUATION: char = '\0';
 
 /// A cell in the render buffer
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -21,6 +25,13 @@ pub struct BufferCell {
     pub dirty: bool,
 }
 
+impl BufferCell {
+    /// Check if the cell is covered by the wide character to its left
+    pub fn is_continuation(&self) -> bool {
+        self.ch == CONTINUATION
+    }
+}
+
 impl Default for BufferCell {
     fn default() -> Self {
         Self {
@@ -42,6 +53,14 @@ pub struct RenderBuffer {
     cells: Vec<BufferCell>,
     /// Previous buffer state for diffing
     prev_cells: Vec<BufferCell>,
+    /// Color vision deficiency simulated on output
+    color_vision: Option<ColorVision>,
+    /// Colors the terminal can display
+    color_depth: ColorDepth,
+    /// Glyphs used for borders and decorations
+    symbols: Symbols,
+    /// Rules for control characters and tabs in drawn text
+    sanitizer: Sanitizer,
 }
 
 impl RenderBuffer {
@@ -56,6 +75,10 @@ impl RenderBuffer {
             height,
             cells,
             prev_cells,
+            color_vision: None,
+            color_depth: ColorDepth::TrueColor,
+            symbols: Symbols::default(),
+            sanitizer: Sanitizer::default(),
         }
     }
 
@@ -105,6 +128,62 @@ impl RenderBuffer {
         }
     }
 
+    /// Get the color vision deficiency simulated on output
+    pub fn color_vision(&self) -> Option<ColorVision> {
+        self.color_vision
+    }
+
+    /// Simulate a color vision deficiency when writing to the terminal
+    ///
+    /// Cells keep their original colors; only the output is transformed.
+    /// Every cell is written again on the next render.
+    pub fn set_color_vision(&mut self, vision: Option<ColorVision>) {
+        if self.color_vision != vision {
+            self.color_vision = vision;
+            self.invalidate();
+        }
+    }
+
+    /// Get the color depth output is downsampled to
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Downsample colors to what the terminal can display
+    ///
+    /// Like `set_color_vision`, only the output is affected.
+    pub fn set_color_depth(&mut self, depth: ColorDepth) {
+        if self.color_depth != depth {
+            self.color_depth = depth;
+            self.invalidate();
+        }
+    }
+
+    /// Get the glyphs used for borders and decorations
+    pub fn symbols(&self) -> &Symbols {
+        &self.symbols
+    }
+
+    /// Set the glyphs used for borders and decorations
+    pub fn set_symbols(&mut self, symbols: Symbols) {
+        self.symbols = symbols;
+    }
+
+    /// Get the rules for control characters and tabs in drawn text
+    pub fn sanitizer(&self) -> Sanitizer {
+        self.sanitizer
+    }
+
+    /// Set the rules for control characters and tabs in drawn text
+    pub fn set_sanitizer(&mut self, sanitizer: Sanitizer) {
+        self.sanitizer = sanitizer;
+    }
+
+    /// Forget what is on screen so every cell is written on the next render
+    fn invalidate(&mut self) {
+        self.prev_cells.fill(BufferCell { ch: '\0', ..BufferCell::default() });
+    }
+
     /// Get a cell at the specified position
     pub fn get_cell(&self, x: u16, y: u16) -> Option<&BufferCell> {
         if x < self.width && y < self.height {
@@ -126,36 +205,110 @@ impl RenderBuffer {
     }
 
     /// Set a cell at the specified position
+    ///
+    /// Control characters are replaced as the sanitizer dictates. A wide
+    /// character also covers the next cell, or is drawn as a space if it
+    /// doesn't fit, and zero-width characters are skipped. Wide characters
+    /// partly overwritten lose their other half.
     pub fn set_cell(&mut self, x: u16, y: u16, ch: char, style: &Style) -> Result<()> {
-        if let Some(cell) = self.get_cell_mut(x, y) {
-            let new_cell = BufferCell {
-                ch,
-                style: *style,
-                dirty: cell.ch != ch || cell.style != *style,
-            };
-            *cell = new_cell;
+        let Some(mut ch) = self.sanitizer.sanitize_char(ch) else {
+            return Ok(());
+        };
+        let mut width = ch.width().unwrap_or(0);
+        if width == 0 || x >= self.width || y >= self.height {
+            return Ok(());
+        }
+        if width > 1 && x + 1 >= self.width {
+            (ch, width) = (' ', 1);
+        }
+
+        self.split_wide(x, y);
+        self.put(x, y, ch, style);
+        if width > 1 {
+            self.split_wide(x + 1, y);
+            self.put(x + 1, y, CONTINUATION, style);
         }
         Ok(())
     }
 
+    /// Write a character into a cell as is
+    fn put(&mut self, x: u16, y: u16, ch: char, style: &Style) {
+        if let Some(cell) = self.get_cell_mut(x, y) {
+            cell.dirty = cell.ch != ch || cell.style != *style;
+            cell.ch = ch;
+            cell.style = *style;
+        }
+    }
+
+    /// Blank the other half of a wide character covering a cell
+    fn split_wide(&mut self, x: u16, y: u16) {
+        let Some(cell) = self.get_cell(x, y).copied() else {
+            return;
+        };
+        let other = if cell.is_continuation() {
+            x.checked_sub(1)
+        } else if cell.ch.width().unwrap_or(0) > 1 {
+            Some(x + 1)
+        } else {
+            None
+        };
+        if let Some(other) = other {
+            let style = self.get_cell(other, y).map_or(cell.style, |cell| cell.style);
+            self.put(other, y, ' ', &style);
+        }
+    }
+
     /// Draw a character at the specified position
     pub fn draw_char(&mut self, x: u16, y: u16, ch: char, style: &Style) -> Result<()> {
         self.set_cell(x, y, ch, style)
     }
 
     /// Draw text at the specified position
+    ///
+    /// The text is sanitized first, so control characters cannot reach the
+    /// terminal and tabs expand to spaces.
     pub fn draw_text(&mut self, x: u16, y: u16, text: &str, style: &Style) -> Result<()> {
+        let text = self.sanitizer.sanitize(text, x);
+        self.draw_sanitized(x, y, &text, style)
+    }
+
+    /// Draw text that has already been sanitized
+    ///
+    /// Wide characters take two cells, and one that would be cut off at the
+    /// right edge is left out.
+    pub(crate) fn draw_sanitized(&mut self, x: u16, y: u16, text: &str, style: &Style) -> Result<()> {
         let mut current_x = x;
         for ch in text.chars() {
-            if current_x >= self.width {
+            let width = ch.width().unwrap_or(0) as u16;
+            if current_x.saturating_add(width) > self.width {
                 break;
             }
             self.draw_char(current_x, y, ch, style)?;
-            current_x += 1;
+            current_x += width;
         }
         Ok(())
     }
 
+    /// Draw spans in their own styles, clipped to `max_width` columns
+    ///
+    /// Returns the number of columns drawn.
+    pub fn draw_spans(&mut self, x: u16, y: u16, spans: &[Span], max_width: u16) -> Result<u16> {
+        let end = x.saturating_add(max_width).min(self.width);
+        let mut current_x = x;
+        for span in spans {
+            let content = self.sanitizer.sanitize(&span.content, current_x);
+            for ch in content.chars() {
+                let width = ch.width().unwrap_or(0) as u16;
+                if current_x.saturating_add(width) > end {
+                    return Ok(current_x - x);
+                }
+                self.draw_char(current_x, y, ch, &span.style)?;
+                current_x += width;
+            }
+        }
+        Ok(current_x - x)
+    }
+
     /// Draw a rectangle
     pub fn draw_rect(&mut self, x: u16, y: u16, width: u16, height: u16, style: &Style) -> Result<()> {
         for row in 0..height {
@@ -176,22 +329,24 @@ impl RenderBuffer {
             return Ok(());
         }
 
+        let border = self.symbols.border;
+
         // Draw corners
-        self.draw_char(x, y, '┌', style)?;
-        self.draw_char(x + width - 1, y, '┐', style)?;
-        self.draw_char(x, y + height - 1, '└', style)?;
-        self.draw_char(x + width - 1, y + height - 1, '┘', style)?;
+        self.draw_char(x, y, border.top_left, style)?;
+        self.draw_char(x + width - 1, y, border.top_right, style)?;
+        self.draw_char(x, y + height - 1, border.bottom_left, style)?;
+        self.draw_char(x + width - 1, y + height - 1, border.bottom_right, style)?;
 
         // Draw horizontal lines
         for col in 1..width - 1 {
-            self.draw_char(x + col, y, '─', style)?;
-            self.draw_char(x + col, y + height - 1, '─', style)?;
+            self.draw_char(x + col, y, border.horizontal, style)?;
+            self.draw_char(x + col, y + height - 1, border.horizontal, style)?;
         }
 
         // Draw vertical lines
         for row in 1..height - 1 {
-            self.draw_char(x, y + row, '│', style)?;
-            self.draw_char(x + width - 1, y + row, '│', style)?;
+            self.draw_char(x, y + row, border.vertical, style)?;
+            self.draw_char(x + width - 1, y + row, border.vertical, style)?;
         }
 
         Ok(())
@@ -211,20 +366,55 @@ impl RenderBuffer {
         Ok(())
     }
 
+    /// Darken the colors of an area, as under a drop shadow
+    ///
+    /// Colors left to the terminal's defaults become black and dark gray.
+    pub fn darken_area(&mut self, x: u16, y: u16, width: u16, height: u16, amount: f32) -> Result<()> {
+        for row in y..y.saturating_add(height) {
+            for col in x..x.saturating_add(width) {
+                if let Some(cell) = self.get_cell_mut(col, row) {
+                    let style = Style {
+                        foreground: Some(cell.style.foreground.map_or(Color::BrightBlack, |color| color.darken(amount))),
+                        background: Some(cell.style.background.map_or(Color::Black, |color| color.darken(amount))),
+                        ..cell.style
+                    };
+                    cell.dirty |= cell.style != style;
+                    cell.style = style;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the cells that changed since the last render as (x, y, cell)
+    pub fn diff(&self) -> Vec<(u16, u16, &BufferCell)> {
+        let width = self.width.max(1) as usize;
+        self.cells
+            .iter()
+            .zip(&self.prev_cells)
+            .enumerate()
+            .filter(|(_, (cell, prev_cell))| cell.ch != prev_cell.ch || cell.style != prev_cell.style)
+            .map(|(index, (cell, _))| ((index % width) as u16, (index / width) as u16, cell))
+            .collect()
+    }
+
     /// Render the buffer to the terminal
+    ///
+    /// Only cells that changed since the previous call are written.
     pub fn render_to_terminal(&mut self) -> Result<()> {
         let mut stdout = stdout();
         let mut current_style = None;
         let mut x = 0u16;
         let mut y = 0u16;
 
-        // Swap buffers
-        std::mem::swap(&mut self.cells, &mut self.prev_cells);
-
         // Render only dirty cells
         for (index, cell) in self.cells.iter().enumerate() {
             let prev_cell = &self.prev_cells[index];
             
+            // The wide character to the left is printed over continuations
+            if cell.is_continuation() {
+                continue;
+            }
             if cell.ch != prev_cell.ch || cell.style != prev_cell.style {
                 let cell_x = (index as u16) % self.width;
                 let cell_y = (index as u16) / self.width;
@@ -238,16 +428,23 @@ impl RenderBuffer {
 
                 // Apply style if changed
                 if Some(cell.style) != current_style {
-                    let commands = Renderer::style_to_commands(&cell.style);
+                    let style = match self.color_vision {
+                        Some(vision) => vision.simulate_style(&cell.style),
+                        None => cell.style,
+                    };
+                    let style = self.color_depth.downsample_style(&style);
+                    let commands = Renderer::style_to_commands(&style);
                     for command in commands {
                         queue!(stdout, command)?;
                     }
                     current_style = Some(cell.style);
                 }
 
-                // Print character
-                queue!(stdout, Print(cell.ch))?;
-                x += 1;
+                // Print character, guarding against control characters
+                // written into cells directly
+                let ch = if cell.ch.is_control() { ' ' } else { cell.ch };
+                queue!(stdout, Print(ch))?;
+                x += ch.width().unwrap_or(1).max(1) as u16;
                 if x >= self.width {
                     x = 0;
                     y += 1;
@@ -260,17 +457,22 @@ impl RenderBuffer {
             queue!(stdout, ResetColor)?;
         }
 
+        // Remember what is on screen for the next frame
+        self.prev_cells.copy_from_slice(&self.cells);
+
         // Flush output
         stdout.flush()?;
         Ok(())
     }
 
     /// Get the buffer as a string (for debugging)
+    ///
+    /// Wide characters appear once, so each row is as wide as on screen.
     pub fn as_string(&self) -> String {
         let mut result = String::new();
         for y in 0..self.height {
             for x in 0..self.width {
-                if let Some(cell) = self.get_cell(x, y) {
+                if let Some(cell) = self.get_cell(x, y).filter(|cell| !cell.is_continuation()) {
                     result.push(cell.ch);
                 }
             }
@@ -286,7 +488,7 @@ impl RenderBuffer {
         let mut result = String::new();
         for y in 0..self.height {
             for x in 0..self.width {
-                if let Some(cell) = self.get_cell(x, y) {
+                if let Some(cell) = self.get_cell(x, y).filter(|cell| !cell.is_continuation()) {
                     // Add ANSI color codes for debugging
                     if let Some(fg) = cell.style.foreground {
                         result.push_str(&format!("\x1b[38;5;{}m", fg.as_ansi_value()));
@@ -330,7 +532,8 @@ impl RenderBuffer {
                 let dest_cell_x = dest_x + col;
                 let dest_cell_y = dest_y + row;
 
-                if let Some(src_cell) = src.get_cell(src_cell_x, src_cell_y) {
+                // Copying the wide character fills its continuation
+                if let Some(src_cell) = src.get_cell(src_cell_x, src_cell_y).filter(|cell| !cell.is_continuation()) {
                     if dest_cell_x < self.width && dest_cell_y < self.height {
                         self.set_cell(dest_cell_x, dest_cell_y, src_cell.ch, &src_cell.style)?;
                     }
@@ -387,6 +590,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_draw_spans() {
+        let mut buffer = RenderBuffer::new(6, 1);
+        let red = Style::default().with_foreground(Color::Red);
+        let spans = [Span::raw("ab"), Span::styled("cdef", red)];
+
+        assert_eq!(buffer.draw_spans(0, 0, &spans, 4).unwrap(), 4);
+        assert_eq!(buffer.as_string(), "abcd  ");
+        assert_eq!(buffer.get_cell(2, 0).unwrap().style, red);
+
+        // Clipped at the right edge of the buffer
+        assert_eq!(buffer.draw_spans(4, 0, &spans, 10).unwrap(), 2);
+        assert_eq!(buffer.as_string(), "abcdab");
+    }
+
+    #[test]
+    fn test_wide_characters() {
+        let mut buffer = RenderBuffer::new(5, 1);
+        buffer.draw_text(0, 0, "日本語", &Style::default()).unwrap();
+        assert_eq!(buffer.as_string(), "日本 ");
+        assert!(buffer.get_cell(1, 0).unwrap().is_continuation());
+
+        // Overwriting either half blanks the other
+        buffer.draw_char(1, 0, 'a', &Style::default()).unwrap();
+        buffer.draw_char(2, 0, 'b', &Style::default()).unwrap();
+        assert_eq!(buffer.as_string(), " ab  ");
+
+        // A wide character doesn't fit in the last column
+        buffer.draw_char(4, 0, '語', &Style::default()).unwrap();
+        assert_eq!(buffer.as_string(), " ab  ");
+
+        buffer.clear();
+        assert_eq!(buffer.draw_spans(0, 0, &[Span::raw("日本")], 3).unwrap(), 2);
+        assert_eq!(buffer.as_string(), "日   ");
+    }
+
+    #[test]
+    fn test_sanitized_text() {
+        use crate::render::ControlChars;
+
+        let mut buffer = RenderBuffer::new(12, 1);
+        buffer.draw_text(0, 0, "a\x1b[2J\tb", &Style::default()).unwrap();
+        assert_eq!(buffer.as_string(), "a^[[2J  b   ");
+
+        // Already sanitized text is drawn as given
+        buffer.clear();
+        buffer.draw_sanitized(2, 0, "^[ b", &Style::default()).unwrap();
+        assert_eq!(buffer.as_string(), "  ^[ b      ");
+
+        buffer.set_sanitizer(Sanitizer::new().with_control_chars(ControlChars::Strip).with_tab_width(4));
+        buffer.clear();
+        buffer.draw_spans(1, 0, &[Span::raw("x\x07"), Span::raw("\ty\nz")], 12).unwrap();
+        buffer.draw_char(11, 0, '\x1b', &Style::default()).unwrap();
+        assert_eq!(buffer.as_string(), " x  y z     ");
+    }
+
     #[test]
     fn test_rect_operations() {
         let mut buffer = RenderBuffer::new(10, 5);
@@ -403,6 +662,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_diff_only_changed_cells() {
+        let mut buffer = RenderBuffer::new(4, 2);
+        assert!(buffer.diff().is_empty());
+
+        buffer.draw_text(1, 1, "ab", &Style::default()).unwrap();
+        let changed: Vec<_> = buffer.diff().into_iter().map(|(x, y, cell)| (x, y, cell.ch)).collect();
+        assert_eq!(changed, vec![(1, 1, 'a'), (2, 1, 'b')]);
+
+        // Once presented, redrawing the same content produces no changes
+        buffer.prev_cells.copy_from_slice(&buffer.cells);
+        buffer.clear();
+        buffer.draw_text(1, 1, "ab", &Style::default()).unwrap();
+        assert!(buffer.diff().is_empty());
+    }
+
+    #[test]
+    fn test_color_vision_redraws_everything() {
+        let mut buffer = RenderBuffer::new(3, 1);
+        buffer.draw_text(0, 0, "abc", &Style::default().with_foreground(Color::Red)).unwrap();
+        buffer.prev_cells.copy_from_slice(&buffer.cells);
+        assert!(buffer.diff().is_empty());
+
+        buffer.set_color_vision(Some(ColorVision::Protanopia));
+        assert_eq!(buffer.color_vision(), Some(ColorVision::Protanopia));
+        assert_eq!(buffer.diff().len(), 3);
+        assert_eq!(buffer.get_cell(0, 0).unwrap().style.foreground, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_color_depth() {
+        let mut buffer = RenderBuffer::new(1, 1);
+        assert_eq!(buffer.color_depth(), ColorDepth::TrueColor);
+        buffer.prev_cells.copy_from_slice(&buffer.cells);
+        buffer.set_color_depth(ColorDepth::Ansi16);
+        assert_eq!(buffer.color_depth(), ColorDepth::Ansi16);
+        assert_eq!(buffer.diff().len(), 1);
+    }
+
     #[test]
     fn test_border_operations() {
         let mut buffer = RenderBuffer::new(10, 5);
@@ -421,6 +719,12 @@ mod tests {
         assert_eq!(buffer.get_cell(5, 3).unwrap().ch, '─');
         assert_eq!(buffer.get_cell(1, 2).unwrap().ch, '│');
         assert_eq!(buffer.get_cell(8, 2).unwrap().ch, '│');
+
+        buffer.set_symbols(Symbols::ascii());
+        buffer.draw_border(1, 1, 8, 3, &style).unwrap();
+        assert_eq!(buffer.get_cell(1, 1).unwrap().ch, '+');
+        assert_eq!(buffer.get_cell(5, 3).unwrap().ch, '-');
+        assert_eq!(buffer.get_cell(8, 2).unwrap().ch, '|');
     }
 
     #[test]
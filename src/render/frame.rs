@@ -0,0 +1,79 @@
+//! Frame for immediate-mode drawing
+
+use crate::render::{Rect, RenderBuffer, Result};
+use crate::style::Style;
+use crate::widgets::Widget;
+
+/// Single frame drawn by `Renderer::draw`
+///
+/// The frame starts out blank every time. Widgets rendered into it are
+/// compared against the previous frame when presented, so only changed
+/// cells reach the terminal.
+#[derive(Debug)]
+pub struct Frame<'a> {
+    buffer: &'a mut RenderBuffer,
+    cursor: Option<(u16, u16)>,
+}
+
+impl<'a> Frame<'a> {
+    /// Create a frame drawing into a buffer
+    pub fn new(buffer: &'a mut RenderBuffer) -> Self {
+        Self { buffer, cursor: None }
+    }
+
+    /// Get the full area of the frame
+    pub fn area(&self) -> Rect {
+        Rect::new(0, 0, self.buffer.width(), self.buffer.height())
+    }
+
+    /// Render a widget into an area
+    ///
+    /// The area is clipped to the frame.
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) -> Result<()> {
+        let area = area.intersection(&self.area());
+        if area.is_empty() {
+            return Ok(());
+        }
+        widget.render(area, self.buffer)
+    }
+
+    /// Draw text at the specified position
+    pub fn draw_text(&mut self, x: u16, y: u16, text: &str, style: &Style) -> Result<()> {
+        self.buffer.draw_text(x, y, text, style)
+    }
+
+    /// Show the cursor at a position after the frame is presented
+    pub fn set_cursor(&mut self, x: u16, y: u16) {
+        self.cursor = Some((x, y));
+    }
+
+    /// Get the requested cursor position
+    pub fn cursor(&self) -> Option<(u16, u16)> {
+        self.cursor
+    }
+
+    /// Get the underlying buffer
+    pub fn buffer_mut(&mut self) -> &mut RenderBuffer {
+        self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::Block;
+
+    #[test]
+    fn test_frame_clips_widgets() {
+        let mut buffer = RenderBuffer::new(6, 3);
+        let mut frame = Frame::new(&mut buffer);
+        assert_eq!(frame.area(), Rect::new(0, 0, 6, 3));
+
+        frame.render_widget(Block::bordered(), Rect::new(2, 1, 10, 10)).unwrap();
+        frame.set_cursor(1, 1);
+        assert_eq!(frame.cursor(), Some((1, 1)));
+
+        // The block is shrunk to the part of the area inside the frame
+        assert_eq!(buffer.as_string(), "      \n  ┌──┐\n  └──┘");
+    }
+}
@@ -6,10 +6,16 @@
 pub mod renderer;
 pub mod context;
 pub mod buffer;
+pub mod frame;
+pub mod rect;
+pub mod sanitize;
 
 pub use renderer::Renderer;
 pub use context::RenderContext;
 pub use buffer::RenderBuffer;
+pub use frame::Frame;
+pub use rect::Rect;
+pub use sanitize::{ControlChars, Sanitizer};
 
 /// Render-wide error type
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,120 @@
+//! Rectangular screen areas
+
+/// Rectangular area of the screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect {
+    /// Left column
+    pub x: u16,
+    /// Top row
+    pub y: u16,
+    /// Width in columns
+    pub width: u16,
+    /// Height in rows
+    pub height: u16,
+}
+
+impl Rect {
+    /// Create a new rectangle
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Get the number of cells in the rectangle
+    pub fn area(&self) -> u32 {
+        self.width as u32 * self.height as u32
+    }
+
+    /// Check if the rectangle has no cells
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Get the column after the right edge
+    pub fn right(&self) -> u16 {
+        self.x.saturating_add(self.width)
+    }
+
+    /// Get the row after the bottom edge
+    pub fn bottom(&self) -> u16 {
+        self.y.saturating_add(self.height)
+    }
+
+    /// Check if a position lies inside the rectangle
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    /// Shrink the rectangle by `margin` cells on every side
+    pub fn inner(&self, margin: u16) -> Self {
+        self.inset(margin, margin, margin, margin)
+    }
+
+    /// Shrink the rectangle by individual amounts on each side
+    pub fn inset(&self, top: u16, right: u16, bottom: u16, left: u16) -> Self {
+        let width = self.width.saturating_sub(left.saturating_add(right));
+        let height = self.height.saturating_sub(top.saturating_add(bottom));
+        Self {
+            x: self.x.saturating_add(left.min(self.width)),
+            y: self.y.saturating_add(top.min(self.height)),
+            width,
+            height,
+        }
+    }
+
+    /// Get the overlapping part of two rectangles
+    pub fn intersection(&self, other: &Rect) -> Self {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        Self {
+            x,
+            y,
+            width: right.saturating_sub(x),
+            height: bottom.saturating_sub(y),
+        }
+    }
+
+    /// Get the rectangle as an (x, y, width, height) tuple
+    pub fn as_tuple(&self) -> (u16, u16, u16, u16) {
+        (self.x, self.y, self.width, self.height)
+    }
+}
+
+impl From<(u16, u16, u16, u16)> for Rect {
+    fn from((x, y, width, height): (u16, u16, u16, u16)) -> Self {
+        Self::new(x, y, width, height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_geometry() {
+        let rect = Rect::new(2, 3, 10, 4);
+        assert_eq!(rect.area(), 40);
+        assert_eq!(rect.right(), 12);
+        assert_eq!(rect.bottom(), 7);
+        assert!(rect.contains(2, 3));
+        assert!(!rect.contains(12, 3));
+        assert!(Rect::new(0, 0, 0, 5).is_empty());
+    }
+
+    #[test]
+    fn test_rect_inner() {
+        let rect = Rect::new(0, 0, 10, 5);
+        assert_eq!(rect.inner(1), Rect::new(1, 1, 8, 3));
+        assert_eq!(rect.inner(3), Rect::new(3, 3, 4, 0));
+        assert_eq!(rect.inset(0, 2, 0, 1), Rect::new(1, 0, 7, 5));
+    }
+
+    #[test]
+    fn test_rect_intersection() {
+        let a = Rect::new(0, 0, 10, 10);
+        let b = Rect::new(5, 8, 10, 10);
+        assert_eq!(a.intersection(&b), Rect::new(5, 8, 5, 2));
+        assert!(a.intersection(&Rect::new(20, 20, 1, 1)).is_empty());
+    }
+}
@@ -1,17 +1,19 @@
 //! Main renderer for VibeUI
 
 use crate::platform::Terminal;
-use crate::render::{RenderBuffer, RenderContext, Result};
-use crate::style::Style;
+use crate::render::{Frame, RenderBuffer, RenderContext, Result, Sanitizer};
+use crate::components::ComponentBase;
+use crate::style::{ColorDepth, ColorVision, Span, Style, StyleTarget, Stylesheet, Symbols, Theme};
 use crossterm::{
     execute, queue,
     style::{Color as CrosstermColor, Print, SetForegroundColor, SetBackgroundColor, 
             SetAttribute, Attribute, ResetColor},
-    cursor::MoveTo,
+    cursor::{self, MoveTo},
     terminal::ClearType,
     Command,
 };
 use std::io::{stdout, Write};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Main renderer for drawing UI components
 #[derive(Debug)]
@@ -19,6 +21,10 @@ pub struct Renderer {
     terminal: Terminal,
     buffer: RenderBuffer,
     context: RenderContext,
+    stylesheet: Stylesheet,
+    theme: Option<Theme>,
+    inherited: Vec<Style>,
+    ascii_only: bool,
 }
 
 impl Renderer {
@@ -28,6 +34,10 @@ impl Renderer {
             terminal: Terminal::new().expect("Failed to create terminal"),
             buffer: RenderBuffer::new(terminal.width(), terminal.height()),
             context: RenderContext::new(terminal.width(), terminal.height()),
+            stylesheet: Stylesheet::default(),
+            theme: None,
+            inherited: Vec::new(),
+            ascii_only: false,
         }
     }
 
@@ -69,15 +79,49 @@ impl Renderer {
         Ok(())
     }
 
+    /// Draw a frame in immediate mode
+    ///
+    /// The closure draws into a blank frame, which is then compared with the
+    /// previous frame so only changed cells are written to the terminal.
+    pub fn draw<F: FnOnce(&mut Frame) -> Result<()>>(&mut self, f: F) -> Result<()> {
+        self.update_size()?;
+        self.buffer.clear();
+
+        let mut frame = Frame::new(&mut self.buffer);
+        f(&mut frame)?;
+        let cursor = frame.cursor();
+
+        self.present()?;
+        match cursor {
+            Some((x, y)) => execute!(stdout(), MoveTo(x, y), cursor::Show)?,
+            None => execute!(stdout(), cursor::Hide)?,
+        }
+        Ok(())
+    }
+
     /// Draw text at the specified position
+    ///
+    /// Text is sanitized before it is truncated, so expanded tabs and
+    /// control characters count towards `max_width`. Tab stops are counted
+    /// from the left edge of the screen, not from `x`.
     pub fn draw_text(&mut self, x: u16, y: u16, text: &str, style: &Style, max_width: Option<u16>) -> Result<()> {
+        let text = self.buffer.sanitizer().sanitize(text, x);
         let text = if let Some(max_width) = max_width {
-            let text_width = text.chars().count() as u16;
-            if text_width > max_width {
-                // Truncate text with ellipsis
-                let truncate_len = (max_width.saturating_sub(3)) as usize;
-                let truncated: String = text.chars().take(truncate_len).collect();
-                format!("{}...", truncated)
+            let text_width = text.width();
+            if text_width > max_width as usize {
+                // Truncate text with ellipsis, counting columns so wide
+                // characters aren't cut in half
+                let ellipsis = &self.buffer.symbols().ellipsis;
+                let room = (max_width as usize).saturating_sub(ellipsis.width());
+                let mut used = 0;
+                let truncated: String = text
+                    .chars()
+                    .take_while(|ch| {
+                        used += ch.width().unwrap_or(0);
+                        used <= room
+                    })
+                    .collect();
+                format!("{}{}", truncated, ellipsis)
             } else {
                 text.to_string()
             }
@@ -85,7 +129,17 @@ impl Renderer {
             text.to_string()
         };
 
-        self.buffer.draw_text(x, y, &text, style)
+        self.buffer.draw_sanitized(x, y, &text, style)
+    }
+
+    /// Draw spans with their styles applied on top of `style`
+    pub fn draw_spans(&mut self, x: u16, y: u16, spans: &[Span], style: &Style, max_width: Option<u16>) -> Result<()> {
+        let spans: Vec<Span> = spans
+            .iter()
+            .map(|span| Span::styled(span.content.clone(), style.applied_with(&span.style)))
+            .collect();
+        self.buffer.draw_spans(x, y, &spans, max_width.unwrap_or(u16::MAX))?;
+        Ok(())
     }
 
     /// Draw a rectangle
@@ -102,31 +156,9 @@ impl Renderer {
         Ok(())
     }
 
-    /// Draw a border
+    /// Draw a border with the active border set
     pub fn draw_border(&mut self, x: u16, y: u16, width: u16, height: u16, style: &Style) -> Result<()> {
-        if width < 2 || height < 2 {
-            return Ok(());
-        }
-
-        // Draw corners
-        self.buffer.draw_char(x, y, '┌', style)?;
-        self.buffer.draw_char(x + width - 1, y, '┐', style)?;
-        self.buffer.draw_char(x, y + height - 1, '└', style)?;
-        self.buffer.draw_char(x + width - 1, y + height - 1, '┘', style)?;
-
-        // Draw horizontal lines
-        for col in 1..width - 1 {
-            self.buffer.draw_char(x + col, y, '─', style)?;
-            self.buffer.draw_char(x + col, y + height - 1, '─', style)?;
-        }
-
-        // Draw vertical lines
-        for row in 1..height - 1 {
-            self.buffer.draw_char(x, y + row, '│', style)?;
-            self.buffer.draw_char(x + width - 1, y + row, '│', style)?;
-        }
-
-        Ok(())
+        self.buffer.draw_border(x, y, width, height, style)
     }
 
     /// Draw a line
@@ -143,9 +175,10 @@ impl Renderer {
         let x2 = x2 as i32;
         let y2 = y2 as i32;
 
+        let bullet = self.buffer.symbols().bullet;
         loop {
             if x >= 0 && y >= 0 && x < self.buffer.width() as i32 && y < self.buffer.height() as i32 {
-                self.buffer.draw_char(x as u16, y as u16, '•', style)?;
+                self.buffer.draw_char(x as u16, y as u16, bullet, style)?;
             }
 
             if x == x2 && y == y2 {
@@ -194,6 +227,117 @@ impl Renderer {
         Ok(())
     }
 
+    /// Resolve a component's style for the current render
+    ///
+    /// Colors are inherited from the enclosing `push_inherited` style, then
+    /// stylesheet rules matching the component and its interaction state are
+    /// applied, and finally the component's own `inline` style.
+    pub fn resolve_style(&self, component: &dyn ComponentBase, inline: &Style) -> Style {
+        let states = component.state().pseudo_classes();
+        let target = StyleTarget::new(component.type_name(), component.id())
+            .with_classes(component.classes())
+            .with_states(&states);
+        let mut style = self.stylesheet.resolve(&target, self.inherited.last(), self.theme.as_ref());
+        style.apply(inline);
+        style
+    }
+
+    /// Make a style the parent of components rendered until `pop_inherited`
+    pub fn push_inherited(&mut self, style: Style) {
+        self.inherited.push(style);
+    }
+
+    /// Restore the parent style from before the last `push_inherited`
+    pub fn pop_inherited(&mut self) {
+        self.inherited.pop();
+    }
+
+    /// Get the stylesheet used to resolve component styles
+    pub fn stylesheet(&self) -> &Stylesheet {
+        &self.stylesheet
+    }
+
+    /// Get a mutable stylesheet
+    pub fn stylesheet_mut(&mut self) -> &mut Stylesheet {
+        &mut self.stylesheet
+    }
+
+    /// Replace the stylesheet
+    pub fn set_stylesheet(&mut self, stylesheet: Stylesheet) {
+        self.stylesheet = stylesheet;
+    }
+
+    /// Get the theme that stylesheet references resolve against
+    pub fn theme(&self) -> Option<&Theme> {
+        self.theme.as_ref()
+    }
+
+    /// Set the theme that stylesheet references resolve against
+    ///
+    /// Widgets draw with the theme's symbols unless ASCII-only mode is on.
+    pub fn set_theme(&mut self, theme: Option<Theme>) {
+        self.theme = theme;
+        self.update_symbols();
+    }
+
+    /// Get the rules for control characters and tabs in drawn text
+    pub fn sanitizer(&self) -> Sanitizer {
+        self.buffer.sanitizer()
+    }
+
+    /// Set the rules for control characters and tabs in drawn text
+    pub fn set_sanitizer(&mut self, sanitizer: Sanitizer) {
+        self.buffer.set_sanitizer(sanitizer);
+    }
+
+    /// Get the glyphs widgets draw with
+    pub fn symbols(&self) -> &Symbols {
+        self.buffer.symbols()
+    }
+
+    /// Check whether only ASCII glyphs are drawn
+    pub fn ascii_only(&self) -> bool {
+        self.ascii_only
+    }
+
+    /// Draw only ASCII glyphs, for legacy consoles and serial lines
+    pub fn set_ascii_only(&mut self, ascii_only: bool) {
+        self.ascii_only = ascii_only;
+        self.update_symbols();
+    }
+
+    /// Make the symbols of the theme, or ASCII, active in the buffer
+    fn update_symbols(&mut self) {
+        let symbols = match &self.theme {
+            _ if self.ascii_only => Symbols::ascii(),
+            Some(theme) => theme.symbols.clone(),
+            None => Symbols::default(),
+        };
+        if self.buffer.symbols() != &symbols {
+            self.buffer.set_symbols(symbols);
+        }
+    }
+
+    /// Get the color vision deficiency simulated on output
+    pub fn color_vision(&self) -> Option<ColorVision> {
+        self.buffer.color_vision()
+    }
+
+    /// Simulate a color vision deficiency, or stop with `None`
+    pub fn set_color_vision(&mut self, vision: Option<ColorVision>) {
+        self.buffer.set_color_vision(vision);
+    }
+
+    /// Get the color depth output is downsampled to
+    pub fn color_depth(&self) -> ColorDepth {
+        self.buffer.color_depth()
+    }
+
+    /// Downsample output colors to a color depth
+    pub fn set_color_depth(&mut self, depth: ColorDepth) {
+        self.buffer.set_color_depth(depth);
+    }
+
     /// Get the render context
     pub fn context(&self) -> &RenderContext {
         &self.context
@@ -229,28 +373,28 @@ impl Renderer {
         }
 
         // Set attributes
-        if style.bold {
+        if style.bold == Some(true) {
             commands.push(Box::new(SetAttribute(Attribute::Bold)));
         }
-        if style.italic {
+        if style.italic == Some(true) {
             commands.push(Box::new(SetAttribute(Attribute::Italic)));
         }
-        if style.underline {
+        if style.underline == Some(true) {
             commands.push(Box::new(SetAttribute(Attribute::Underlined)));
         }
-        if style.dim {
+        if style.dim == Some(true) {
             commands.push(Box::new(SetAttribute(Attribute::Dim)));
         }
-        if style.blink {
+        if style.blink == Some(true) {
             commands.push(Box::new(SetAttribute(Attribute::SlowBlink)));
         }
-        if style.reverse {
+        if style.reverse == Some(true) {
             commands.push(Box::new(SetAttribute(Attribute::Reverse)));
         }
-        if style.hidden {
+        if style.hidden == Some(true) {
             commands.push(Box::new(SetAttribute(Attribute::Hidden)));
         }
-        if style.strikethrough {
+        if style.strikethrough == Some(true) {
             commands.push(Box::new(SetAttribute(Attribute::CrossedOut)));
         }
 
@@ -270,6 +414,8 @@ impl Renderer {
             crate::style::Color::White => CrosstermColor::White,
             crate::style::Color::Rgb(r, g, b) => CrosstermColor::Rgb { r, g, b },
             crate::style::Color::AnsiValue(v) => CrosstermColor::AnsiValue(v),
+            // Bright colors by index, as crossterm names them differently
+            color => CrosstermColor::AnsiValue(color.as_ansi_value()),
         }
     }
 }
@@ -293,6 +439,23 @@ mod tests {
         assert!(renderer.height() > 0);
     }
 
+    #[test]
+    fn test_ascii_only() {
+        let mut renderer = Renderer::default();
+        let mut theme = Theme::new("rounded");
+        theme.symbols.border = crate::style::BorderSet::ROUNDED;
+        renderer.set_theme(Some(theme));
+        assert_eq!(renderer.symbols().border.top_left, '╭');
+
+        renderer.set_ascii_only(true);
+        assert!(renderer.symbols().is_ascii());
+        renderer.draw_text(0, 0, "truncated", &Style::default(), Some(6)).unwrap();
+        assert_eq!(renderer.buffer.get_cell(3, 0).unwrap().ch, '.');
+
+        renderer.set_ascii_only(false);
+        assert_eq!(renderer.symbols().border.top_left, '╭');
+    }
+
     #[test]
     fn test_style_conversion() {
         let style = Style::default()
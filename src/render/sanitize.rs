@@ -0,0 +1,167 @@
+//! Sanitizing of text before it reaches the terminal
+//!
+//! Text drawn into the buffer may come from untrusted sources such as file
+//! names or log lines. Control characters in it could start escape
+//! sequences or move the cursor, so they are made visible or removed, and
+//! tabs are expanded to spaces.
+
+use std::borrow::Cow;
+use unicode_width::UnicodeWidthStr;
+
+/// How control characters are shown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ControlChars {
+    /// Caret notation, such as `^[` for ESC, `^?` for DEL and `M-^[` for CSI
+    #[default]
+    Caret,
+    /// A single replacement glyph
+    Replace(char),
+    /// Remove them
+    Strip,
+}
+
+/// Rules for turning arbitrary text into printable cells
+///
+/// Drawn text is single-line, so line breaks (`\r\n`, `\n` or `\r`) become
+/// one space each, whatever the mode. Tabs advance to the next tab stop,
+/// counted from the left edge of the buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sanitizer {
+    control_chars: ControlChars,
+    tab_width: u16,
+}
+
+impl Sanitizer {
+    /// Create a sanitizer using caret notation and tab stops every 8 columns
+    pub fn new() -> Self {
+        Self {
+            control_chars: ControlChars::Caret,
+            tab_width: 8,
+        }
+    }
+
+    /// Set how control characters are shown
+    pub fn with_control_chars(mut self, control_chars: ControlChars) -> Self {
+        self.control_chars = control_chars;
+        self
+    }
+
+    /// Set the distance between tab stops, at least 1
+    pub fn with_tab_width(mut self, tab_width: u16) -> Self {
+        self.tab_width = tab_width.max(1);
+        self
+    }
+
+    /// Get how control characters are shown
+    pub fn control_chars(&self) -> ControlChars {
+        self.control_chars
+    }
+
+    /// Get the distance between tab stops
+    pub fn tab_width(&self) -> u16 {
+        self.tab_width
+    }
+
+    /// Make text drawn from `column` printable
+    ///
+    /// Text without control characters is returned as is.
+    pub fn sanitize<'a>(&self, text: &'a str, column: u16) -> Cow<'a, str> {
+        if !text.chars().any(char::is_control) {
+            return Cow::Borrowed(text);
+        }
+
+        let mut output = String::with_capacity(text.len());
+        let mut column = column as usize;
+        let mut chars = text.chars().peekable();
+        while let Some(ch) = chars.next() {
+            let start = output.len();
+            match ch {
+                '\t' => {
+                    let width = self.tab_width as usize;
+                    output.extend(std::iter::repeat_n(' ', width - column % width));
+                }
+                '\r' => {
+                    chars.next_if_eq(&'\n');
+                    output.push(' ');
+                }
+                '\n' => output.push(' '),
+                ch if ch.is_control() => match self.control_chars {
+                    ControlChars::Caret => caret(ch, &mut output),
+                    ControlChars::Replace(glyph) if !glyph.is_control() => output.push(glyph),
+                    ControlChars::Replace(_) | ControlChars::Strip => {}
+                },
+                ch => output.push(ch),
+            }
+            column += UnicodeWidthStr::width(&output[start..]);
+        }
+        Cow::Owned(output)
+    }
+
+    /// Get what a single cell shows for a character, or `None` to skip it
+    ///
+    /// A control character needs more than one cell in caret notation, so
+    /// it shows as `^` instead.
+    pub fn sanitize_char(&self, ch: char) -> Option<char> {
+        if !ch.is_control() {
+            return Some(ch);
+        }
+        match self.control_chars {
+            ControlChars::Caret => Some('^'),
+            ControlChars::Replace(glyph) if !glyph.is_control() => Some(glyph),
+            ControlChars::Replace(_) | ControlChars::Strip => None,
+        }
+    }
+}
+
+impl Default for Sanitizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Write a control character in caret notation
+fn caret(ch: char, output: &mut String) {
+    let code = ch as u32;
+    if code >= 0x80 {
+        output.push_str("M-");
+    }
+    output.push('^');
+    output.push(char::from_u32((code & 0x7f) ^ 0x40).unwrap_or('?'));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_modes() {
+        let sanitizer = Sanitizer::new();
+        assert!(matches!(sanitizer.sanitize("plain text", 0), Cow::Borrowed("plain text")));
+        assert_eq!(sanitizer.sanitize("a\x1b[31mb\x07\x7f\u{9b}", 0), "a^[[31mb^G^?M-^[");
+
+        let sanitizer = Sanitizer::new().with_control_chars(ControlChars::Replace('\u{fffd}'));
+        assert_eq!(sanitizer.sanitize("\x1b]0;title\x07", 0), "\u{fffd}]0;title\u{fffd}");
+        assert_eq!(sanitizer.sanitize_char('\x1b'), Some('\u{fffd}'));
+
+        let sanitizer = Sanitizer::new().with_control_chars(ControlChars::Strip);
+        assert_eq!(sanitizer.sanitize("\x1b[2Jclear\x00", 0), "[2Jclear");
+        assert_eq!(sanitizer.sanitize_char('\x07'), None);
+        assert_eq!(sanitizer.sanitize_char('x'), Some('x'));
+
+        // A replacement glyph that is itself a control character is stripped
+        let sanitizer = Sanitizer::new().with_control_chars(ControlChars::Replace('\x1b'));
+        assert_eq!(sanitizer.sanitize("a\x1bb", 0), "ab");
+    }
+
+    #[test]
+    fn test_tabs_and_line_breaks() {
+        let sanitizer = Sanitizer::new().with_tab_width(4);
+        assert_eq!(sanitizer.sanitize("a\tb", 0), "a   b");
+        assert_eq!(sanitizer.sanitize("a\tb", 2), "a b");
+        assert_eq!(sanitizer.sanitize("\x1b\tx", 0), "^[  x");
+        // Wide characters move the next tab stop by two columns
+        assert_eq!(sanitizer.sanitize("日\tx", 0), "日  x");
+        assert_eq!(sanitizer.sanitize("one\r\ntwo\nthree\rfour", 0), "one two three four");
+        assert_eq!(Sanitizer::new().with_tab_width(0).tab_width(), 1);
+    }
+}
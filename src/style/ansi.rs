@@ -0,0 +1,295 @@
+//! Parsing of ANSI escape sequences into styled text
+//!
+//! Output of programs such as `cargo` and `git` colors text with SGR
+//! sequences (`ESC [ ... m`). These become span styles; every other escape
+//! sequence and control character is removed, except that carriage
+//! returns, backspaces and erase-in-line act on the current line the way a
+//! terminal would, so progress output collapses to its final state.
+
+use crate::style::depth::SYSTEM_COLORS;
+use crate::style::{Color, Line, Style, Text};
+use std::iter::Peekable;
+use std::str::Chars;
+
+impl Text {
+    /// Parse a string containing ANSI escape sequences
+    ///
+    /// Styles carry over from one line to the next, like in a terminal.
+    /// Unknown SGR parameters are ignored.
+    pub fn from_ansi(input: &str) -> Text {
+        let mut parser = Parser::default();
+        let mut chars = input.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\x1b' => match chars.next() {
+                    Some('[') => parser.csi(&mut chars),
+                    Some(']' | 'P' | 'X' | '^' | '_') => skip_string(&mut chars),
+                    Some('\x20'..='\x2f') => {
+                        // Intermediate bytes, then a final byte
+                        while chars.next_if(|ch| ('\x20'..='\x2f').contains(ch)).is_some() {}
+                        chars.next();
+                    }
+                    _ => {}
+                },
+                '\u{9b}' => parser.csi(&mut chars),
+                '\u{90}' | '\u{98}' | '\u{9d}' | '\u{9e}' | '\u{9f}' => skip_string(&mut chars),
+                '\n' => parser.new_line(),
+                '\r' => parser.cursor = 0,
+                '\x08' => parser.cursor = parser.cursor.saturating_sub(1),
+                '\t' => parser.put('\t'),
+                ch if ch.is_control() => {}
+                ch => parser.put(ch),
+            }
+        }
+        parser.finish()
+    }
+}
+
+/// Remove ANSI escape sequences, keeping only the text
+pub fn strip(input: &str) -> String {
+    Text::from_ansi(input).to_string()
+}
+
+/// Text written so far and the state of the virtual cursor
+#[derive(Default)]
+struct Parser {
+    lines: Vec<Line>,
+    line: Vec<(char, Style)>,
+    cursor: usize,
+    style: Style,
+}
+
+impl Parser {
+    /// Write a character at the cursor, overwriting what is there
+    fn put(&mut self, ch: char) {
+        while self.line.len() < self.cursor {
+            self.line.push((' ', Style::default()));
+        }
+        match self.line.get_mut(self.cursor) {
+            Some(cell) => *cell = (ch, self.style),
+            None => self.line.push((ch, self.style)),
+        }
+        self.cursor += 1;
+    }
+
+    /// Finish the current line
+    fn new_line(&mut self) {
+        let line = std::mem::take(&mut self.line);
+        self.lines.push(Line::from_styled_chars(line));
+        self.cursor = 0;
+    }
+
+    /// Handle a control sequence after `ESC [`
+    ///
+    /// Sequences with private markers or intermediate bytes are ignored. A
+    /// sequence cut short by a control character is dropped and the control
+    /// character handled as usual.
+    fn csi(&mut self, chars: &mut Peekable<Chars<'_>>) {
+        let mut params = String::new();
+        let mut intermediates = false;
+        let mut last = None;
+        while let Some(&ch) = chars.peek() {
+            match ch {
+                '\x30'..='\x3f' => params.push(ch),
+                '\x20'..='\x2f' => intermediates = true,
+                '\x40'..='\x7e' => {
+                    chars.next();
+                    last = Some(ch);
+                    break;
+                }
+                _ => return,
+            }
+            chars.next();
+        }
+
+        if intermediates || params.starts_with(['<', '=', '>', '?']) {
+            return;
+        }
+        match last {
+            Some('m') => apply_sgr(&mut self.style, &params),
+            Some('K') => self.erase_line(&params),
+            _ => {}
+        }
+    }
+
+    /// Erase after the cursor (0), before and at it (1) or the whole line (2)
+    fn erase_line(&mut self, params: &str) {
+        match params {
+            "" | "0" => self.line.truncate(self.cursor),
+            "1" => {
+                let end = (self.cursor + 1).min(self.line.len());
+                self.line[..end].fill((' ', Style::default()));
+            }
+            "2" => self.line.clear(),
+            _ => {}
+        }
+    }
+
+    /// Get the text, without an empty last line
+    fn finish(mut self) -> Text {
+        if !self.line.is_empty() {
+            self.new_line();
+        }
+        Text::new(self.lines)
+    }
+}
+
+/// Skip a string command such as OSC, up to BEL or the string terminator
+fn skip_string(chars: &mut Peekable<Chars<'_>>) {
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\x07' | '\u{9c}' => return,
+            '\x1b' => {
+                chars.next_if_eq(&'\\');
+                return;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Apply SGR parameters to a style
+///
+/// Extended colors are accepted both as `38;5;n` and as `38:5:n`, with an
+/// optional color space id in the colon form of truecolor.
+fn apply_sgr(style: &mut Style, params: &str) {
+    // Empty parameters count as 0, so `ESC [ m` resets
+    let mut groups = params.split(';');
+    while let Some(group) = groups.next() {
+        if group.contains(':') {
+            let parts: Vec<u32> = group.split(':').map(|part| part.parse().unwrap_or(0)).collect();
+            match parts[0] {
+                38 | 48 | 58 => {
+                    let args = match parts.get(1) {
+                        Some(2) if parts.len() >= 6 => &parts[3..6],
+                        _ => &parts[2..],
+                    };
+                    set_extended(style, parts[0], parts[1], args);
+                }
+                4 => style.underline = Some(parts.get(1) != Some(&0)),
+                code => apply_code(style, code),
+            }
+            continue;
+        }
+
+        let code = group.parse().unwrap_or(0);
+        if matches!(code, 38 | 48 | 58) {
+            let kind = groups.next().and_then(|kind| kind.parse().ok()).unwrap_or(0);
+            let count = match kind {
+                5 => 1,
+                2 => 3,
+                _ => 0,
+            };
+            let args: Vec<u32> = groups.by_ref().take(count).map(|arg| arg.parse().unwrap_or(0)).collect();
+            set_extended(style, code, kind, &args);
+        } else {
+            apply_code(style, code);
+        }
+    }
+}
+
+/// Set a 256-color (`kind` 5) or truecolor (`kind` 2) foreground or background
+///
+/// Underline colors (58) are not supported and ignored.
+fn set_extended(style: &mut Style, code: u32, kind: u32, args: &[u32]) {
+    let channel = |index: usize| args.get(index).map(|&value| value.min(255) as u8);
+    let color = match kind {
+        5 => channel(0).map(Color::AnsiValue),
+        2 => match (channel(0), channel(1), channel(2)) {
+            (Some(r), Some(g), Some(b)) => Some(Color::Rgb(r, g, b)),
+            _ => None,
+        },
+        _ => None,
+    };
+    match (code, color) {
+        (38, Some(color)) => style.foreground = Some(color),
+        (48, Some(color)) => style.background = Some(color),
+        _ => {}
+    }
+}
+
+/// Apply a single SGR code
+fn apply_code(style: &mut Style, code: u32) {
+    match code {
+        0 => *style = Style::default(),
+        1 => style.bold = Some(true),
+        2 => style.dim = Some(true),
+        3 => style.italic = Some(true),
+        4 | 21 => style.underline = Some(true),
+        5 | 6 => style.blink = Some(true),
+        7 => style.reverse = Some(true),
+        8 => style.hidden = Some(true),
+        9 => style.strikethrough = Some(true),
+        22 => {
+            style.bold = Some(false);
+            style.dim = Some(false);
+        }
+        23 => style.italic = Some(false),
+        24 => style.underline = Some(false),
+        25 => style.blink = Some(false),
+        27 => style.reverse = Some(false),
+        28 => style.hidden = Some(false),
+        29 => style.strikethrough = Some(false),
+        30..=37 => style.foreground = Some(SYSTEM_COLORS[(code - 30) as usize]),
+        39 => style.foreground = None,
+        40..=47 => style.background = Some(SYSTEM_COLORS[(code - 40) as usize]),
+        49 => style.background = None,
+        90..=97 => style.foreground = Some(SYSTEM_COLORS[(code - 90 + 8) as usize]),
+        100..=107 => style.background = Some(SYSTEM_COLORS[(code - 100 + 8) as usize]),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Span;
+
+    #[test]
+    fn test_parse_sgr() {
+        let text = Text::from_ansi("\x1b[1;32mok\x1b[0m done \x1b[91;44mX\x1b[39mY\x1b[m");
+        let bold_green = Style::default().with_bold(true).with_foreground(Color::Green);
+        assert_eq!(
+            text.lines[0].spans,
+            vec![
+                Span::styled("ok", bold_green),
+                Span::raw(" done "),
+                Span::styled("X", Style::default().with_colors(Color::BrightRed, Color::Blue)),
+                Span::styled("Y", Style::default().with_background(Color::Blue)),
+            ]
+        );
+
+        let text = Text::from_ansi("\x1b[38;5;208ma\x1b[48;2;1;2;3mb\x1b[38:2::10:20:30;4mc\x1b[22;24;38:5:9;58;5;1md");
+        let spans = &text.lines[0].spans;
+        assert_eq!(spans[0].style.foreground, Some(Color::AnsiValue(208)));
+        assert_eq!(spans[1].style.background, Some(Color::Rgb(1, 2, 3)));
+        assert_eq!(spans[2].style.foreground, Some(Color::Rgb(10, 20, 30)));
+        assert_eq!(spans[2].style.underline, Some(true));
+        assert_eq!(spans[3].style.foreground, Some(Color::AnsiValue(9)));
+        assert_eq!(spans[3].style.underline, Some(false));
+
+        // Styles persist across lines
+        let text = Text::from_ansi("\x1b[31mred\nstill red\n");
+        assert_eq!(text.height(), 2);
+        assert_eq!(text.lines[1].spans[0].style.foreground, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_strip_other_sequences() {
+        let input = "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07 \x1b[2J\x1b[1;1H\x1b(Bx\x1b7\x1b[?25ly\x07\x00z";
+        assert_eq!(strip(input), "link xyz");
+        assert_eq!(strip("cut \x1b["), "cut ");
+        assert_eq!(strip("\u{9b}31mc1\u{9d}title\u{9c}"), "c1");
+        assert_eq!(strip("tab\there"), "tab\there");
+    }
+
+    #[test]
+    fn test_carriage_return_and_erase() {
+        assert_eq!(strip("Compiling 10%\rCompiling 100%\n"), "Compiling 100%");
+        assert_eq!(strip("downloading\r\x1b[Kdone"), "done");
+        assert_eq!(strip("abc\x08\x08X"), "aXc");
+        assert_eq!(strip("abcdef\x1b[3D"), "abcdef");
+        assert_eq!(strip("line\r\nnext"), "line\nnext");
+        assert_eq!(Text::from_ansi("").height(), 0);
+    }
+}
@@ -0,0 +1,141 @@
+//! WCAG contrast audit for themes
+
+use crate::style::{Color, Theme};
+use std::fmt;
+
+/// A WCAG 2 contrast requirement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WcagLevel {
+    /// Level AA for large or bold text, 3:1
+    AaLarge,
+    /// Level AA for normal text, 4.5:1
+    Aa,
+    /// Level AAA for normal text, 7:1
+    Aaa,
+}
+
+impl WcagLevel {
+    /// Get the minimum contrast ratio for this level
+    pub fn min_ratio(&self) -> f32 {
+        match self {
+            WcagLevel::AaLarge => 3.0,
+            WcagLevel::Aa => 4.5,
+            WcagLevel::Aaa => 7.0,
+        }
+    }
+}
+
+/// The contrast of one theme style
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastCheck {
+    /// Name of the style
+    pub style: String,
+    /// Effective foreground color
+    pub foreground: Color,
+    /// Effective background color
+    pub background: Color,
+    /// Contrast ratio between the colors (1.0 to 21.0)
+    pub ratio: f32,
+}
+
+impl ContrastCheck {
+    /// Check if the style meets a level
+    pub fn passes(&self, level: WcagLevel) -> bool {
+        self.ratio >= level.min_ratio()
+    }
+}
+
+impl fmt::Display for ContrastCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} on {} has contrast {:.2}:1", self.style, self.foreground, self.background, self.ratio)
+    }
+}
+
+/// Contrast of every style in a theme
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContrastReport {
+    checks: Vec<ContrastCheck>,
+}
+
+impl ContrastReport {
+    /// Get all checks, sorted by style name
+    pub fn checks(&self) -> &[ContrastCheck] {
+        &self.checks
+    }
+
+    /// Get the check for a style
+    pub fn get(&self, style: &str) -> Option<&ContrastCheck> {
+        self.checks.iter().find(|check| check.style == style)
+    }
+
+    /// Get the styles failing a level
+    pub fn failures(&self, level: WcagLevel) -> Vec<&ContrastCheck> {
+        self.checks.iter().filter(|check| !check.passes(level)).collect()
+    }
+
+    /// Check if every style meets a level
+    pub fn passes(&self, level: WcagLevel) -> bool {
+        self.checks.iter().all(|check| check.passes(level))
+    }
+}
+
+impl Theme {
+    /// Compute the contrast of every style
+    ///
+    /// Styles without a foreground or background are checked against the
+    /// palette's `text` and `background`, and reversed styles use their
+    /// swapped colors. Hidden styles are skipped.
+    pub fn audit_contrast(&self) -> ContrastReport {
+        let mut checks: Vec<ContrastCheck> = self
+            .styles
+            .iter()
+            .filter(|(_, style)| style.hidden != Some(true))
+            .map(|(name, style)| {
+                let mut foreground = style.foreground.unwrap_or(self.palette.text);
+                let mut background = style.background.unwrap_or(self.palette.background);
+                if style.reverse == Some(true) {
+                    std::mem::swap(&mut foreground, &mut background);
+                }
+                ContrastCheck {
+                    style: name.clone(),
+                    foreground,
+                    background,
+                    ratio: foreground.contrast_ratio(&background),
+                }
+            })
+            .collect();
+        checks.sort_by(|a, b| a.style.cmp(&b.style));
+        ContrastReport { checks }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    #[test]
+    fn test_audit_contrast() {
+        let mut theme = Theme::new("audit");
+        theme.palette.text = Color::Rgb(255, 255, 255);
+        theme.palette.background = Color::Rgb(0, 0, 0);
+        theme.styles.insert("plain".to_string(), Style::new());
+        theme.styles.insert("faint".to_string(), Style::new().with_foreground(Color::Rgb(60, 60, 60)));
+        theme.styles.insert("inverse".to_string(), Style::new().with_reverse(true));
+        theme.styles.insert("secret".to_string(), Style::new().with_hidden(true));
+
+        let report = theme.audit_contrast();
+        let names: Vec<&str> = report.checks().iter().map(|check| check.style.as_str()).collect();
+        assert_eq!(names, vec!["faint", "inverse", "plain"]);
+
+        let inverse = report.get("inverse").unwrap();
+        assert_eq!(inverse.foreground, Color::Rgb(0, 0, 0));
+        assert!(inverse.passes(WcagLevel::Aaa));
+
+        let failures = report.failures(WcagLevel::Aa);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].style, "faint");
+        assert!(!report.passes(WcagLevel::AaLarge));
+        assert!(failures[0].to_string().starts_with("faint: #3C3C3C on #000000 has contrast"));
+    }
+}
@@ -1,10 +1,13 @@
 //! Color definitions and utilities
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
 /// Color representation for terminal UI
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Serialized as the string accepted by `Color::parse`, such as `"red"` or
+/// `"#1E1E2E"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Color {
     /// Basic ANSI colors
     Black,
@@ -43,11 +46,20 @@ impl Color {
     pub fn from_hex(hex: &str) -> Result<Self, crate::style::Error> {
         let hex = hex.trim_start_matches('#');
         
+        if hex.len() == 8 {
+            return Err(crate::style::Error::ColorParse(
+                "Hex colors with alpha are not supported".to_string()
+            ));
+        }
         if hex.len() != 6 {
             return Err(crate::style::Error::ColorParse(
                 "Hex color must be 6 characters long".to_string()
             ));
         }
+        // Checked before slicing, which would panic inside a multibyte char
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(crate::style::Error::ColorParse(format!("Invalid hex color: {}", hex)));
+        }
 
         let r = u8::from_str_radix(&hex[0..2], 16)
             .map_err(|e| crate::style::Error::ColorParse(format!("Invalid hex: {}", e)))?;
@@ -78,25 +90,8 @@ impl Color {
             Color::BrightMagenta => 13,
             Color::BrightCyan => 14,
             Color::BrightWhite => 15,
-            Color::Rgb(r, g, b) => {
-                // Convert RGB to 256-color palette
-                if r == g && g == b {
-                    // Grayscale
-                    if r < 8 {
-                        16
-                    } else if r > 248 {
-                        231
-                    } else {
-                        232 + (r - 8) / 10
-                    }
-                } else {
-                    // 6x6x6 color cube
-                    let r = (r as f32 / 255.0 * 5.0).round() as u8;
-                    let g = (g as f32 / 255.0 * 5.0).round() as u8;
-                    let b = (b as f32 / 255.0 * 5.0).round() as u8;
-                    16 + 36 * r + 6 * g + b
-                }
-            }
+            // Nearest entry of the color cube or gray ramp
+            Color::Rgb(r, g, b) => crate::style::depth::nearest_ansi256(*r, *g, *b),
             Color::AnsiValue(v) => *v,
         }
     }
@@ -170,6 +165,47 @@ impl Color {
         (r as f32 * 0.299 + g as f32 * 0.587 + b as f32 * 0.114) / 255.0
     }
 
+    /// Get the WCAG relative luminance (0.0 to 1.0)
+    pub fn relative_luminance(&self) -> f32 {
+        let [r, g, b] = self.as_linear_rgb();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Get the WCAG contrast ratio against another color (1.0 to 21.0)
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Convert to linear-light RGB components (0.0 to 1.0)
+    pub fn as_linear_rgb(&self) -> [f32; 3] {
+        let (r, g, b) = self.as_rgb();
+        [r, g, b].map(|channel| {
+            let c = channel as f32 / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        })
+    }
+
+    /// Create a color from linear-light RGB components
+    ///
+    /// Components are clamped to 0.0 to 1.0.
+    pub fn from_linear_rgb(rgb: [f32; 3]) -> Self {
+        let [r, g, b] = rgb.map(|c| {
+            let c = c.clamp(0.0, 1.0);
+            let c = if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            };
+            (c * 255.0).round() as u8
+        });
+        Color::Rgb(r, g, b)
+    }
+
     /// Check if the color is light (brightness > 0.5)
     pub fn is_light(&self) -> bool {
         self.brightness() > 0.5
@@ -209,6 +245,9 @@ impl Color {
     }
 
     /// Parse a color from a string
+    ///
+    /// Colors with an alpha channel are rejected unless fully opaque, since
+    /// terminals can't blend them with what is behind.
     pub fn parse(s: &str) -> Result<Self, crate::style::Error> {
         let s = s.to_lowercase();
         
@@ -221,7 +260,7 @@ impl Color {
             "magenta" => Ok(Color::Magenta),
             "cyan" => Ok(Color::Cyan),
             "white" => Ok(Color::White),
-            "brightblack" | "gray" => Ok(Color::BrightBlack),
+            "brightblack" | "gray" | "grey" => Ok(Color::BrightBlack),
             "brightred" => Ok(Color::BrightRed),
             "brightgreen" => Ok(Color::BrightGreen),
             "brightyellow" => Ok(Color::BrightYellow),
@@ -232,8 +271,22 @@ impl Color {
             _ => {
                 if s.starts_with('#') {
                     Self::from_hex(&s)
+                } else if let Some(value) = s.strip_prefix("ansi(").and_then(|s| s.strip_suffix(')')) {
+                    value.trim().parse::<u8>().map(Color::AnsiValue).map_err(|_| {
+                        crate::style::Error::ColorParse(format!("Invalid ANSI color: {}", s))
+                    })
                 } else if let Ok(ansi_value) = s.parse::<u8>() {
                     Ok(Color::AnsiValue(ansi_value))
+                } else if let Some(args) = function_args(&s, &["rgba", "rgb"]) {
+                    check_opaque(&args, &s)?;
+                    parse_rgb_args(&args)
+                        .ok_or_else(|| crate::style::Error::ColorParse(format!("Invalid rgb color: {}", s)))
+                } else if let Some(args) = function_args(&s, &["hsla", "hsl"]) {
+                    check_opaque(&args, &s)?;
+                    parse_hsl_args(&args)
+                        .ok_or_else(|| crate::style::Error::ColorParse(format!("Invalid hsl color: {}", s)))
+                } else if let Some((r, g, b)) = crate::style::named::lookup(&s) {
+                    Ok(Color::Rgb(r, g, b))
                 } else {
                     Err(crate::style::Error::ColorParse(
                         format!("Unknown color: {}", s)
@@ -244,6 +297,59 @@ impl Color {
     }
 }
 
+/// Get the arguments of a CSS color function such as `rgb(1, 2, 3)`
+///
+/// Arguments may be separated by commas, spaces or the `/` before alpha.
+fn function_args<'a>(s: &'a str, names: &[&str]) -> Option<Vec<&'a str>> {
+    let args = names.iter().find_map(|name| s.strip_prefix(name))?;
+    let args = args.trim_start().strip_prefix('(')?.strip_suffix(')')?;
+    Some(args.split([',', ' ', '/']).filter(|arg| !arg.is_empty()).collect())
+}
+
+/// Reject a color function whose fourth argument is a translucent alpha
+fn check_opaque(args: &[&str], color: &str) -> Result<(), crate::style::Error> {
+    let Some(alpha) = args.get(3) else {
+        return Ok(());
+    };
+    let value = match alpha.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok().map(|percent| percent / 100.0),
+        None => alpha.parse::<f32>().ok(),
+    };
+    if value == Some(1.0) {
+        Ok(())
+    } else {
+        Err(crate::style::Error::ColorParse(format!("Translucent colors are not supported: {}", color)))
+    }
+}
+
+/// Parse `rgb()` arguments: 0-255 or percentages, with an opaque alpha
+fn parse_rgb_args(args: &[&str]) -> Option<Color> {
+    if !(3..=4).contains(&args.len()) {
+        return None;
+    }
+    let channel = |arg: &str| -> Option<u8> {
+        let value = match arg.strip_suffix('%') {
+            Some(percent) => percent.parse::<f32>().ok()? * 2.55,
+            None => arg.parse::<f32>().ok()?,
+        };
+        Some(value.clamp(0.0, 255.0).round() as u8)
+    };
+    Some(Color::Rgb(channel(args[0])?, channel(args[1])?, channel(args[2])?))
+}
+
+/// Parse `hsl()` arguments: hue in degrees, then percentages, with an opaque alpha
+fn parse_hsl_args(args: &[&str]) -> Option<Color> {
+    if !(3..=4).contains(&args.len()) {
+        return None;
+    }
+    let percent = |arg: &str| -> Option<f32> { Some(arg.trim_end_matches('%').parse::<f32>().ok()? / 100.0) };
+    Some(Color::from_hsl(crate::style::space::Hsl {
+        h: args[0].trim_end_matches("deg").parse().ok()?,
+        s: percent(args[1])?,
+        l: percent(args[2])?,
+    }))
+}
+
 impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -269,6 +375,36 @@ impl fmt::Display for Color {
     }
 }
 
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Encoding of `Rgb` and `AnsiValue` used before colors were strings
+        #[derive(Deserialize)]
+        enum Tagged {
+            Rgb(u8, u8, u8),
+            AnsiValue(u8),
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Text(String),
+            Tagged(Tagged),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Text(text) => Color::parse(&text).map_err(serde::de::Error::custom),
+            Repr::Tagged(Tagged::Rgb(r, g, b)) => Ok(Color::Rgb(r, g, b)),
+            Repr::Tagged(Tagged::AnsiValue(value)) => Ok(Color::AnsiValue(value)),
+        }
+    }
+}
+
 impl Default for Color {
     fn default() -> Self {
         Color::White
@@ -321,6 +457,8 @@ mod tests {
 
         let hex = Color::from_hex("#FF0000").unwrap();
         assert_eq!(hex, Color::Rgb(255, 0, 0));
+        assert!(Color::from_hex("aé123").is_err());
+        assert!(Color::parse("#aé123").is_err());
 
         let parsed = Color::parse("red").unwrap();
         assert_eq!(parsed, Color::Red);
@@ -352,6 +490,39 @@ mod tests {
         assert!(!gray.is_light() && !gray.is_dark()); // Around middle
     }
 
+    #[test]
+    fn test_parse_css_colors() {
+        assert_eq!(Color::parse("rgb(255, 128, 0)").unwrap(), Color::Rgb(255, 128, 0));
+        assert_eq!(Color::parse("rgb(100% 50% 0% / 100%)").unwrap(), Color::Rgb(255, 128, 0));
+        assert_eq!(Color::parse("rgba(1,2,3,1)").unwrap(), Color::Rgb(1, 2, 3));
+        // Terminals can't blend, so translucent colors are errors
+        assert!(Color::parse("rgba(1,2,3,0.4)").is_err());
+        assert!(Color::parse("hsl(120 100% 25% / 50%)").is_err());
+        assert!(Color::parse("#ff000080").is_err());
+        assert_eq!(Color::parse("hsl(120, 100%, 25%)").unwrap(), Color::Rgb(0, 128, 0));
+        assert_eq!(Color::parse("hsl(240deg 100% 50%)").unwrap(), Color::Rgb(0, 0, 255));
+        assert_eq!(Color::parse("RebeccaPurple").unwrap(), Color::Rgb(0x66, 0x33, 0x99));
+        // Terminal color names keep their meaning, in either spelling
+        assert_eq!(Color::parse("red").unwrap(), Color::Red);
+        assert_eq!(Color::parse("grey").unwrap(), Color::parse("gray").unwrap());
+        assert!(Color::parse("rgb(1, 2)").is_err());
+        assert!(Color::parse("hsl(a, b, c)").is_err());
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 0.01);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 0.01);
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+        // #767676 is the lightest gray passing AA on white
+        assert!(Color::Rgb(0x76, 0x76, 0x76).contrast_ratio(&white) >= 4.5);
+
+        let color = Color::Rgb(12, 200, 99);
+        assert_eq!(Color::from_linear_rgb(color.as_linear_rgb()), color);
+    }
+
     #[test]
     fn test_color_blend() {
         let red = Color::Red;
@@ -379,4 +550,19 @@ mod tests {
         assert_eq!(Color::Rgb(255, 0, 0).to_string(), "#FF0000");
         assert_eq!(Color::AnsiValue(42).to_string(), "ansi(42)");
     }
+
+    #[test]
+    fn test_color_serde() {
+        for color in [Color::Red, Color::BrightCyan, Color::Rgb(30, 30, 46), Color::AnsiValue(208)] {
+            let json = serde_json::to_string(&color).unwrap();
+            assert_eq!(serde_json::from_str::<Color>(&json).unwrap(), color);
+        }
+        assert_eq!(serde_json::to_string(&Color::Rgb(30, 30, 46)).unwrap(), "\"#1E1E2E\"");
+        assert_eq!(serde_json::from_str::<Color>("\"#1e1e2e\"").unwrap(), Color::Rgb(30, 30, 46));
+
+        // The older enum encoding still loads
+        assert_eq!(serde_json::from_str::<Color>("\"Red\"").unwrap(), Color::Red);
+        assert_eq!(serde_json::from_str::<Color>(r#"{"Rgb": [1, 2, 3]}"#).unwrap(), Color::Rgb(1, 2, 3));
+        assert!(serde_json::from_str::<Color>("\"nope\"").is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,137 @@
+//! Color depth and downsampling
+//!
+//! Terminals without truecolor support get each color mapped to the
+//! perceptually nearest entry of the palette they do support.
+
+use crate::style::{Color, Oklab, Style};
+use std::sync::OnceLock;
+
+/// How many colors a terminal can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// No colors, only attributes such as bold and reverse
+    Monochrome,
+    /// The 16 system colors
+    Ansi16,
+    /// The 256-color xterm palette
+    Ansi256,
+    /// 24-bit RGB
+    TrueColor,
+}
+
+/// The 16 system colors, by ANSI index
+pub(crate) const SYSTEM_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::BrightBlack,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+    Color::BrightWhite,
+];
+
+impl ColorDepth {
+    /// Map a color to one this depth can display
+    ///
+    /// Returns `None` for monochrome, where the terminal's default colors
+    /// are used instead.
+    pub fn downsample(&self, color: Color) -> Option<Color> {
+        match (self, color) {
+            (ColorDepth::Monochrome, _) => None,
+            (ColorDepth::TrueColor, color) => Some(color),
+            (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Some(Color::AnsiValue(nearest_ansi256(r, g, b))),
+            (ColorDepth::Ansi256, color) => Some(color),
+            (ColorDepth::Ansi16, Color::AnsiValue(v)) if v < 16 => Some(SYSTEM_COLORS[v as usize]),
+            (ColorDepth::Ansi16, Color::Rgb(..) | Color::AnsiValue(_)) => Some(nearest_ansi16(color)),
+            (ColorDepth::Ansi16, color) => Some(color),
+        }
+    }
+
+    /// Map both colors of a style, keeping its attributes
+    pub fn downsample_style(&self, style: &Style) -> Style {
+        Style {
+            foreground: style.foreground.and_then(|color| self.downsample(color)),
+            background: style.background.and_then(|color| self.downsample(color)),
+            ..*style
+        }
+    }
+}
+
+/// Get the entry of the 256-color palette nearest to an RGB color
+///
+/// Only the color cube and gray ramp (16 to 255) are considered, since
+/// terminals customize the 16 system colors.
+pub(crate) fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let target = Color::Rgb(r, g, b).to_oklab();
+    let table = ansi256_table();
+    (16..=255u8)
+        .min_by(|&x, &y| {
+            let (dx, dy) = (target.distance(&table[x as usize]), target.distance(&table[y as usize]));
+            dx.total_cmp(&dy)
+        })
+        .unwrap_or(16)
+}
+
+/// Get the system color nearest to a color
+fn nearest_ansi16(color: Color) -> Color {
+    let target = color.to_oklab();
+    let table = ansi256_table();
+    let index = (0..16)
+        .min_by(|&x: &usize, &y| target.distance(&table[x]).total_cmp(&target.distance(&table[y])))
+        .unwrap_or(0);
+    SYSTEM_COLORS[index]
+}
+
+/// OKLab values of the 256-color palette, computed once
+fn ansi256_table() -> &'static [Oklab; 256] {
+    static TABLE: OnceLock<[Oklab; 256]> = OnceLock::new();
+    TABLE.get_or_init(|| std::array::from_fn(|index| Color::AnsiValue(index as u8).to_oklab()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_downsample_256() {
+        let depth = ColorDepth::Ansi256;
+        assert_eq!(depth.downsample(Color::Rgb(255, 0, 0)), Some(Color::AnsiValue(196)));
+        assert_eq!(depth.downsample(Color::Rgb(0, 0, 0)), Some(Color::AnsiValue(16)));
+        assert_eq!(depth.downsample(Color::Rgb(128, 128, 128)), Some(Color::AnsiValue(244)));
+        assert_eq!(depth.downsample(Color::Red), Some(Color::Red));
+        // Exact palette colors map to themselves
+        for index in [21u8, 100, 208, 240] {
+            let (r, g, b) = Color::AnsiValue(index).as_rgb();
+            assert_eq!(nearest_ansi256(r, g, b), index);
+        }
+    }
+
+    #[test]
+    fn test_downsample_16() {
+        let depth = ColorDepth::Ansi16;
+        assert_eq!(depth.downsample(Color::Rgb(250, 10, 10)), Some(Color::BrightRed));
+        assert_eq!(depth.downsample(Color::Rgb(0, 0, 120)), Some(Color::Blue));
+        assert_eq!(depth.downsample(Color::AnsiValue(9)), Some(Color::BrightRed));
+        assert_eq!(depth.downsample(Color::AnsiValue(231)), Some(Color::BrightWhite));
+        assert_eq!(depth.downsample(Color::Cyan), Some(Color::Cyan));
+    }
+
+    #[test]
+    fn test_downsample_style() {
+        let style = Style::new().with_colors(Color::Rgb(1, 2, 3), Color::Red).with_bold(true);
+        let mono = ColorDepth::Monochrome.downsample_style(&style);
+        assert_eq!((mono.foreground, mono.background), (None, None));
+        assert_eq!(mono.bold, Some(true));
+        assert_eq!(ColorDepth::TrueColor.downsample_style(&style), style);
+        assert!(ColorDepth::Ansi16 < ColorDepth::TrueColor);
+    }
+}
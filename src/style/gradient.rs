@@ -0,0 +1,95 @@
+//! Color gradients with multiple stops
+
+use crate::style::Color;
+
+/// A gradient interpolated in OKLab
+///
+/// Stops are positions from 0.0 to 1.0. Interpolating in a perceptual
+/// space avoids the muddy midpoints of sRGB gradients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    stops: Vec<(f32, Color)>,
+}
+
+impl Gradient {
+    /// Create a gradient with evenly spaced colors
+    pub fn new(colors: &[Color]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self {
+            stops: colors
+                .iter()
+                .enumerate()
+                .map(|(index, color)| (index as f32 / last, *color))
+                .collect(),
+        }
+    }
+
+    /// Add a stop at a position from 0.0 to 1.0
+    pub fn with_stop(mut self, position: f32, color: Color) -> Self {
+        let position = position.clamp(0.0, 1.0);
+        let index = self.stops.partition_point(|(stop, _)| *stop <= position);
+        self.stops.insert(index, (position, color));
+        self
+    }
+
+    /// Get the stops, ordered by position
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// Get the color at a position from 0.0 to 1.0
+    ///
+    /// Positions before the first stop or after the last take that stop's
+    /// color. An empty gradient is black.
+    pub fn at(&self, position: f32) -> Color {
+        let index = self.stops.partition_point(|(stop, _)| *stop <= position);
+        match (index.checked_sub(1).map(|index| self.stops[index]), self.stops.get(index)) {
+            (Some((start, from)), Some(&(end, to))) => {
+                let t = if end > start { (position - start) / (end - start) } else { 1.0 };
+                from.mix(&to, t)
+            }
+            (Some((_, color)), None) | (None, Some(&(_, color))) => color,
+            (None, None) => Color::Rgb(0, 0, 0),
+        }
+    }
+
+    /// Sample `count` evenly spaced colors, including both ends
+    pub fn colors(&self, count: usize) -> Vec<Color> {
+        let last = count.saturating_sub(1).max(1) as f32;
+        (0..count).map(|index| self.at(index as f32 / last)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradient() {
+        let red = Color::Rgb(255, 0, 0);
+        let blue = Color::Rgb(0, 0, 255);
+        let gradient = Gradient::new(&[red, blue]);
+        assert_eq!(gradient.at(0.0), red);
+        assert_eq!(gradient.at(1.0), blue);
+        assert_eq!(gradient.at(-1.0), red);
+        assert_eq!(gradient.at(2.0), blue);
+        assert_eq!(gradient.at(0.5), red.mix(&blue, 0.5));
+
+        let colors = gradient.colors(5);
+        assert_eq!(colors.len(), 5);
+        assert_eq!((colors[0], colors[4]), (red, blue));
+    }
+
+    #[test]
+    fn test_gradient_stops() {
+        let white = Color::Rgb(255, 255, 255);
+        let gradient = Gradient::new(&[Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 0)]).with_stop(0.25, white);
+        let positions: Vec<f32> = gradient.stops().iter().map(|(position, _)| *position).collect();
+        assert_eq!(positions, vec![0.0, 0.25, 1.0]);
+        assert_eq!(gradient.at(0.25), white);
+        assert!(gradient.at(0.1).relative_luminance() < gradient.at(0.2).relative_luminance());
+
+        assert_eq!(Gradient::new(&[]).at(0.5), Color::Rgb(0, 0, 0));
+        assert_eq!(Gradient::new(&[white]).at(0.5), white);
+    }
+}
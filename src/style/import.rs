@@ -0,0 +1,466 @@
+//! Importers for third-party terminal color schemes
+//!
+//! Community schemes are mapped onto a [`ColorPalette`] and the theme's
+//! default styles are generated from it.
+
+use crate::style::theme::{find_token, line_at, ColorPalette, Theme, ThemeError};
+use crate::style::Color;
+use std::collections::BTreeMap;
+
+/// Slot names of a base16 scheme, in order
+const BASE16_KEYS: [&str; 16] = [
+    "base00", "base01", "base02", "base03", "base04", "base05", "base06", "base07",
+    "base08", "base09", "base0A", "base0B", "base0C", "base0D", "base0E", "base0F",
+];
+
+/// Names of the eight ANSI colors in Alacritty configs
+const ALACRITTY_KEYS: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+/// Names of the eight ANSI colors in Windows Terminal schemes
+const WINDOWS_TERMINAL_KEYS: [&str; 8] = ["black", "red", "green", "yellow", "blue", "purple", "cyan", "white"];
+
+impl Theme {
+    /// Load a theme from a base16 or base24 YAML scheme
+    ///
+    /// Both the flat `scheme:`/`base00:` layout and the newer layout with a
+    /// `palette:` block are accepted. The extra slots of base24 schemes are
+    /// ignored.
+    pub fn from_base16(yaml: &str) -> Result<Self, ThemeError> {
+        let values = parse_yaml(yaml)?;
+        let get = |key: &str| values.get(key).map(|(value, _)| value.as_str());
+
+        let mut base = [Color::Black; 16];
+        for (slot, key) in base.iter_mut().zip(BASE16_KEYS) {
+            *slot = match get(key).or_else(|| get(&key.to_lowercase())) {
+                Some(value) => parse_scheme_color(value)
+                    .ok_or_else(|| invalid("<base16>", yaml, value, format!("invalid color `{}` for {}", value, key)))?,
+                None => return Err(invalid("<base16>", yaml, key, format!("missing {}", key))),
+            };
+        }
+
+        let name = get("name").or_else(|| get("scheme")).unwrap_or("base16");
+        let mut theme = Theme::new(name).with_palette(ColorPalette {
+            primary: base[0x0D],
+            secondary: base[0x0E],
+            accent: base[0x0C],
+            background: base[0x00],
+            surface: base[0x01],
+            text: base[0x05],
+            on_primary: base[0x00],
+            on_secondary: base[0x00],
+            on_accent: base[0x00],
+            on_surface: base[0x05],
+            error: base[0x08],
+            warning: base[0x0A],
+            success: base[0x0B],
+            info: base[0x0C],
+            border: base[0x02],
+            disabled: base[0x03],
+        });
+        theme.author = get("author").map(str::to_string);
+        theme.description = get("description").map(str::to_string);
+        theme.generate_default_styles();
+        Ok(theme)
+    }
+
+    /// Convert the theme to a base16 YAML scheme
+    ///
+    /// Slots without a palette counterpart are blended from nearby colors,
+    /// so exporting is lossy but importing the result gives back the
+    /// palette of an imported scheme.
+    pub fn to_base16(&self) -> String {
+        let p = &self.palette;
+        let base = [
+            p.background,
+            p.surface,
+            p.border,
+            p.disabled,
+            p.text.blend(&p.background, 0.25),
+            p.text,
+            p.on_surface,
+            p.text,
+            p.error,
+            p.warning.blend(&p.error, 0.5),
+            p.warning,
+            p.success,
+            p.accent,
+            p.primary,
+            p.secondary,
+            p.error.blend(&p.background, 0.5),
+        ];
+
+        let mut yaml = String::new();
+        yaml.push_str("system: \"base16\"\n");
+        yaml.push_str(&format!("name: {}\n", yaml_string(&self.name)));
+        if let Some(author) = &self.author {
+            yaml.push_str(&format!("author: {}\n", yaml_string(author)));
+        }
+        let variant = if p.background.is_dark() { "dark" } else { "light" };
+        yaml.push_str(&format!("variant: \"{}\"\n", variant));
+        yaml.push_str("palette:\n");
+        for (key, color) in BASE16_KEYS.iter().zip(base) {
+            yaml.push_str(&format!("  {}: \"{}\"\n", key, color.to_hex().to_lowercase()));
+        }
+        yaml
+    }
+
+    /// Load a theme from the `[colors]` section of an Alacritty TOML config
+    ///
+    /// Reads `colors.primary`, `colors.normal` and, when present,
+    /// `colors.bright`. Colors may be written as `#rrggbb` or `0xrrggbb`.
+    pub fn from_alacritty(name: &str, toml: &str) -> Result<Self, ThemeError> {
+        let table: toml::Table = toml.parse().map_err(|error: toml::de::Error| ThemeError::Parse {
+            location: match error.span() {
+                Some(span) => format!("<alacritty>:{}", line_at(toml, span.start)),
+                None => "<alacritty>".to_string(),
+            },
+            message: error.message().to_string(),
+        })?;
+
+        let colors = table.get("colors").and_then(toml::Value::as_table);
+        let section = |name: &str| colors.and_then(|colors| colors.get(name)).and_then(toml::Value::as_table);
+        let color = |section_name: &str, key: &str| -> Result<Option<Color>, ThemeError> {
+            let Some(value) = section(section_name).and_then(|section| section.get(key)) else {
+                return Ok(None);
+            };
+            let text = value.as_str().unwrap_or_default();
+            parse_scheme_color(text).map(Some).ok_or_else(|| {
+                invalid("<alacritty>", toml, key, format!("invalid color for colors.{}.{}", section_name, key))
+            })
+        };
+        let required = |section_name: &str, key: &str| -> Result<Color, ThemeError> {
+            color(section_name, key)?.ok_or_else(|| {
+                invalid("<alacritty>", toml, section_name, format!("missing colors.{}.{}", section_name, key))
+            })
+        };
+
+        let mut normal = [Color::Black; 8];
+        let mut bright = [Color::Black; 8];
+        for (index, key) in ALACRITTY_KEYS.iter().enumerate() {
+            normal[index] = required("normal", key)?;
+            bright[index] = color("bright", key)?.unwrap_or(normal[index]);
+        }
+
+        let scheme = TerminalScheme {
+            foreground: required("primary", "foreground")?,
+            background: required("primary", "background")?,
+            normal,
+            bright,
+        };
+        Ok(scheme.into_theme(name))
+    }
+
+    /// Load a theme from a Windows Terminal color scheme
+    ///
+    /// Takes a single scheme object as found in the `schemes` list of
+    /// `settings.json`, with `name`, `foreground`, `background` and the
+    /// sixteen ANSI colors such as `purple` and `brightBlack`.
+    pub fn from_windows_terminal(json: &str) -> Result<Self, ThemeError> {
+        let scheme: serde_json::Value = serde_json::from_str(json).map_err(|error| ThemeError::Parse {
+            location: format!("<windows-terminal>:{}", error.line()),
+            message: error.to_string(),
+        })?;
+
+        let color = |key: &str| -> Result<Color, ThemeError> {
+            let value = scheme.get(key).and_then(serde_json::Value::as_str).ok_or_else(|| {
+                invalid("<windows-terminal>", json, "", format!("missing {}", key))
+            })?;
+            parse_scheme_color(value)
+                .ok_or_else(|| invalid("<windows-terminal>", json, key, format!("invalid color `{}` for {}", value, key)))
+        };
+
+        let mut normal = [Color::Black; 8];
+        let mut bright = [Color::Black; 8];
+        for (index, key) in WINDOWS_TERMINAL_KEYS.iter().enumerate() {
+            normal[index] = color(key)?;
+            bright[index] = color(&format!("bright{}{}", key[..1].to_uppercase(), &key[1..]))?;
+        }
+
+        let name = scheme.get("name").and_then(serde_json::Value::as_str).unwrap_or("windows-terminal");
+        let scheme = TerminalScheme {
+            foreground: color("foreground")?,
+            background: color("background")?,
+            normal,
+            bright,
+        };
+        Ok(scheme.into_theme(name))
+    }
+}
+
+/// The colors shared by terminal emulator schemes
+struct TerminalScheme {
+    foreground: Color,
+    background: Color,
+    /// Black, red, green, yellow, blue, magenta, cyan and white
+    normal: [Color; 8],
+    /// Bright variants of `normal`
+    bright: [Color; 8],
+}
+
+impl TerminalScheme {
+    /// Map the scheme onto a palette and generate the default styles
+    fn into_theme(self, name: &str) -> Theme {
+        let [_, red, green, yellow, blue, magenta, cyan, _] = self.normal;
+        let mut theme = Theme::new(name).with_palette(ColorPalette {
+            primary: blue,
+            secondary: magenta,
+            accent: cyan,
+            background: self.background,
+            surface: self.background.blend(&self.foreground, 0.1),
+            text: self.foreground,
+            on_primary: self.background,
+            on_secondary: self.background,
+            on_accent: self.background,
+            on_surface: self.foreground,
+            error: red,
+            warning: yellow,
+            success: green,
+            info: cyan,
+            border: self.bright[0],
+            disabled: self.bright[0],
+        });
+        theme.generate_default_styles();
+        theme
+    }
+}
+
+/// Parse a scheme color written as `rrggbb`, `#rrggbb` or `0xrrggbb`
+fn parse_scheme_color(value: &str) -> Option<Color> {
+    let hex = value.trim();
+    let hex = hex
+        .strip_prefix('#')
+        .or_else(|| hex.strip_prefix("0x"))
+        .or_else(|| hex.strip_prefix("0X"))
+        .unwrap_or(hex);
+    Color::from_hex(hex).ok()
+}
+
+/// Create an error located at the first line mentioning `needle`
+fn invalid(source: &str, text: &str, needle: &str, message: String) -> ThemeError {
+    let location = match find_token(text, needle) {
+        Some(offset) => format!("{}:{}", source, line_at(text, offset)),
+        None => source.to_string(),
+    };
+    ThemeError::Invalid { location, message }
+}
+
+/// Parse the subset of YAML used by base16 schemes
+///
+/// Returns `key: value` pairs with their line numbers. Keys nested one
+/// level deep, such as those under `palette:`, are flattened.
+fn parse_yaml(text: &str) -> Result<BTreeMap<String, (String, usize)>, ThemeError> {
+    let mut values = BTreeMap::new();
+    for (index, line) in text.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed == "---" {
+            continue;
+        }
+        // Lists, such as a scheme's `tags`, aren't used
+        if trimmed == "-" || trimmed.starts_with("- ") {
+            continue;
+        }
+        let Some((key, value)) = trimmed.split_once(':') else {
+            return Err(ThemeError::Parse {
+                location: format!("<base16>:{}", index + 1),
+                message: format!("expected `key: value`, found `{}`", trimmed),
+            });
+        };
+
+        let value = value.trim();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => unquote(&value[1..], quote).ok_or_else(|| ThemeError::Parse {
+                location: format!("<base16>:{}", index + 1),
+                message: "unterminated string".to_string(),
+            })?,
+            _ => value.split(" #").next().unwrap_or_default().trim().to_string(),
+        };
+        values.insert(key.trim().to_string(), (value, index + 1));
+    }
+    Ok(values)
+}
+
+/// Read a quoted YAML scalar up to its closing quote
+///
+/// Double-quoted strings support backslash escapes, and single-quoted
+/// strings write a quote as `''`. Returns `None` if the string isn't closed.
+fn unquote(text: &str, quote: char) -> Option<String> {
+    let mut value = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if quote == '\'' && chars.peek() == Some(&'\'') => {
+                chars.next();
+                value.push('\'');
+            }
+            '\\' if quote == '"' => {
+                let escaped = match chars.next()? {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    '0' => '\0',
+                    'x' => hex_escape(&mut chars, 2)?,
+                    'u' => hex_escape(&mut chars, 4)?,
+                    'U' => hex_escape(&mut chars, 8)?,
+                    other => other,
+                };
+                value.push(escaped);
+            }
+            c if c == quote => return Some(value),
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// Read the code point of a `\x`, `\u` or `\U` escape
+fn hex_escape(chars: &mut impl Iterator<Item = char>, digits: usize) -> Option<char> {
+    let hex: String = chars.take(digits).collect();
+    if hex.len() != digits {
+        return None;
+    }
+    u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32)
+}
+
+/// Write a string as a double-quoted YAML scalar
+fn yaml_string(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOMORROW_NIGHT: &str = r#"
+scheme: "Tomorrow Night"
+author: "Chris Kempson (http://chriskempson.com)"
+base00: "1d1f21" # background
+base01: "282a2e"
+base02: "373b41"
+base03: "969896"
+base04: "b4b7b4"
+base05: "c5c8c6"
+base06: "e0e0e0"
+base07: "ffffff"
+base08: "cc6666"
+base09: "de935f"
+base0A: "f0c674"
+base0B: "b5bd68"
+base0C: "8abeb7"
+base0D: "81a2be"
+base0E: "b294bb"
+base0F: "a3685a"
+"#;
+
+    #[test]
+    fn test_base16_import() {
+        let theme = Theme::from_base16(TOMORROW_NIGHT).unwrap();
+        assert_eq!(theme.name, "Tomorrow Night");
+        assert_eq!(theme.author.as_deref(), Some("Chris Kempson (http://chriskempson.com)"));
+        assert_eq!(theme.palette.background, Color::Rgb(0x1d, 0x1f, 0x21));
+        assert_eq!(theme.palette.primary, Color::Rgb(0x81, 0xa2, 0xbe));
+        assert_eq!(theme.palette.error, Color::Rgb(0xcc, 0x66, 0x66));
+        assert_eq!(theme.get_style("default").unwrap().background, Some(theme.palette.background));
+
+        let error = Theme::from_base16("scheme: \"x\"\nbase00: \"nothex\"\n").unwrap_err();
+        assert_eq!(error.to_string(), "<base16>:2: invalid color `nothex` for base00");
+        assert!(matches!(Theme::from_base16("scheme \"x\""), Err(ThemeError::Parse { .. })));
+
+        // Lists such as tags are skipped, and bad colors are errors, not panics
+        let tagged = format!("{}tags:\n  - dark\n  - warm\n", TOMORROW_NIGHT);
+        assert_eq!(Theme::from_base16(&tagged).unwrap().name, "Tomorrow Night");
+        let error = Theme::from_base16(&TOMORROW_NIGHT.replace("cc6666", "aé123")).unwrap_err();
+        assert!(error.to_string().contains("invalid color `aé123` for base08"));
+        assert_eq!(Theme::from_base16("scheme: 'It''s'\n").unwrap_err().to_string(), "<base16>: missing base00");
+    }
+
+    #[test]
+    fn test_base16_round_trip() {
+        let theme = Theme::from_base16(TOMORROW_NIGHT).unwrap();
+        let yaml = theme.to_base16();
+        assert!(yaml.contains("variant: \"dark\""));
+        assert!(yaml.contains("  base0D: \"#81a2be\""));
+
+        let imported = Theme::from_base16(&yaml).unwrap();
+        assert_eq!(imported.name, theme.name);
+        assert_eq!(imported.author, theme.author);
+
+        // Names with quotes, backslashes and control characters survive
+        let mut named = theme.clone();
+        named.name = "Ros\u{e9} \"Pine\" \\ Moon\u{7}".to_string();
+        named.author = Some("O'Brien".to_string());
+        let imported = Theme::from_base16(&named.to_base16()).unwrap();
+        assert_eq!(imported.name, named.name);
+        assert_eq!(imported.author, named.author);
+        let (a, b) = (&imported.palette, &theme.palette);
+        assert_eq!(
+            [a.primary, a.secondary, a.accent, a.background, a.surface, a.text, a.error, a.warning, a.success, a.border, a.disabled],
+            [b.primary, b.secondary, b.accent, b.background, b.surface, b.text, b.error, b.warning, b.success, b.border, b.disabled],
+        );
+    }
+
+    #[test]
+    fn test_alacritty_import() {
+        let theme = Theme::from_alacritty("gruvbox", r##"
+            [colors.primary]
+            background = "0x282828"
+            foreground = "#ebdbb2"
+
+            [colors.normal]
+            black = "#282828"
+            red = "#cc241d"
+            green = "#98971a"
+            yellow = "#d79921"
+            blue = "#458588"
+            magenta = "#b16286"
+            cyan = "#689d6a"
+            white = "#a89984"
+
+            [colors.bright]
+            black = "#928374"
+        "##).unwrap();
+
+        assert_eq!(theme.name, "gruvbox");
+        assert_eq!(theme.palette.background, Color::Rgb(0x28, 0x28, 0x28));
+        assert_eq!(theme.palette.text, Color::Rgb(0xeb, 0xdb, 0xb2));
+        assert_eq!(theme.palette.primary, Color::Rgb(0x45, 0x85, 0x88));
+        assert_eq!(theme.palette.border, Color::Rgb(0x92, 0x83, 0x74));
+        assert!(theme.get_style("button").is_some());
+
+        let error = Theme::from_alacritty("x", "[colors.primary]\nbackground = \"#000000\"\n").unwrap_err();
+        assert!(error.to_string().contains("missing colors.normal.black"));
+    }
+
+    #[test]
+    fn test_windows_terminal_import() {
+        let theme = Theme::from_windows_terminal(r##"{
+            "name": "Campbell",
+            "foreground": "#CCCCCC",
+            "background": "#0C0C0C",
+            "black": "#0C0C0C", "red": "#C50F1F", "green": "#13A10E", "yellow": "#C19C00",
+            "blue": "#0037DA", "purple": "#881798", "cyan": "#3A96DD", "white": "#CCCCCC",
+            "brightBlack": "#767676", "brightRed": "#E74856", "brightGreen": "#16C60C",
+            "brightYellow": "#F9F1A5", "brightBlue": "#3B78FF", "brightPurple": "#B4009E",
+            "brightCyan": "#61D6D6", "brightWhite": "#F2F2F2"
+        }"##).unwrap();
+
+        assert_eq!(theme.name, "Campbell");
+        assert_eq!(theme.palette.secondary, Color::Rgb(0x88, 0x17, 0x98));
+        assert_eq!(theme.palette.success, Color::Rgb(0x13, 0xa1, 0x0e));
+        assert_eq!(theme.palette.disabled, Color::Rgb(0x76, 0x76, 0x76));
+
+        let error = Theme::from_windows_terminal(r#"{"name": "x"}"#).unwrap_err();
+        assert_eq!(error.to_string(), "<windows-terminal>: missing black");
+    }
+}
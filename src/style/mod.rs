@@ -3,13 +3,32 @@
 //! This module contains the styling system for VibeUI applications,
 //! including colors, styles, and styling utilities.
 
+pub mod ansi;
+pub mod audit;
 pub mod color;
+pub mod depth;
+pub mod gradient;
+pub mod import;
+mod named;
+pub mod space;
 pub mod style;
+pub mod stylesheet;
+pub mod symbols;
+pub mod text;
 pub mod theme;
+pub mod vision;
 
+pub use audit::{ContrastCheck, ContrastReport, WcagLevel};
 pub use color::Color;
+pub use depth::ColorDepth;
+pub use gradient::Gradient;
+pub use space::{Hsl, Hsv, Oklab, Oklch};
 pub use style::Style;
-pub use theme::Theme;
+pub use stylesheet::{PseudoClass, StyleSelector, StyleTarget, Stylesheet};
+pub use symbols::{BorderSet, Symbols};
+pub use text::{Line, Span, Text};
+pub use theme::{Appearance, Theme, ThemeError};
+pub use vision::ColorVision;
 
 /// Style-wide error type
 #[derive(Debug, thiserror::Error)]
@@ -22,6 +41,12 @@ pub enum Error {
     
     #[error("Theme error: {0}")]
     Theme(String),
+
+    #[error("Stylesheet error at line {line}: {message}")]
+    Stylesheet { line: usize, message: String },
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 /// Result type used throughout the style system
@@ -0,0 +1,315 @@
+//! Color spaces and color adjustments
+//!
+//! Conversions between sRGB and HSL, HSV, OKLab and OKLCH. OKLab is
+//! perceptually uniform, so distances and interpolation in it match how
+//! different colors look.
+
+use crate::style::Color;
+
+/// A color as hue, saturation and lightness
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    /// Hue in degrees (0.0 to 360.0)
+    pub h: f32,
+    /// Saturation (0.0 to 1.0)
+    pub s: f32,
+    /// Lightness (0.0 to 1.0)
+    pub l: f32,
+}
+
+/// A color as hue, saturation and value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    /// Hue in degrees (0.0 to 360.0)
+    pub h: f32,
+    /// Saturation (0.0 to 1.0)
+    pub s: f32,
+    /// Value (0.0 to 1.0)
+    pub v: f32,
+}
+
+/// A color in the OKLab perceptual color space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    /// Perceived lightness (0.0 to 1.0)
+    pub l: f32,
+    /// Green to red axis
+    pub a: f32,
+    /// Blue to yellow axis
+    pub b: f32,
+}
+
+/// A color in OKLab's polar form: lightness, chroma and hue
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    /// Perceived lightness (0.0 to 1.0)
+    pub l: f32,
+    /// Chroma, 0.0 for grays and up to about 0.37 for sRGB colors
+    pub c: f32,
+    /// Hue in degrees (0.0 to 360.0)
+    pub h: f32,
+}
+
+impl Oklab {
+    /// Convert to polar form
+    pub fn to_oklch(&self) -> Oklch {
+        Oklch {
+            l: self.l,
+            c: self.a.hypot(self.b),
+            h: self.b.atan2(self.a).to_degrees().rem_euclid(360.0),
+        }
+    }
+
+    /// Interpolate towards another color
+    pub fn lerp(&self, other: &Oklab, t: f32) -> Oklab {
+        Oklab {
+            l: self.l + (other.l - self.l) * t,
+            a: self.a + (other.a - self.a) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+
+    /// Get the perceptual distance to another color
+    pub fn distance(&self, other: &Oklab) -> f32 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2)).sqrt()
+    }
+}
+
+impl Oklch {
+    /// Convert to rectangular form
+    pub fn to_oklab(&self) -> Oklab {
+        let h = self.h.to_radians();
+        Oklab { l: self.l, a: self.c * h.cos(), b: self.c * h.sin() }
+    }
+}
+
+impl Color {
+    /// Convert to HSL
+    pub fn to_hsl(&self) -> Hsl {
+        let (h, max, min) = hue_and_range(*self);
+        let l = (max + min) / 2.0;
+        let s = if max == min { 0.0 } else { (max - min) / (1.0 - (2.0 * l - 1.0).abs()) };
+        Hsl { h, s, l }
+    }
+
+    /// Create a color from HSL
+    pub fn from_hsl(hsl: Hsl) -> Self {
+        let (s, l) = (hsl.s.clamp(0.0, 1.0), hsl.l.clamp(0.0, 1.0));
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        from_hue(hsl.h, chroma, l - chroma / 2.0)
+    }
+
+    /// Convert to HSV
+    pub fn to_hsv(&self) -> Hsv {
+        let (h, max, min) = hue_and_range(*self);
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Hsv { h, s, v: max }
+    }
+
+    /// Create a color from HSV
+    pub fn from_hsv(hsv: Hsv) -> Self {
+        let (s, v) = (hsv.s.clamp(0.0, 1.0), hsv.v.clamp(0.0, 1.0));
+        let chroma = v * s;
+        from_hue(hsv.h, chroma, v - chroma)
+    }
+
+    /// Convert to OKLab
+    pub fn to_oklab(&self) -> Oklab {
+        let [r, g, b] = self.as_linear_rgb().map(f64::from);
+        let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+        let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+        let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+        Oklab {
+            l: (0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s) as f32,
+            a: (1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s) as f32,
+            b: (0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s) as f32,
+        }
+    }
+
+    /// Create a color from OKLab, clamping to the sRGB gamut
+    pub fn from_oklab(lab: Oklab) -> Self {
+        let (big_l, a, b) = (f64::from(lab.l), f64::from(lab.a), f64::from(lab.b));
+        let l = (big_l + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+        let m = (big_l - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+        let s = (big_l - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+        Color::from_linear_rgb([
+            (4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s) as f32,
+            (-1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s) as f32,
+            (-0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s) as f32,
+        ])
+    }
+
+    /// Convert to OKLCH
+    pub fn to_oklch(&self) -> Oklch {
+        self.to_oklab().to_oklch()
+    }
+
+    /// Create a color from OKLCH, clamping to the sRGB gamut
+    pub fn from_oklch(lch: Oklch) -> Self {
+        Color::from_oklab(lch.to_oklab())
+    }
+
+    /// Make the color lighter by adding to its HSL lightness
+    ///
+    /// `lighten(0.1)` adds 10 percentage points, like Sass.
+    pub fn lighten(&self, amount: f32) -> Color {
+        let mut hsl = self.to_hsl();
+        hsl.l += amount;
+        Color::from_hsl(hsl)
+    }
+
+    /// Make the color darker by subtracting from its HSL lightness
+    pub fn darken(&self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Make the color more vivid by adding to its HSL saturation
+    pub fn saturate(&self, amount: f32) -> Color {
+        let mut hsl = self.to_hsl();
+        hsl.s += amount;
+        Color::from_hsl(hsl)
+    }
+
+    /// Make the color duller by subtracting from its HSL saturation
+    pub fn desaturate(&self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// Rotate the hue by a number of degrees
+    pub fn rotate_hue(&self, degrees: f32) -> Color {
+        let mut hsl = self.to_hsl();
+        hsl.h = (hsl.h + degrees).rem_euclid(360.0);
+        Color::from_hsl(hsl)
+    }
+
+    /// Mix with another color in OKLab
+    ///
+    /// Unlike `blend`, which works on raw sRGB values, the midpoint of a
+    /// perceptual mix looks halfway between the colors.
+    pub fn mix(&self, other: &Color, factor: f32) -> Color {
+        Color::from_oklab(self.to_oklab().lerp(&other.to_oklab(), factor.clamp(0.0, 1.0)))
+    }
+
+    /// Get the perceptual distance to another color in OKLab
+    pub fn distance(&self, other: &Color) -> f32 {
+        self.to_oklab().distance(&other.to_oklab())
+    }
+
+    /// Get `count` progressively darker shades, ending just before black
+    pub fn shades(&self, count: usize) -> Vec<Color> {
+        (1..=count)
+            .map(|step| self.mix(&Color::Rgb(0, 0, 0), step as f32 / (count + 1) as f32))
+            .collect()
+    }
+
+    /// Get `count` progressively lighter tints, ending just before white
+    pub fn tints(&self, count: usize) -> Vec<Color> {
+        (1..=count)
+            .map(|step| self.mix(&Color::Rgb(255, 255, 255), step as f32 / (count + 1) as f32))
+            .collect()
+    }
+}
+
+/// Get the hue in degrees and the largest and smallest sRGB components
+fn hue_and_range(color: Color) -> (f32, f32, f32) {
+    let (r, g, b) = color.as_rgb();
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (h, max, min)
+}
+
+/// Build an sRGB color from a hue, chroma and the smallest component
+fn from_hue(hue: f32, chroma: f32, min: f32) -> Color {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let channel = |c: f32| ((c + min).clamp(0.0, 1.0) * 255.0).round() as u8;
+    Color::Rgb(channel(r), channel(g), channel(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hsl_hsv_round_trip() {
+        let orange = Color::Rgb(255, 165, 0);
+        let hsl = orange.to_hsl();
+        assert!((hsl.h - 38.8).abs() < 0.1);
+        assert!((hsl.s - 1.0).abs() < 0.001);
+        assert!((hsl.l - 0.5).abs() < 0.001);
+        assert_eq!(Color::from_hsl(hsl), orange);
+
+        let hsv = orange.to_hsv();
+        assert!((hsv.v - 1.0).abs() < 0.001);
+        assert_eq!(Color::from_hsv(hsv), orange);
+
+        for color in [Color::Rgb(12, 34, 56), Color::Rgb(200, 10, 150), Color::Rgb(128, 128, 128)] {
+            assert_eq!(Color::from_hsl(color.to_hsl()), color);
+            assert_eq!(Color::from_hsv(color.to_hsv()), color);
+        }
+    }
+
+    #[test]
+    fn test_oklab_round_trip() {
+        let white = Color::Rgb(255, 255, 255).to_oklab();
+        assert!((white.l - 1.0).abs() < 0.001);
+        assert!(white.a.abs() < 0.001 && white.b.abs() < 0.001);
+
+        for color in [Color::Rgb(255, 0, 0), Color::Rgb(12, 34, 56), Color::Rgb(240, 230, 140)] {
+            assert_eq!(Color::from_oklab(color.to_oklab()), color);
+            assert_eq!(Color::from_oklch(color.to_oklch()), color);
+        }
+
+        let red = Color::Rgb(255, 0, 0).to_oklch();
+        assert!((red.l - 0.628).abs() < 0.001);
+        assert!((red.c - 0.2577).abs() < 0.001);
+        assert!((red.h - 29.23).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_adjustments() {
+        let red = Color::Rgb(255, 0, 0);
+        assert_eq!(red.lighten(0.25), Color::Rgb(255, 128, 128));
+        assert_eq!(red.darken(0.25), Color::Rgb(128, 0, 0));
+        assert_eq!(red.desaturate(1.0), Color::Rgb(128, 128, 128));
+        assert_eq!(red.rotate_hue(120.0), Color::Rgb(0, 255, 0));
+        assert_eq!(Color::Rgb(191, 64, 64).saturate(0.5), Color::Rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_mix_and_shades() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        assert_eq!(black.mix(&white, 0.0), black);
+        assert_eq!(black.mix(&white, 1.0), white);
+        // The perceptual midpoint is halfway in OKLab lightness
+        assert!((black.mix(&white, 0.5).to_oklab().l - 0.5).abs() < 0.01);
+
+        let blue = Color::Rgb(30, 100, 200);
+        let shades = blue.shades(3);
+        let tints = blue.tints(3);
+        assert_eq!(shades.len(), 3);
+        assert!(shades.windows(2).all(|pair| pair[0].relative_luminance() > pair[1].relative_luminance()));
+        assert!(tints.windows(2).all(|pair| pair[0].relative_luminance() < pair[1].relative_luminance()));
+        assert!(blue.distance(&shades[0]) < blue.distance(&shades[2]));
+    }
+}
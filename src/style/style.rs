@@ -5,6 +5,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Style definition for UI elements
+///
+/// Colors and attributes left as `None` keep those of the style underneath
+/// when styles are applied on top of each other, so `Some(false)` can turn
+/// an attribute off.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Style {
     /// Foreground color
@@ -12,21 +16,29 @@ pub struct Style {
     /// Background color
     pub background: Option<Color>,
     /// Bold text
-    pub bold: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
     /// Italic text
-    pub italic: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
     /// Underlined text
-    pub underline: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underline: Option<bool>,
     /// Dim text
-    pub dim: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dim: Option<bool>,
     /// Blinking text
-    pub blink: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blink: Option<bool>,
     /// Reverse video
-    pub reverse: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reverse: Option<bool>,
     /// Hidden text
-    pub hidden: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
     /// Strikethrough text
-    pub strikethrough: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strikethrough: Option<bool>,
 }
 
 impl Default for Style {
@@ -34,14 +46,14 @@ impl Default for Style {
         Self {
             foreground: None,
             background: None,
-            bold: false,
-            italic: false,
-            underline: false,
-            dim: false,
-            blink: false,
-            reverse: false,
-            hidden: false,
-            strikethrough: false,
+            bold: None,
+            italic: None,
+            underline: None,
+            dim: None,
+            blink: None,
+            reverse: None,
+            hidden: None,
+            strikethrough: None,
         }
     }
 }
@@ -66,49 +78,49 @@ impl Style {
 
     /// Set bold
     pub fn with_bold(mut self, bold: bool) -> Self {
-        self.bold = bold;
+        self.bold = Some(bold);
         self
     }
 
     /// Set italic
     pub fn with_italic(mut self, italic: bool) -> Self {
-        self.italic = italic;
+        self.italic = Some(italic);
         self
     }
 
     /// Set underline
     pub fn with_underline(mut self, underline: bool) -> Self {
-        self.underline = underline;
+        self.underline = Some(underline);
         self
     }
 
     /// Set dim
     pub fn with_dim(mut self, dim: bool) -> Self {
-        self.dim = dim;
+        self.dim = Some(dim);
         self
     }
 
     /// Set blink
     pub fn with_blink(mut self, blink: bool) -> Self {
-        self.blink = blink;
+        self.blink = Some(blink);
         self
     }
 
     /// Set reverse
     pub fn with_reverse(mut self, reverse: bool) -> Self {
-        self.reverse = reverse;
+        self.reverse = Some(reverse);
         self
     }
 
     /// Set hidden
     pub fn with_hidden(mut self, hidden: bool) -> Self {
-        self.hidden = hidden;
+        self.hidden = Some(hidden);
         self
     }
 
     /// Set strikethrough
     pub fn with_strikethrough(mut self, strikethrough: bool) -> Self {
-        self.strikethrough = strikethrough;
+        self.strikethrough = Some(strikethrough);
         self
     }
 
@@ -123,14 +135,14 @@ impl Style {
     pub fn reset(mut self) -> Self {
         self.foreground = None;
         self.background = None;
-        self.bold = false;
-        self.italic = false;
-        self.underline = false;
-        self.dim = false;
-        self.blink = false;
-        self.reverse = false;
-        self.hidden = false;
-        self.strikethrough = false;
+        self.bold = None;
+        self.italic = None;
+        self.underline = None;
+        self.dim = None;
+        self.blink = None;
+        self.reverse = None;
+        self.hidden = None;
+        self.strikethrough = None;
         self
     }
 
@@ -138,17 +150,19 @@ impl Style {
     pub fn is_empty(&self) -> bool {
         self.foreground.is_none()
             && self.background.is_none()
-            && !self.bold
-            && !self.italic
-            && !self.underline
-            && !self.dim
-            && !self.blink
-            && !self.reverse
-            && !self.hidden
-            && !self.strikethrough
+            && self.bold.is_none()
+            && self.italic.is_none()
+            && self.underline.is_none()
+            && self.dim.is_none()
+            && self.blink.is_none()
+            && self.reverse.is_none()
+            && self.hidden.is_none()
+            && self.strikethrough.is_none()
     }
 
     /// Apply another style on top of this one
+    ///
+    /// Colors and attributes the other style sets replace this style's.
     pub fn apply(&mut self, other: &Style) {
         if other.foreground.is_some() {
             self.foreground = other.foreground;
@@ -156,29 +170,29 @@ impl Style {
         if other.background.is_some() {
             self.background = other.background;
         }
-        if other.bold {
-            self.bold = true;
+        if other.bold.is_some() {
+            self.bold = other.bold;
         }
-        if other.italic {
-            self.italic = true;
+        if other.italic.is_some() {
+            self.italic = other.italic;
         }
-        if other.underline {
-            self.underline = true;
+        if other.underline.is_some() {
+            self.underline = other.underline;
         }
-        if other.dim {
-            self.dim = true;
+        if other.dim.is_some() {
+            self.dim = other.dim;
         }
-        if other.blink {
-            self.blink = true;
+        if other.blink.is_some() {
+            self.blink = other.blink;
         }
-        if other.reverse {
-            self.reverse = true;
+        if other.reverse.is_some() {
+            self.reverse = other.reverse;
         }
-        if other.hidden {
-            self.hidden = true;
+        if other.hidden.is_some() {
+            self.hidden = other.hidden;
         }
-        if other.strikethrough {
-            self.strikethrough = true;
+        if other.strikethrough.is_some() {
+            self.strikethrough = other.strikethrough;
         }
     }
 
@@ -206,19 +220,19 @@ impl Style {
 
     /// Make the style dim
     pub fn dim(&mut self) {
-        self.dim = true;
+        self.dim = Some(true);
     }
 
     /// Create a new dim style
     pub fn dimmed(&self) -> Style {
         let mut result = *self;
-        result.dim = true;
+        result.dim = Some(true);
         result
     }
 
     /// Make the style bright
     pub fn bright(&mut self) {
-        self.dim = false;
+        self.dim = Some(false);
     }
 
     /// Create a new bright style
@@ -230,7 +244,7 @@ impl Style {
 
     /// Get the effective foreground color (considering reverse)
     pub fn effective_foreground(&self) -> Option<Color> {
-        if self.reverse {
+        if self.reverse == Some(true) {
             self.background
         } else {
             self.foreground
@@ -239,7 +253,7 @@ impl Style {
 
     /// Get the effective background color (considering reverse)
     pub fn effective_background(&self) -> Option<Color> {
-        if self.reverse {
+        if self.reverse == Some(true) {
             self.foreground
         } else {
             self.background
@@ -253,14 +267,14 @@ impl Style {
 
         for part in parts {
             match part.to_lowercase().as_str() {
-                "bold" => style.bold = true,
-                "italic" => style.italic = true,
-                "underline" => style.underline = true,
-                "dim" => style.dim = true,
-                "blink" => style.blink = true,
-                "reverse" => style.reverse = true,
-                "hidden" => style.hidden = true,
-                "strikethrough" => style.strikethrough = true,
+                "bold" => style.bold = Some(true),
+                "italic" => style.italic = Some(true),
+                "underline" => style.underline = Some(true),
+                "dim" => style.dim = Some(true),
+                "blink" => style.blink = Some(true),
+                "reverse" => style.reverse = Some(true),
+                "hidden" => style.hidden = Some(true),
+                "strikethrough" => style.strikethrough = Some(true),
                 _ => {
                     // Try to parse as color
                     if let Some(color_part) = part.strip_prefix("fg=") {
@@ -288,28 +302,28 @@ impl Style {
         if let Some(bg) = self.background {
             parts.push(format!("bg={}", bg));
         }
-        if self.bold {
+        if self.bold == Some(true) {
             parts.push("bold".to_string());
         }
-        if self.italic {
+        if self.italic == Some(true) {
             parts.push("italic".to_string());
         }
-        if self.underline {
+        if self.underline == Some(true) {
             parts.push("underline".to_string());
         }
-        if self.dim {
+        if self.dim == Some(true) {
             parts.push("dim".to_string());
         }
-        if self.blink {
+        if self.blink == Some(true) {
             parts.push("blink".to_string());
         }
-        if self.reverse {
+        if self.reverse == Some(true) {
             parts.push("reverse".to_string());
         }
-        if self.hidden {
+        if self.hidden == Some(true) {
             parts.push("hidden".to_string());
         }
-        if self.strikethrough {
+        if self.strikethrough == Some(true) {
             parts.push("strikethrough".to_string());
         }
 
@@ -350,49 +364,49 @@ impl StyleBuilder {
 
     /// Set bold
     pub fn bold(mut self, bold: bool) -> Self {
-        self.style.bold = bold;
+        self.style.bold = Some(bold);
         self
     }
 
     /// Set italic
     pub fn italic(mut self, italic: bool) -> Self {
-        self.style.italic = italic;
+        self.style.italic = Some(italic);
         self
     }
 
     /// Set underline
     pub fn underline(mut self, underline: bool) -> Self {
-        self.style.underline = underline;
+        self.style.underline = Some(underline);
         self
     }
 
     /// Set dim
     pub fn dim(mut self, dim: bool) -> Self {
-        self.style.dim = dim;
+        self.style.dim = Some(dim);
         self
     }
 
     /// Set blink
     pub fn blink(mut self, blink: bool) -> Self {
-        self.style.blink = blink;
+        self.style.blink = Some(blink);
         self
     }
 
     /// Set reverse
     pub fn reverse(mut self, reverse: bool) -> Self {
-        self.style.reverse = reverse;
+        self.style.reverse = Some(reverse);
         self
     }
 
     /// Set hidden
     pub fn hidden(mut self, hidden: bool) -> Self {
-        self.style.hidden = hidden;
+        self.style.hidden = Some(hidden);
         self
     }
 
     /// Set strikethrough
     pub fn strikethrough(mut self, strikethrough: bool) -> Self {
-        self.style.strikethrough = strikethrough;
+        self.style.strikethrough = Some(strikethrough);
         self
     }
 
@@ -416,140 +430,140 @@ pub mod presets {
     pub const DEFAULT: Style = Style {
         foreground: None,
         background: None,
-        bold: false,
-        italic: false,
-        underline: false,
-        dim: false,
-        blink: false,
-        reverse: false,
-        hidden: false,
-        strikethrough: false,
+        bold: None,
+        italic: None,
+        underline: None,
+        dim: None,
+        blink: None,
+        reverse: None,
+        hidden: None,
+        strikethrough: None,
     };
 
     /// Normal text style
     pub const NORMAL: Style = Style {
         foreground: Some(palette::WHITE),
         background: Some(palette::BLACK),
-        bold: false,
-        italic: false,
-        underline: false,
-        dim: false,
-        blink: false,
-        reverse: false,
-        hidden: false,
-        strikethrough: false,
+        bold: None,
+        italic: None,
+        underline: None,
+        dim: None,
+        blink: None,
+        reverse: None,
+        hidden: None,
+        strikethrough: None,
     };
 
     /// Header style
     pub const HEADER: Style = Style {
         foreground: Some(palette::BRIGHT_WHITE),
         background: Some(palette::BLUE),
-        bold: true,
-        italic: false,
-        underline: false,
-        dim: false,
-        blink: false,
-        reverse: false,
-        hidden: false,
-        strikethrough: false,
+        bold: Some(true),
+        italic: None,
+        underline: None,
+        dim: None,
+        blink: None,
+        reverse: None,
+        hidden: None,
+        strikethrough: None,
     };
 
     /// Warning style
     pub const WARNING: Style = Style {
         foreground: Some(palette::BLACK),
         background: Some(palette::YELLOW),
-        bold: true,
-        italic: false,
-        underline: false,
-        dim: false,
-        blink: false,
-        reverse: false,
-        hidden: false,
-        strikethrough: false,
+        bold: Some(true),
+        italic: None,
+        underline: None,
+        dim: None,
+        blink: None,
+        reverse: None,
+        hidden: None,
+        strikethrough: None,
     };
 
     /// Error style
     pub const ERROR: Style = Style {
         foreground: Some(palette::WHITE),
         background: Some(palette::RED),
-        bold: true,
-        italic: false,
-        underline: false,
-        dim: false,
-        blink: false,
-        reverse: false,
-        hidden: false,
-        strikethrough: false,
+        bold: Some(true),
+        italic: None,
+        underline: None,
+        dim: None,
+        blink: None,
+        reverse: None,
+        hidden: None,
+        strikethrough: None,
     };
 
     /// Success style
     pub const SUCCESS: Style = Style {
         foreground: Some(palette::BLACK),
         background: Some(palette::GREEN),
-        bold: true,
-        italic: false,
-        underline: false,
-        dim: false,
-        blink: false,
-        reverse: false,
-        hidden: false,
-        strikethrough: false,
+        bold: Some(true),
+        italic: None,
+        underline: None,
+        dim: None,
+        blink: None,
+        reverse: None,
+        hidden: None,
+        strikethrough: None,
     };
 
     /// Info style
     pub const INFO: Style = Style {
         foreground: Some(palette::BLACK),
         background: Some(palette::CYAN),
-        bold: false,
-        italic: false,
-        underline: false,
-        dim: false,
-        blink: false,
-        reverse: false,
-        hidden: false,
-        strikethrough: false,
+        bold: None,
+        italic: None,
+        underline: None,
+        dim: None,
+        blink: None,
+        reverse: None,
+        hidden: None,
+        strikethrough: None,
     };
 
     /// Highlight style
     pub const HIGHLIGHT: Style = Style {
         foreground: Some(palette::BLACK),
         background: Some(palette::BRIGHT_YELLOW),
-        bold: true,
-        italic: false,
-        underline: false,
-        dim: false,
-        blink: false,
-        reverse: false,
-        hidden: false,
-        strikethrough: false,
+        bold: Some(true),
+        italic: None,
+        underline: None,
+        dim: None,
+        blink: None,
+        reverse: None,
+        hidden: None,
+        strikethrough: None,
     };
 
     /// Dim style
     pub const DIM: Style = Style {
         foreground: Some(palette::GRAY),
         background: None,
-        bold: false,
-        italic: false,
-        underline: false,
-        dim: true,
-        blink: false,
-        reverse: false,
-        hidden: false,
-        strikethrough: false,
+        bold: None,
+        italic: None,
+        underline: None,
+        dim: Some(true),
+        blink: None,
+        reverse: None,
+        hidden: None,
+        strikethrough: None,
     };
 
     /// Inverse style
     pub const INVERSE: Style = Style {
         foreground: Some(palette::BLACK),
         background: Some(palette::WHITE),
-        bold: false,
-        italic: false,
-        underline: false,
-        dim: false,
-        blink: false,
-        reverse: false,
-        hidden: false,
-        strikethrough: false,
+        bold: None,
+        italic: None,
+        underline: None,
+        dim: None,
+        blink: None,
+        reverse: None,
+        hidden: None,
+        strikethrough: None,
     };
 }
 
@@ -620,8 +634,8 @@ mod tests {
         
         assert_eq!(style.foreground, Some(Color::Red));
         assert_eq!(style.background, Some(Color::Blue));
-        assert!(style.bold);
-        assert!(!style.italic);
+        assert_eq!(style.bold, Some(true));
+        assert_eq!(style.italic, None);
     }
 
     #[test]
@@ -633,8 +647,8 @@ mod tests {
             .build();
         
         assert_eq!(style.foreground, Some(Color::Green));
-        assert!(style.bold);
-        assert!(style.underline);
+        assert_eq!(style.bold, Some(true));
+        assert_eq!(style.underline, Some(true));
     }
 
     #[test]
@@ -646,7 +660,12 @@ mod tests {
         
         assert_eq!(base.foreground, Some(Color::Red));
         assert_eq!(base.background, Some(Color::Blue));
-        assert!(base.bold);
+        assert_eq!(base.bold, Some(true));
+
+        // An attribute set to false turns the one underneath off
+        base.apply(&Style::new().with_bold(false));
+        assert_eq!(base.bold, Some(false));
+        assert_eq!(base.foreground, Some(Color::Red));
     }
 
     #[test]
@@ -667,8 +686,8 @@ mod tests {
         
         assert_eq!(style.foreground, Some(Color::Red));
         assert_eq!(style.background, Some(Color::Blue));
-        assert!(style.bold);
-        assert!(style.underline);
+        assert_eq!(style.bold, Some(true));
+        assert_eq!(style.underline, Some(true));
     }
 
     #[test]
@@ -0,0 +1,643 @@
+//! CSS-like stylesheets resolved against the active theme
+//!
+//! A stylesheet is a list of rules, each a comma-separated list of selectors
+//! and a block of declarations:
+//!
+//! ```text
+//! /* Every button, with the theme's "button" style when there is one */
+//! Button { fg: white; bg: blue; bold; style: button; }
+//! Button:hover { bg: $accent; }
+//! #ok.primary, Label.title { fg: $primary; underline: true; }
+//! ```
+//!
+//! Selectors combine a component type (or `*`), an `#id`, `.class`es and
+//! the `:hover`, `:focus`, `:active`, `:disabled` and `:selected` states.
+//! Matching rules are applied in order of specificity, then order in the
+//! sheet.
+
+use crate::style::theme::{ColorPalette, Theme};
+use crate::style::{Color, Error, Result, Style};
+use std::fmt;
+use std::path::Path;
+
+/// Rules giving the built-in components their default look
+///
+/// Literal colors are used without a theme, and are overridden by the
+/// theme's named styles when it has them.
+const DEFAULT_STYLESHEET: &str = "
+Button { fg: white; bg: blue; bold; style: button; }
+Button:hover { fg: black; bg: cyan; style: button_hover; }
+Button:active { fg: white; bg: red; style: button_active; }
+TextInput { fg: white; bg: black; style: input; }
+TextInput:focus { style: input_focused; }
+:disabled { dim; style: caption; }
+";
+
+/// Interaction state matched by a selector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PseudoClass {
+    /// The mouse is over the component
+    Hover,
+    /// The component has keyboard focus
+    Focus,
+    /// The component is being pressed
+    Active,
+    /// The component ignores input
+    Disabled,
+    /// The component is selected
+    Selected,
+}
+
+impl PseudoClass {
+    /// Parse a pseudo-class name without the leading `:`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "hover" => Some(PseudoClass::Hover),
+            "focus" => Some(PseudoClass::Focus),
+            "active" => Some(PseudoClass::Active),
+            "disabled" => Some(PseudoClass::Disabled),
+            "selected" => Some(PseudoClass::Selected),
+            _ => None,
+        }
+    }
+
+    /// Get the pseudo-class name without the leading `:`
+    pub fn name(&self) -> &'static str {
+        match self {
+            PseudoClass::Hover => "hover",
+            PseudoClass::Focus => "focus",
+            PseudoClass::Active => "active",
+            PseudoClass::Disabled => "disabled",
+            PseudoClass::Selected => "selected",
+        }
+    }
+}
+
+/// What a selector is matched against
+#[derive(Debug, Clone, Copy)]
+pub struct StyleTarget<'a> {
+    /// Component type name, such as `Button`
+    pub kind: &'a str,
+    /// Component ID
+    pub id: &'a str,
+    /// User-defined classes
+    pub classes: &'a [String],
+    /// Current interaction states
+    pub states: &'a [PseudoClass],
+}
+
+impl<'a> StyleTarget<'a> {
+    /// Create a target without classes or states
+    pub fn new(kind: &'a str, id: &'a str) -> Self {
+        Self {
+            kind,
+            id,
+            classes: &[],
+            states: &[],
+        }
+    }
+
+    /// Set the classes
+    pub fn with_classes(mut self, classes: &'a [String]) -> Self {
+        self.classes = classes;
+        self
+    }
+
+    /// Set the interaction states
+    pub fn with_states(mut self, states: &'a [PseudoClass]) -> Self {
+        self.states = states;
+        self
+    }
+}
+
+/// A compound selector such as `Button#ok.primary:hover`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyleSelector {
+    kind: Option<String>,
+    id: Option<String>,
+    classes: Vec<String>,
+    states: Vec<PseudoClass>,
+}
+
+impl StyleSelector {
+    /// Parse a selector
+    pub fn parse(selector: &str) -> Result<Self> {
+        let selector = selector.trim();
+        if selector.is_empty() {
+            return Err(Error::InvalidStyle("empty selector".to_string()));
+        }
+        if selector.contains(char::is_whitespace) {
+            return Err(Error::InvalidStyle(format!(
+                "combinators are not supported in selector `{}`",
+                selector
+            )));
+        }
+
+        let mut parsed = Self::default();
+        let mut rest = selector;
+        if let Some(after) = rest.strip_prefix('*') {
+            rest = after;
+        } else if rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let (kind, after) = split_ident(rest);
+            parsed.kind = Some(kind.to_string());
+            rest = after;
+        }
+
+        while let Some(prefix) = rest.chars().next() {
+            let (name, after) = split_ident(&rest[prefix.len_utf8()..]);
+            if name.is_empty() {
+                return Err(Error::InvalidStyle(format!(
+                    "expected a name after `{}` in selector `{}`",
+                    prefix, selector
+                )));
+            }
+            match prefix {
+                '#' if parsed.id.is_none() => parsed.id = Some(name.to_string()),
+                '#' => {
+                    return Err(Error::InvalidStyle(format!(
+                        "selector `{}` has more than one id",
+                        selector
+                    )))
+                }
+                '.' => parsed.classes.push(name.to_string()),
+                ':' => {
+                    let state = PseudoClass::parse(name).ok_or_else(|| {
+                        Error::InvalidStyle(format!("unknown pseudo-class `:{}`", name))
+                    })?;
+                    parsed.states.push(state);
+                }
+                _ => {
+                    return Err(Error::InvalidStyle(format!(
+                        "unexpected `{}` in selector `{}`",
+                        prefix, selector
+                    )))
+                }
+            }
+            rest = after;
+        }
+
+        Ok(parsed)
+    }
+
+    /// Get the selector's specificity as (ids, classes and states, types)
+    ///
+    /// Higher specificity wins, compared element by element as in CSS.
+    pub fn specificity(&self) -> (usize, usize, usize) {
+        (
+            usize::from(self.id.is_some()),
+            self.classes.len() + self.states.len(),
+            usize::from(self.kind.is_some()),
+        )
+    }
+
+    /// Check if the selector matches a target
+    pub fn matches(&self, target: &StyleTarget) -> bool {
+        self.kind.as_deref().is_none_or(|kind| kind == target.kind)
+            && self.id.as_deref().is_none_or(|id| id == target.id)
+            && self.classes.iter().all(|class| target.classes.contains(class))
+            && self.states.iter().all(|state| target.states.contains(state))
+    }
+}
+
+impl fmt::Display for StyleSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            Some(kind) => write!(f, "{}", kind)?,
+            None if self.id.is_none() && self.classes.is_empty() && self.states.is_empty() => {
+                write!(f, "*")?
+            }
+            None => {}
+        }
+        if let Some(id) = &self.id {
+            write!(f, "#{}", id)?;
+        }
+        for class in &self.classes {
+            write!(f, ".{}", class)?;
+        }
+        for state in &self.states {
+            write!(f, ":{}", state.name())?;
+        }
+        Ok(())
+    }
+}
+
+/// A color in a declaration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorValue {
+    /// A fixed color
+    Color(Color),
+    /// A palette color of the active theme, written `$name`
+    Palette(String),
+}
+
+impl ColorValue {
+    /// Parse a color name, hex value or `$palette` reference
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.strip_prefix('$') {
+            Some(name) if ColorPalette::default().get(name).is_some() => {
+                Ok(ColorValue::Palette(name.to_string()))
+            }
+            Some(name) => Err(Error::InvalidStyle(format!("unknown palette color `${}`", name))),
+            None => Ok(ColorValue::Color(Color::parse(value)?)),
+        }
+    }
+
+    /// Get the color, looking palette references up in the theme
+    pub fn resolve(&self, theme: Option<&Theme>) -> Option<Color> {
+        match self {
+            ColorValue::Color(color) => Some(*color),
+            ColorValue::Palette(name) => theme.and_then(|theme| theme.palette.get(name)),
+        }
+    }
+}
+
+/// A single property set by a rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Declaration {
+    /// Apply a named style from the active theme
+    Style(String),
+    /// Set the foreground color
+    Foreground(ColorValue),
+    /// Set the background color
+    Background(ColorValue),
+    /// Turn bold on or off
+    Bold(bool),
+    /// Turn italic on or off
+    Italic(bool),
+    /// Turn underline on or off
+    Underline(bool),
+    /// Turn dim on or off
+    Dim(bool),
+    /// Turn blink on or off
+    Blink(bool),
+    /// Turn reverse video on or off
+    Reverse(bool),
+    /// Turn hidden on or off
+    Hidden(bool),
+    /// Turn strikethrough on or off
+    Strikethrough(bool),
+}
+
+impl Declaration {
+    /// Parse a `property: value` pair, or a bare attribute name meaning `true`
+    pub fn parse(declaration: &str) -> Result<Self> {
+        let (property, value) = match declaration.split_once(':') {
+            Some((property, value)) => (property.trim(), Some(value.trim())),
+            None => (declaration.trim(), None),
+        };
+
+        let flag = || match value {
+            None | Some("true") => Ok(true),
+            Some("false") => Ok(false),
+            Some(value) => Err(Error::InvalidStyle(format!(
+                "expected true or false for `{}`, found `{}`",
+                property, value
+            ))),
+        };
+        let required = || {
+            value.filter(|value| !value.is_empty()).ok_or_else(|| {
+                Error::InvalidStyle(format!("missing value for `{}`", property))
+            })
+        };
+
+        Ok(match property {
+            "style" => Declaration::Style(required()?.to_string()),
+            "fg" | "foreground" | "color" => Declaration::Foreground(ColorValue::parse(required()?)?),
+            "bg" | "background" => Declaration::Background(ColorValue::parse(required()?)?),
+            "bold" => Declaration::Bold(flag()?),
+            "italic" => Declaration::Italic(flag()?),
+            "underline" => Declaration::Underline(flag()?),
+            "dim" => Declaration::Dim(flag()?),
+            "blink" => Declaration::Blink(flag()?),
+            "reverse" => Declaration::Reverse(flag()?),
+            "hidden" => Declaration::Hidden(flag()?),
+            "strikethrough" => Declaration::Strikethrough(flag()?),
+            _ => return Err(Error::InvalidStyle(format!("unknown property `{}`", property))),
+        })
+    }
+
+    /// Apply the declaration to a style
+    pub fn apply(&self, style: &mut Style, theme: Option<&Theme>) {
+        match self {
+            Declaration::Style(name) => {
+                if let Some(named) = theme.and_then(|theme| theme.get_style(name)) {
+                    style.apply(named);
+                }
+            }
+            Declaration::Foreground(color) => {
+                if let Some(color) = color.resolve(theme) {
+                    style.foreground = Some(color);
+                }
+            }
+            Declaration::Background(color) => {
+                if let Some(color) = color.resolve(theme) {
+                    style.background = Some(color);
+                }
+            }
+            Declaration::Bold(value) => style.bold = Some(*value),
+            Declaration::Italic(value) => style.italic = Some(*value),
+            Declaration::Underline(value) => style.underline = Some(*value),
+            Declaration::Dim(value) => style.dim = Some(*value),
+            Declaration::Blink(value) => style.blink = Some(*value),
+            Declaration::Reverse(value) => style.reverse = Some(*value),
+            Declaration::Hidden(value) => style.hidden = Some(*value),
+            Declaration::Strikethrough(value) => style.strikethrough = Some(*value),
+        }
+    }
+}
+
+/// Selectors and the declarations applied to components they match
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// Selectors, any of which makes the rule apply
+    pub selectors: Vec<StyleSelector>,
+    /// Declarations, applied in order
+    pub declarations: Vec<Declaration>,
+}
+
+impl Rule {
+    /// Get the specificity of the most specific selector matching a target
+    pub fn matches(&self, target: &StyleTarget) -> Option<(usize, usize, usize)> {
+        self.selectors
+            .iter()
+            .filter(|selector| selector.matches(target))
+            .map(StyleSelector::specificity)
+            .max()
+    }
+}
+
+/// An ordered list of style rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stylesheet {
+    rules: Vec<Rule>,
+}
+
+impl Stylesheet {
+    /// Create an empty stylesheet
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Parse a stylesheet
+    pub fn parse(css: &str) -> Result<Self> {
+        let mut stylesheet = Self::new();
+        stylesheet.add_rules(css)?;
+        Ok(stylesheet)
+    }
+
+    /// Load a stylesheet from a file
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let css = std::fs::read_to_string(path)?;
+        Self::parse(&css)
+    }
+
+    /// Parse rules and add them after the existing ones
+    ///
+    /// Nothing is added if any rule fails to parse.
+    pub fn add_rules(&mut self, css: &str) -> Result<()> {
+        let css = strip_comments(css);
+        let mut rules = Vec::new();
+        let mut rest = css.as_str();
+
+        while !rest.trim().is_empty() {
+            let line = line_at(&css, rest.trim_start());
+            let open = rest.find('{').ok_or_else(|| syntax_error(line, "expected `{`"))?;
+            let close = rest.find('}').ok_or_else(|| syntax_error(line, "expected `}`"))?;
+            if close < open {
+                return Err(syntax_error(line_at(&css, &rest[close..]), "unexpected `}`"));
+            }
+
+            let selectors = rest[..open]
+                .split(',')
+                .map(StyleSelector::parse)
+                .collect::<Result<Vec<_>>>()
+                .map_err(|error| stylesheet_error(line, error))?;
+
+            let body = &rest[open + 1..close];
+            if body.contains('{') {
+                return Err(syntax_error(line_at(&css, body), "nested blocks are not supported"));
+            }
+            let declarations = body
+                .split(';')
+                .filter(|declaration| !declaration.trim().is_empty())
+                .map(|declaration| {
+                    Declaration::parse(declaration)
+                        .map_err(|error| stylesheet_error(line_at(&css, declaration.trim_start()), error))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            rules.push(Rule { selectors, declarations });
+            rest = &rest[close + 1..];
+        }
+
+        self.rules.extend(rules);
+        Ok(())
+    }
+
+    /// Add the rules of another stylesheet after the existing ones
+    pub fn extend(&mut self, other: Stylesheet) {
+        self.rules.extend(other.rules);
+    }
+
+    /// Get the rules in order
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Get the number of rules
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Check if the stylesheet has no rules
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Compute the style of a target
+    ///
+    /// Foreground and background colors are inherited from `parent`, then
+    /// matching rules are applied from least to most specific, with later
+    /// rules winning ties.
+    pub fn resolve(&self, target: &StyleTarget, parent: Option<&Style>, theme: Option<&Theme>) -> Style {
+        let mut style = Style::default();
+        if let Some(parent) = parent {
+            style.foreground = parent.foreground;
+            style.background = parent.background;
+        }
+
+        let mut matched: Vec<_> = self
+            .rules
+            .iter()
+            .enumerate()
+            .filter_map(|(index, rule)| Some((rule.matches(target)?, index, rule)))
+            .collect();
+        matched.sort_by_key(|(specificity, index, _)| (*specificity, *index));
+
+        for (_, _, rule) in matched {
+            for declaration in &rule.declarations {
+                declaration.apply(&mut style, theme);
+            }
+        }
+        style
+    }
+}
+
+impl Default for Stylesheet {
+    /// The built-in component styles
+    fn default() -> Self {
+        Self::parse(DEFAULT_STYLESHEET).expect("default stylesheet is valid")
+    }
+}
+
+/// Split a leading identifier off a string
+fn split_ident(s: &str) -> (&str, &str) {
+    let end = s
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+/// Replace `/* ... */` comments with spaces, keeping line breaks
+fn strip_comments(css: &str) -> String {
+    let mut result = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        result.push_str(&rest[..start]);
+        let end = rest[start..].find("*/").map_or(rest.len(), |end| start + end + 2);
+        result.extend(rest[start..end].chars().map(|c| if c == '\n' { '\n' } else { ' ' }));
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Get the 1-based line of a slice within the stylesheet text
+fn line_at(css: &str, slice: &str) -> usize {
+    let offset = slice.as_ptr() as usize - css.as_ptr() as usize;
+    css[..offset].matches('\n').count() + 1
+}
+
+/// Create a stylesheet error at a line
+fn syntax_error(line: usize, message: &str) -> Error {
+    Error::Stylesheet {
+        line,
+        message: message.to_string(),
+    }
+}
+
+/// Attach a line number to a selector or declaration error
+fn stylesheet_error(line: usize, error: Error) -> Error {
+    let message = match error {
+        Error::InvalidStyle(message) => message,
+        error => error.to_string(),
+    };
+    Error::Stylesheet { line, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selector_parse() {
+        let selector = StyleSelector::parse("Button#ok.primary.wide:hover").unwrap();
+        assert_eq!(selector.to_string(), "Button#ok.primary.wide:hover");
+        assert_eq!(selector.specificity(), (1, 3, 1));
+
+        assert_eq!(StyleSelector::parse("*").unwrap().specificity(), (0, 0, 0));
+        assert!(StyleSelector::parse("Button:pressed").is_err());
+        assert!(StyleSelector::parse("Container Button").is_err());
+        assert!(StyleSelector::parse("#a#b").is_err());
+
+        // Non-ASCII characters are rejected, not sliced through
+        assert!(matches!(StyleSelector::parse("Bütton"), Err(Error::InvalidStyle(_))));
+        assert!(matches!(StyleSelector::parse("Button.é"), Err(Error::InvalidStyle(_))));
+    }
+
+    #[test]
+    fn test_selector_matches() {
+        let classes = vec!["primary".to_string()];
+        let target = StyleTarget::new("Button", "ok")
+            .with_classes(&classes)
+            .with_states(&[PseudoClass::Hover]);
+
+        for selector in ["*", "Button", "#ok", ".primary", ":hover", "Button#ok.primary:hover"] {
+            assert!(StyleSelector::parse(selector).unwrap().matches(&target), "{}", selector);
+        }
+        for selector in ["Label", "#cancel", ".danger", ":focus"] {
+            assert!(!StyleSelector::parse(selector).unwrap().matches(&target), "{}", selector);
+        }
+    }
+
+    #[test]
+    fn test_specificity_and_order() {
+        let stylesheet = Stylesheet::parse("
+            #ok { fg: green; }
+            Button { fg: red; bg: blue; }
+            .primary { fg: yellow; }
+            Button { bg: black; }
+        ").unwrap();
+
+        let classes = vec!["primary".to_string()];
+        let target = StyleTarget::new("Button", "other").with_classes(&classes);
+        let style = stylesheet.resolve(&target, None, None);
+        assert_eq!(style.foreground, Some(Color::Yellow));
+        assert_eq!(style.background, Some(Color::Black));
+
+        let target = StyleTarget::new("Button", "ok").with_classes(&classes);
+        assert_eq!(stylesheet.resolve(&target, None, None).foreground, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_inheritance() {
+        let stylesheet = Stylesheet::parse("Label { fg: cyan; bold; }").unwrap();
+        let parent = Style::default()
+            .with_foreground(Color::White)
+            .with_background(Color::Blue)
+            .with_underline(true);
+
+        let style = stylesheet.resolve(&StyleTarget::new("Label", "title"), Some(&parent), None);
+        assert_eq!(style.foreground, Some(Color::Cyan));
+        assert_eq!(style.background, Some(Color::Blue));
+        assert_eq!(style.bold, Some(true));
+        assert_eq!(style.underline, None);
+    }
+
+    #[test]
+    fn test_theme_references() {
+        let mut theme = Theme::default();
+        theme.palette.accent = Color::Magenta;
+        let stylesheet = Stylesheet::parse("Button:hover { bg: $accent; } Label { style: title; }").unwrap();
+
+        let target = StyleTarget::new("Button", "ok").with_states(&[PseudoClass::Hover]);
+        let style = stylesheet.resolve(&target, None, Some(&theme));
+        assert_eq!(style.background, Some(Color::Magenta));
+        // Palette references need a theme
+        assert_eq!(stylesheet.resolve(&target, None, None).background, None);
+
+        let style = stylesheet.resolve(&StyleTarget::new("Label", "title"), None, Some(&theme));
+        assert_eq!(&style, theme.get_style("title").unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Stylesheet::parse("Button { fg: red; }\n\nLabel {\n  size: 3;\n}").unwrap_err();
+        assert_eq!(error.to_string(), "Stylesheet error at line 4: unknown property `size`");
+
+        assert!(Stylesheet::parse("Button { fg: $nope; }").is_err());
+        assert!(Stylesheet::parse("Button { bold: maybe; }").is_err());
+        assert!(Stylesheet::parse("Button { fg: red;").is_err());
+        assert!(Stylesheet::parse("Bütton { fg: red; }").is_err());
+        assert!(Stylesheet::parse("/* only a comment */").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_default_stylesheet() {
+        let stylesheet = Stylesheet::default();
+        let target = StyleTarget::new("Button", "ok").with_states(&[PseudoClass::Active]);
+        let style = stylesheet.resolve(&target, None, None);
+        assert_eq!(style.background, Some(Color::Red));
+        assert_eq!(style.bold, Some(true));
+    }
+}
@@ -0,0 +1,346 @@
+//! Symbol sets for borders, scrollbars, toggles, tree guides and spinners
+//!
+//! Widgets draw their glyphs from the active theme's [`Symbols`], so a
+//! theme can switch border styles or fall back to plain ASCII for legacy
+//! consoles and serial lines.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Characters of a box border
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct BorderSet {
+    /// Top-left corner
+    pub top_left: char,
+    /// Top-right corner
+    pub top_right: char,
+    /// Bottom-left corner
+    pub bottom_left: char,
+    /// Bottom-right corner
+    pub bottom_right: char,
+    /// Top and bottom edges
+    pub horizontal: char,
+    /// Left and right edges
+    pub vertical: char,
+}
+
+impl BorderSet {
+    /// Thin lines: `┌─┐`
+    pub const SINGLE: BorderSet = BorderSet::new(['┌', '┐', '└', '┘', '─', '│']);
+    /// Double lines: `╔═╗`
+    pub const DOUBLE: BorderSet = BorderSet::new(['╔', '╗', '╚', '╝', '═', '║']);
+    /// Thin lines with rounded corners: `╭─╮`
+    pub const ROUNDED: BorderSet = BorderSet::new(['╭', '╮', '╰', '╯', '─', '│']);
+    /// Heavy lines: `┏━┓`
+    pub const THICK: BorderSet = BorderSet::new(['┏', '┓', '┗', '┛', '━', '┃']);
+    /// Thin dashed lines: `┌╌┐`
+    pub const DASHED: BorderSet = BorderSet::new(['┌', '┐', '└', '┘', '╌', '╎']);
+    /// Plain ASCII: `+-+`
+    pub const ASCII: BorderSet = BorderSet::new(['+', '+', '+', '+', '-', '|']);
+
+    /// Create a border set from its corners, then horizontal and vertical edges
+    pub const fn new(chars: [char; 6]) -> Self {
+        Self {
+            top_left: chars[0],
+            top_right: chars[1],
+            bottom_left: chars[2],
+            bottom_right: chars[3],
+            horizontal: chars[4],
+            vertical: chars[5],
+        }
+    }
+
+    /// Get a border set by name: `single`, `double`, `rounded`, `thick`, `dashed` or `ascii`
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "single" => Some(Self::SINGLE),
+            "double" => Some(Self::DOUBLE),
+            "rounded" => Some(Self::ROUNDED),
+            "thick" => Some(Self::THICK),
+            "dashed" => Some(Self::DASHED),
+            "ascii" => Some(Self::ASCII),
+            _ => None,
+        }
+    }
+}
+
+impl Default for BorderSet {
+    fn default() -> Self {
+        Self::SINGLE
+    }
+}
+
+impl<'de> Deserialize<'de> for BorderSet {
+    /// Accepts a set name such as `"rounded"` or a table of characters
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Name(String),
+            Chars {
+                top_left: char,
+                top_right: char,
+                bottom_left: char,
+                bottom_right: char,
+                horizontal: char,
+                vertical: char,
+            },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Name(name) => BorderSet::named(&name)
+                .ok_or_else(|| serde::de::Error::custom(format!("unknown border set `{}`", name))),
+            Repr::Chars { top_left, top_right, bottom_left, bottom_right, horizontal, vertical } => {
+                Ok(BorderSet::new([top_left, top_right, bottom_left, bottom_right, horizontal, vertical]))
+            }
+        }
+    }
+}
+
+/// Glyphs of a vertical scrollbar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScrollbarSymbols {
+    /// Empty part of the bar
+    pub track: char,
+    /// Part of the bar showing the visible range
+    pub thumb: char,
+    /// Arrow at the start of the bar
+    pub begin: char,
+    /// Arrow at the end of the bar
+    pub end: char,
+}
+
+/// Glyphs of a two-state control such as a checkbox
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToggleSymbols {
+    /// Shown when checked
+    pub on: String,
+    /// Shown when not checked
+    pub off: String,
+}
+
+/// Guides drawn in front of tree items
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TreeSymbols {
+    /// An item followed by siblings
+    pub branch: String,
+    /// The last item among its siblings
+    pub last: String,
+    /// Continuation of an ancestor that has more siblings
+    pub vertical: String,
+    /// Indentation under an ancestor without more siblings
+    pub space: String,
+}
+
+/// Nerd Font icons, for fonts patched with them
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Icons {
+    /// A folder
+    pub folder: String,
+    /// A file
+    pub file: String,
+    /// Success or a checked item
+    pub check: String,
+    /// Failure or a removed item
+    pub cross: String,
+    /// A warning
+    pub warning: String,
+    /// An informational message
+    pub info: String,
+    /// An error
+    pub error: String,
+    /// Search
+    pub search: String,
+}
+
+impl Icons {
+    /// Icons from the Font Awesome range of Nerd Fonts
+    pub fn nerd_font() -> Self {
+        Self {
+            folder: "\u{f07b}".to_string(),
+            file: "\u{f15b}".to_string(),
+            check: "\u{f00c}".to_string(),
+            cross: "\u{f00d}".to_string(),
+            warning: "\u{f071}".to_string(),
+            info: "\u{f05a}".to_string(),
+            error: "\u{f057}".to_string(),
+            search: "\u{f002}".to_string(),
+        }
+    }
+}
+
+/// The glyphs widgets draw with
+///
+/// In theme files, `symbols` is either a set name (`"unicode"`, `"ascii"`
+/// or `"nerd_font"`) or a table overriding parts of the Unicode set:
+///
+/// ```toml
+/// [symbols]
+/// border = "rounded"
+/// spinner = ["-", "=", "#", "="]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Symbols {
+    /// Box borders
+    pub border: BorderSet,
+    /// Scrollbars
+    pub scrollbar: ScrollbarSymbols,
+    /// Checkboxes
+    pub checkbox: ToggleSymbols,
+    /// Radio buttons
+    pub radio: ToggleSymbols,
+    /// Tree guides
+    pub tree: TreeSymbols,
+    /// Frames of a spinner animation
+    pub spinner: Vec<String>,
+    /// Bullet for lists, points and masked passwords
+    pub bullet: char,
+    /// Text cursor in input fields
+    pub cursor: char,
+    /// Marks truncated text
+    pub ellipsis: String,
+    /// Optional icons
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icons: Option<Icons>,
+}
+
+impl Symbols {
+    /// Box drawing and other Unicode glyphs
+    pub fn unicode() -> Self {
+        Self {
+            border: BorderSet::SINGLE,
+            scrollbar: ScrollbarSymbols { track: '│', thumb: '█', begin: '▲', end: '▼' },
+            checkbox: toggle("☑", "☐"),
+            radio: toggle("◉", "○"),
+            tree: tree("├── ", "└── ", "│   ", "    "),
+            spinner: frames(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]),
+            bullet: '•',
+            cursor: '▏',
+            ellipsis: "…".to_string(),
+            icons: None,
+        }
+    }
+
+    /// Plain ASCII, for legacy consoles and serial lines
+    pub fn ascii() -> Self {
+        Self {
+            border: BorderSet::ASCII,
+            scrollbar: ScrollbarSymbols { track: '|', thumb: '#', begin: '^', end: 'v' },
+            checkbox: toggle("[x]", "[ ]"),
+            radio: toggle("(*)", "( )"),
+            tree: tree("|-- ", "`-- ", "|   ", "    "),
+            spinner: frames(&["|", "/", "-", "\\"]),
+            bullet: '*',
+            cursor: '|',
+            ellipsis: "...".to_string(),
+            icons: None,
+        }
+    }
+
+    /// Unicode glyphs with Nerd Font icons
+    pub fn nerd_font() -> Self {
+        Self::unicode().with_icons(Icons::nerd_font())
+    }
+
+    /// Get a symbol set by name: `unicode`, `ascii` or `nerd_font`
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "unicode" => Some(Self::unicode()),
+            "ascii" => Some(Self::ascii()),
+            "nerd_font" => Some(Self::nerd_font()),
+            _ => None,
+        }
+    }
+
+    /// Set the border set
+    pub fn with_border(mut self, border: BorderSet) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Set the icons
+    pub fn with_icons(mut self, icons: Icons) -> Self {
+        self.icons = Some(icons);
+        self
+    }
+
+    /// Get the spinner frame for a tick count, wrapping around
+    pub fn spinner_frame(&self, tick: usize) -> &str {
+        match self.spinner.len() {
+            0 => "",
+            len => &self.spinner[tick % len],
+        }
+    }
+
+    /// Check that every glyph is plain ASCII
+    pub fn is_ascii(&self) -> bool {
+        let b = &self.border;
+        let s = &self.scrollbar;
+        let chars = [b.top_left, b.top_right, b.bottom_left, b.bottom_right, b.horizontal, b.vertical];
+        let strings = [
+            &self.checkbox.on, &self.checkbox.off, &self.radio.on, &self.radio.off,
+            &self.tree.branch, &self.tree.last, &self.tree.vertical, &self.tree.space, &self.ellipsis,
+        ];
+        chars.iter().chain(&[s.track, s.thumb, s.begin, s.end, self.bullet, self.cursor]).all(char::is_ascii)
+            && strings.iter().all(|string| string.is_ascii())
+            && self.spinner.iter().all(|frame| frame.is_ascii())
+            && self.icons.is_none()
+    }
+}
+
+impl Default for Symbols {
+    fn default() -> Self {
+        Self::unicode()
+    }
+}
+
+/// Create toggle symbols from the checked and unchecked glyphs
+fn toggle(on: &str, off: &str) -> ToggleSymbols {
+    ToggleSymbols { on: on.to_string(), off: off.to_string() }
+}
+
+/// Create tree guides from the branch, last item, continuation and indent
+fn tree(branch: &str, last: &str, vertical: &str, space: &str) -> TreeSymbols {
+    TreeSymbols {
+        branch: branch.to_string(),
+        last: last.to_string(),
+        vertical: vertical.to_string(),
+        space: space.to_string(),
+    }
+}
+
+/// Collect spinner frames into owned strings
+fn frames(frames: &[&str]) -> Vec<String> {
+    frames.iter().map(|frame| frame.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        assert!(Symbols::ascii().is_ascii());
+        assert!(!Symbols::unicode().is_ascii());
+        assert!(!Symbols::unicode().with_border(BorderSet::ASCII).is_ascii());
+        assert_eq!(Symbols::named("nerd_font").unwrap().icons, Some(Icons::nerd_font()));
+        assert!(Symbols::named("fancy").is_none());
+        assert_eq!(BorderSet::named("rounded"), Some(BorderSet::ROUNDED));
+
+        let ascii = Symbols::ascii();
+        assert_eq!(ascii.spinner_frame(5), "/");
+        assert_eq!(Symbols { spinner: Vec::new(), ..ascii }.spinner_frame(3), "");
+    }
+
+    #[test]
+    fn test_symbols_serde() {
+        let symbols: Symbols = toml::from_str("border = \"double\"\nbullet = \"-\"").unwrap();
+        assert_eq!(symbols.border, BorderSet::DOUBLE);
+        assert_eq!(symbols.bullet, '-');
+        assert_eq!(symbols.checkbox, Symbols::unicode().checkbox);
+
+        let json = serde_json::to_string(&Symbols::ascii()).unwrap();
+        assert_eq!(serde_json::from_str::<Symbols>(&json).unwrap(), Symbols::ascii());
+        assert!(toml::from_str::<Symbols>("border = \"wavy\"").is_err());
+    }
+}
@@ -0,0 +1,265 @@
+//! Styled text made of spans and lines
+//!
+//! A [`Span`] is a string drawn with one style, a [`Line`] is a row of spans
+//! and a [`Text`] is a list of lines. Span styles are applied on top of the
+//! style of whatever draws them, so a span only needs to set what differs.
+
+use crate::style::Style;
+use std::fmt;
+
+/// A string drawn with a single style
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Span {
+    /// Text of the span
+    pub content: String,
+    /// Style applied on top of the surrounding style
+    pub style: Style,
+}
+
+impl Span {
+    /// Create a span without a style of its own
+    pub fn raw<S: Into<String>>(content: S) -> Self {
+        Self {
+            content: content.into(),
+            style: Style::default(),
+        }
+    }
+
+    /// Create a styled span
+    pub fn styled<S: Into<String>>(content: S, style: Style) -> Self {
+        Self {
+            content: content.into(),
+            style,
+        }
+    }
+
+    /// Get the width in columns
+    pub fn width(&self) -> usize {
+        self.content.chars().count()
+    }
+}
+
+impl From<&str> for Span {
+    fn from(content: &str) -> Self {
+        Self::raw(content)
+    }
+}
+
+impl From<String> for Span {
+    fn from(content: String) -> Self {
+        Self::raw(content)
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.content)
+    }
+}
+
+/// A single line of spans
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Line {
+    /// Spans of the line, in order
+    pub spans: Vec<Span>,
+}
+
+impl Line {
+    /// Create a line from spans
+    pub fn new(spans: Vec<Span>) -> Self {
+        Self { spans }
+    }
+
+    /// Create a line of one unstyled span
+    pub fn raw<S: Into<String>>(content: S) -> Self {
+        Self::new(vec![Span::raw(content)])
+    }
+
+    /// Create a line of one styled span
+    pub fn styled<S: Into<String>>(content: S, style: Style) -> Self {
+        Self::new(vec![Span::styled(content, style)])
+    }
+
+    /// Add a span to the end of the line
+    pub fn with_span<S: Into<Span>>(mut self, span: S) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Get the width in columns
+    pub fn width(&self) -> usize {
+        self.spans.iter().map(Span::width).sum()
+    }
+
+    /// Split into characters paired with their span style
+    pub fn styled_chars(&self) -> impl Iterator<Item = (char, Style)> + '_ {
+        self.spans
+            .iter()
+            .flat_map(|span| span.content.chars().map(move |ch| (ch, span.style)))
+    }
+
+    /// Build a line from styled characters, merging runs of equal style
+    pub fn from_styled_chars<I: IntoIterator<Item = (char, Style)>>(chars: I) -> Self {
+        let mut spans: Vec<Span> = Vec::new();
+        for (ch, style) in chars {
+            match spans.last_mut() {
+                Some(span) if span.style == style => span.content.push(ch),
+                _ => spans.push(Span::styled(ch.to_string(), style)),
+            }
+        }
+        Self::new(spans)
+    }
+}
+
+impl From<&str> for Line {
+    fn from(content: &str) -> Self {
+        Self::raw(content)
+    }
+}
+
+impl From<String> for Line {
+    fn from(content: String) -> Self {
+        Self::raw(content)
+    }
+}
+
+impl From<Span> for Line {
+    fn from(span: Span) -> Self {
+        Self::new(vec![span])
+    }
+}
+
+impl From<Vec<Span>> for Line {
+    fn from(spans: Vec<Span>) -> Self {
+        Self::new(spans)
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.spans.iter().try_for_each(|span| f.write_str(&span.content))
+    }
+}
+
+/// Multiple lines of styled text
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Text {
+    /// Lines of the text, in order
+    pub lines: Vec<Line>,
+}
+
+impl Text {
+    /// Create text from lines
+    pub fn new(lines: Vec<Line>) -> Self {
+        Self { lines }
+    }
+
+    /// Create unstyled text, one line per `\n`
+    pub fn raw(content: &str) -> Self {
+        Self::new(content.lines().map(Line::raw).collect())
+    }
+
+    /// Create text with one style, one line per `\n`
+    pub fn styled(content: &str, style: Style) -> Self {
+        Self::new(content.lines().map(|line| Line::styled(line, style)).collect())
+    }
+
+    /// Add a line to the end
+    pub fn with_line<L: Into<Line>>(mut self, line: L) -> Self {
+        self.lines.push(line.into());
+        self
+    }
+
+    /// Add a span to the last line, starting a line if there is none
+    pub fn push_span<S: Into<Span>>(&mut self, span: S) {
+        match self.lines.last_mut() {
+            Some(line) => line.spans.push(span.into()),
+            None => self.lines.push(Line::from(span.into())),
+        }
+    }
+
+    /// Get the width of the widest line
+    pub fn width(&self) -> usize {
+        self.lines.iter().map(Line::width).max().unwrap_or(0)
+    }
+
+    /// Get the number of lines
+    pub fn height(&self) -> usize {
+        self.lines.len()
+    }
+}
+
+impl From<&str> for Text {
+    fn from(content: &str) -> Self {
+        Self::raw(content)
+    }
+}
+
+impl From<String> for Text {
+    fn from(content: String) -> Self {
+        Self::raw(&content)
+    }
+}
+
+impl From<Span> for Text {
+    fn from(span: Span) -> Self {
+        Self::new(vec![Line::from(span)])
+    }
+}
+
+impl From<Line> for Text {
+    fn from(line: Line) -> Self {
+        Self::new(vec![line])
+    }
+}
+
+impl From<Vec<Line>> for Text {
+    fn from(lines: Vec<Line>) -> Self {
+        Self::new(lines)
+    }
+}
+
+impl fmt::Display for Text {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, line) in self.lines.iter().enumerate() {
+            if index > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn test_text_building() {
+        let red = Style::default().with_foreground(Color::Red);
+        let line = Line::raw("Hello, ").with_span(Span::styled("world", red)).with_span("!");
+        assert_eq!(line.width(), 13);
+        assert_eq!(line.to_string(), "Hello, world!");
+        assert_eq!(line.spans[1].style, red);
+
+        let mut text = Text::raw("one\ntwo");
+        text.push_span(Span::styled("!", red));
+        assert_eq!((text.width(), text.height()), (4, 2));
+        assert_eq!(text.to_string(), "one\ntwo!");
+
+        let mut empty = Text::default();
+        empty.push_span("x");
+        assert_eq!(empty.height(), 1);
+    }
+
+    #[test]
+    fn test_styled_chars_roundtrip() {
+        let bold = Style::default().with_bold(true);
+        let line = Line::new(vec![Span::raw("ab"), Span::styled("c", bold), Span::styled("d", bold)]);
+        let rebuilt = Line::from_styled_chars(line.styled_chars());
+        assert_eq!(rebuilt, Line::new(vec![Span::raw("ab"), Span::styled("cd", bold)]));
+        assert_eq!(Line::from_styled_chars(Vec::new()), Line::default());
+    }
+}
@@ -1,8 +1,9 @@
 //! Theme system for VibeUI
 
-use crate::style::{Style, Color, StyleRegistry};
+use crate::style::{Style, Color, Oklch, StyleRegistry, Symbols};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
 /// Theme definition containing named styles
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,10 +16,19 @@ pub struct Theme {
     pub version: String,
     /// Theme author
     pub author: Option<String>,
+    /// Name of the theme this one was derived from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
     /// Named styles
     pub styles: HashMap<String, Style>,
     /// Color palette
     pub palette: ColorPalette,
+    /// Glyphs for borders and other widget decorations
+    #[serde(default)]
+    pub symbols: Symbols,
+    /// File the theme was loaded from, watched by hot reload
+    #[serde(skip)]
+    pub source: Option<PathBuf>,
 }
 
 /// Color palette for themes
@@ -81,6 +91,103 @@ impl Default for ColorPalette {
     }
 }
 
+impl ColorPalette {
+    /// Get a color by its field name, such as `primary` or `on_surface`
+    pub fn get(&self, name: &str) -> Option<Color> {
+        match name {
+            "primary" => Some(self.primary),
+            "secondary" => Some(self.secondary),
+            "accent" => Some(self.accent),
+            "background" => Some(self.background),
+            "surface" => Some(self.surface),
+            "text" => Some(self.text),
+            "on_primary" => Some(self.on_primary),
+            "on_secondary" => Some(self.on_secondary),
+            "on_accent" => Some(self.on_accent),
+            "on_surface" => Some(self.on_surface),
+            "error" => Some(self.error),
+            "warning" => Some(self.warning),
+            "success" => Some(self.success),
+            "info" => Some(self.info),
+            "border" => Some(self.border),
+            "disabled" => Some(self.disabled),
+            _ => None,
+        }
+    }
+
+    /// Set a color by its field name, returning false for unknown names
+    pub fn set(&mut self, name: &str, color: Color) -> bool {
+        let slot = match name {
+            "primary" => &mut self.primary,
+            "secondary" => &mut self.secondary,
+            "accent" => &mut self.accent,
+            "background" => &mut self.background,
+            "surface" => &mut self.surface,
+            "text" => &mut self.text,
+            "on_primary" => &mut self.on_primary,
+            "on_secondary" => &mut self.on_secondary,
+            "on_accent" => &mut self.on_accent,
+            "on_surface" => &mut self.on_surface,
+            "error" => &mut self.error,
+            "warning" => &mut self.warning,
+            "success" => &mut self.success,
+            "info" => &mut self.info,
+            "border" => &mut self.border,
+            "disabled" => &mut self.disabled,
+            _ => return false,
+        };
+        *slot = color;
+        true
+    }
+
+    /// Generate a palette from a seed color
+    ///
+    /// The seed becomes the primary color. Secondary and accent colors
+    /// rotate its hue, backgrounds and borders are grays tinted with it, and
+    /// status colors keep their usual hues at a lightness suiting the
+    /// background. Text on each color is whichever of the text and
+    /// background colors contrasts more.
+    pub fn from_seed(seed: Color, appearance: Appearance) -> Self {
+        let hue = seed.to_oklch().h;
+        let dark = appearance == Appearance::Dark;
+        let tone = |l: f32, c: f32, h: f32| Color::from_oklch(Oklch { l, c, h: h.rem_euclid(360.0) });
+        let (light, chroma) = if dark { (0.72, 0.14) } else { (0.52, 0.16) };
+        let neutral = |dark_l: f32, light_l: f32| tone(if dark { dark_l } else { light_l }, 0.015, hue);
+
+        let background = neutral(0.18, 0.98);
+        let text = neutral(0.93, 0.22);
+        let on = |fill: Color| {
+            if text.contrast_ratio(&fill) >= background.contrast_ratio(&fill) {
+                text
+            } else {
+                background
+            }
+        };
+
+        let secondary = tone(light, chroma, hue + 60.0);
+        let accent = tone(light, chroma, hue + 180.0);
+        let surface = neutral(0.24, 0.94);
+        Self {
+            primary: seed,
+            secondary,
+            accent,
+            background,
+            surface,
+            text,
+            on_primary: on(seed),
+            on_secondary: on(secondary),
+            on_accent: on(accent),
+            on_surface: on(surface),
+            error: tone(light, chroma, 25.0),
+            warning: tone(light + 0.1, chroma, 85.0),
+            success: tone(light, chroma, 145.0),
+            info: tone(light, chroma, 240.0),
+            border: neutral(0.38, 0.82),
+            disabled: neutral(0.5, 0.62),
+        }
+    }
+}
+
 impl Theme {
     /// Create a new theme
     pub fn new(name: &str) -> Self {
@@ -89,8 +196,11 @@ impl Theme {
             description: None,
             version: "1.0.0".to_string(),
             author: None,
+            extends: None,
             styles: HashMap::new(),
             palette: ColorPalette::default(),
+            symbols: Symbols::default(),
+            source: None,
         }
     }
 
@@ -101,8 +211,11 @@ impl Theme {
             description: Some(description.to_string()),
             version: "1.0.0".to_string(),
             author: None,
+            extends: None,
             styles: HashMap::new(),
             palette: ColorPalette::default(),
+            symbols: Symbols::default(),
+            source: None,
         }
     }
 
@@ -261,8 +374,11 @@ impl Theme {
     }
 
     /// Load a theme from a JSON string
-    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
-        serde_json::from_str(json)
+    ///
+    /// See [`Theme::load_from_file`] for the format. `extends` can name a
+    /// built-in theme.
+    pub fn from_json(json: &str) -> Result<Self, ThemeError> {
+        ThemeSpec::parse(json, Format::Json, None)?.resolve(json, None, &builtin::get)
     }
 
     /// Convert the theme to a JSON string
@@ -271,8 +387,8 @@ impl Theme {
     }
 
     /// Load a theme from a TOML string
-    pub fn from_toml(toml: &str) -> Result<Self, toml::de::Error> {
-        toml::from_str(toml)
+    pub fn from_toml(toml: &str) -> Result<Self, ThemeError> {
+        ThemeSpec::parse(toml, Format::Toml, None)?.resolve(toml, None, &builtin::get)
     }
 
     /// Convert the theme to a TOML string
@@ -287,14 +403,345 @@ impl Theme {
         Ok(())
     }
 
-    /// Load a theme from a file
-    pub fn load_from_file<P: AsRef<std::path::Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
-        let content = std::fs::read_to_string(path)?;
-        let theme = Self::from_json(&content)?;
+    /// Load a theme from a TOML (`.toml`) or JSON file
+    ///
+    /// A theme can `extends` a built-in theme by name, taking its palette
+    /// and styles. Colors are strings such as `"cyan"` or `"#1e1e2e"`, or
+    /// `$name` references to a palette slot or an entry of `variables`. A
+    /// style can `extends` another style, and a style already defined by
+    /// the base theme is modified rather than replaced:
+    ///
+    /// ```toml
+    /// name = "mocha"
+    /// extends = "dark"
+    ///
+    /// [variables]
+    /// mauve = "#cba6f7"
+    ///
+    /// [palette]
+    /// primary = "$mauve"
+    /// background = "#1e1e2e"
+    ///
+    /// [styles.button]
+    /// bold = true
+    ///
+    /// [styles.danger]
+    /// extends = "button"
+    /// bg = "$error"
+    /// ```
+    ///
+    /// Styles generated from the palette are regenerated when the palette
+    /// changes. Unresolved references and cycles are reported with the file
+    /// and line they appear on. The path is kept in `source` so the theme
+    /// can be reloaded.
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, ThemeError> {
+        load_file(path.as_ref(), &builtin::get)
+    }
+}
+
+/// Error loading a theme
+#[derive(Debug, thiserror::Error)]
+pub enum ThemeError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("{location}: {message}")]
+    Parse { location: String, message: String },
+
+    #[error("{location}: {message}")]
+    Invalid { location: String, message: String },
+}
+
+/// Read and resolve a theme file, looking base themes up with `base`
+fn load_file(path: &Path, base: &dyn Fn(&str) -> Option<Theme>) -> Result<Theme, ThemeError> {
+    let text = std::fs::read_to_string(path)?;
+    let format = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => Format::Toml,
+        _ => Format::Json,
+    };
+    let mut theme = ThemeSpec::parse(&text, format, Some(path))?.resolve(&text, Some(path), base)?;
+    theme.source = Some(path.to_path_buf());
+    Ok(theme)
+}
+
+/// Syntax of a theme file
+#[derive(Debug, Clone, Copy)]
+enum Format {
+    Json,
+    Toml,
+}
+
+/// A theme as written, before `extends` and references are resolved
+#[derive(Debug, Deserialize)]
+struct ThemeSpec {
+    name: String,
+    description: Option<String>,
+    version: Option<String>,
+    author: Option<String>,
+    extends: Option<String>,
+    #[serde(default)]
+    variables: BTreeMap<String, String>,
+    #[serde(default)]
+    palette: BTreeMap<String, String>,
+    #[serde(default)]
+    styles: BTreeMap<String, StyleSpec>,
+    symbols: Option<SymbolsSpec>,
+}
+
+/// Symbols as written, either a set name or a table
+#[derive(Debug)]
+enum SymbolsSpec {
+    Named(String),
+    Custom(Box<Symbols>),
+}
+
+impl<'de> Deserialize<'de> for SymbolsSpec {
+    /// Picks the form from the value's type, so errors inside a table are
+    /// reported as they are instead of as an untagged enum mismatch
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = SymbolsSpec;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a symbol set name or a table of symbols")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<SymbolsSpec, E> {
+                Ok(SymbolsSpec::Named(name.to_string()))
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<SymbolsSpec, A::Error> {
+                let symbols = Symbols::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+                Ok(SymbolsSpec::Custom(Box::new(symbols)))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// A style as written, with colors that may be references
+#[derive(Debug, Default, Deserialize)]
+struct StyleSpec {
+    extends: Option<String>,
+    #[serde(alias = "fg")]
+    foreground: Option<String>,
+    #[serde(alias = "bg")]
+    background: Option<String>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    underline: Option<bool>,
+    dim: Option<bool>,
+    blink: Option<bool>,
+    reverse: Option<bool>,
+    hidden: Option<bool>,
+    strikethrough: Option<bool>,
+}
+
+impl ThemeSpec {
+    /// Parse a theme file
+    fn parse(text: &str, format: Format, path: Option<&Path>) -> Result<Self, ThemeError> {
+        let file = path.map_or_else(|| "<theme>".to_string(), |path| path.display().to_string());
+        match format {
+            Format::Json => serde_json::from_str(text).map_err(|error| ThemeError::Parse {
+                location: format!("{}:{}", file, error.line()),
+                message: error.to_string(),
+            }),
+            Format::Toml => toml::from_str(text).map_err(|error| ThemeError::Parse {
+                location: match error.span() {
+                    Some(span) => format!("{}:{}", file, line_at(text, span.start)),
+                    None => file,
+                },
+                message: error.message().to_string(),
+            }),
+        }
+    }
+
+    /// Resolve `extends` and references into a theme
+    fn resolve(&self, text: &str, path: Option<&Path>, base: &dyn Fn(&str) -> Option<Theme>) -> Result<Theme, ThemeError> {
+        let resolver = Resolver { spec: self, text, path };
+
+        let mut theme = match &self.extends {
+            Some(name) => base(name).ok_or_else(|| {
+                resolver.invalid(name, format!("unknown base theme `{}`", name))
+            })?,
+            None => Theme::new(&self.name),
+        };
+        theme.name = self.name.clone();
+        theme.description = self.description.clone();
+        theme.version = self.version.clone().unwrap_or_else(|| "1.0.0".to_string());
+        theme.author = self.author.clone();
+        theme.extends = self.extends.clone();
+        theme.source = None;
+        match &self.symbols {
+            Some(SymbolsSpec::Named(name)) => {
+                theme.symbols = Symbols::named(name)
+                    .ok_or_else(|| resolver.invalid(name, format!("unknown symbol set `{}`", name)))?;
+            }
+            Some(SymbolsSpec::Custom(symbols)) => theme.symbols = (**symbols).clone(),
+            None => {}
+        }
+
+        // Resolve every color against the base palette and this file, so
+        // references work regardless of the order slots are written in
+        let base_palette = theme.palette.clone();
+        for (slot, value) in &self.palette {
+            if base_palette.get(slot).is_none() {
+                return Err(resolver.invalid(slot, format!("unknown palette slot `{}`", slot)));
+            }
+            let color = resolver.color(value, &base_palette, &format!("palette.{}", slot), &mut Vec::new())?;
+            theme.palette.set(slot, color);
+        }
+        if self.extends.is_none() {
+            theme.generate_default_styles();
+        } else if !self.palette.is_empty() {
+            // Regenerate the styles the base generated from its palette, and
+            // keep the ones it customized
+            let generated = |palette: &ColorPalette| {
+                let mut theme = Theme::new("").with_palette(palette.clone());
+                theme.generate_default_styles();
+                theme.styles
+            };
+            let before = generated(&base_palette);
+            for (name, style) in generated(&theme.palette) {
+                let inherited = theme.styles.get(&name);
+                if inherited.is_none_or(|inherited| before.get(&name) == Some(inherited)) {
+                    theme.styles.insert(name, style);
+                }
+            }
+        }
+
+        let mut resolved = HashMap::new();
+        for name in self.styles.keys() {
+            resolver.style(name, &theme, &base_palette, &mut resolved, &mut Vec::new())?;
+        }
+        theme.styles.extend(resolved);
         Ok(theme)
     }
 }
 
+/// Resolves references in a theme spec, locating errors in its text
+struct Resolver<'a> {
+    spec: &'a ThemeSpec,
+    text: &'a str,
+    path: Option<&'a Path>,
+}
+
+impl Resolver<'_> {
+    /// Resolve a color string or `$name` reference
+    fn color(&self, value: &str, base: &ColorPalette, key: &str, stack: &mut Vec<String>) -> Result<Color, ThemeError> {
+        let Some(name) = value.strip_prefix('$') else {
+            return Color::parse(value)
+                .map_err(|error| self.invalid(value, format!("invalid color `{}` in {}: {}", value, key, error)));
+        };
+        if stack.iter().any(|seen| seen == name) {
+            return Err(self.invalid(value, format!("circular reference `{}` in {}", value, key)));
+        }
+
+        stack.push(name.to_string());
+        let color = if let Some(variable) = self.spec.variables.get(name) {
+            self.color(variable, base, key, stack)
+        } else if let Some(slot) = self.spec.palette.get(name) {
+            self.color(slot, base, key, stack)
+        } else {
+            base.get(name)
+                .ok_or_else(|| self.invalid(value, format!("unresolved reference `{}` in {}", value, key)))
+        };
+        stack.pop();
+        color
+    }
+
+    /// Resolve a style, following `extends` within the file or to the base
+    fn style(
+        &self,
+        name: &str,
+        theme: &Theme,
+        base: &ColorPalette,
+        resolved: &mut HashMap<String, Style>,
+        stack: &mut Vec<String>,
+    ) -> Result<Style, ThemeError> {
+        if let Some(style) = resolved.get(name) {
+            return Ok(*style);
+        }
+        let spec = &self.spec.styles[name];
+        if stack.iter().any(|seen| seen == name) {
+            return Err(self.invalid(name, format!("circular style reference `{}`", name)));
+        }
+
+        stack.push(name.to_string());
+        let mut style = match &spec.extends {
+            Some(parent) if self.spec.styles.contains_key(parent) => {
+                self.style(parent, theme, base, resolved, stack)?
+            }
+            Some(parent) => theme.styles.get(parent).copied().ok_or_else(|| {
+                self.invalid(parent, format!("unresolved style reference `{}` in styles.{}", parent, name))
+            })?,
+            None => theme.styles.get(name).copied().unwrap_or_default(),
+        };
+        stack.pop();
+
+        if let Some(value) = &spec.foreground {
+            let key = format!("styles.{}.foreground", name);
+            style.foreground = Some(self.color(value, base, &key, &mut Vec::new())?);
+        }
+        if let Some(value) = &spec.background {
+            let key = format!("styles.{}.background", name);
+            style.background = Some(self.color(value, base, &key, &mut Vec::new())?);
+        }
+        let flags = [
+            (spec.bold, &mut style.bold),
+            (spec.italic, &mut style.italic),
+            (spec.underline, &mut style.underline),
+            (spec.dim, &mut style.dim),
+            (spec.blink, &mut style.blink),
+            (spec.reverse, &mut style.reverse),
+            (spec.hidden, &mut style.hidden),
+            (spec.strikethrough, &mut style.strikethrough),
+        ];
+        for (value, flag) in flags {
+            if value.is_some() {
+                *flag = value;
+            }
+        }
+
+        resolved.insert(name.to_string(), style);
+        Ok(style)
+    }
+
+    /// Create an error located at the first line mentioning `needle`
+    fn invalid(&self, needle: &str, message: String) -> ThemeError {
+        let file = self.path.map_or_else(|| "<theme>".to_string(), |path| path.display().to_string());
+        let location = match find_token(self.text, needle) {
+            Some(offset) => format!("{}:{}", file, line_at(self.text, offset)),
+            None => file,
+        };
+        ThemeError::Invalid { location, message }
+    }
+}
+
+/// Get the 1-based line of a byte offset
+pub(crate) fn line_at(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count() + 1
+}
+
+/// Find the first occurrence of `needle` as a whole token
+///
+/// Unlike `str::find`, `primary` doesn't match inside `on_primary` or
+/// `$primary_dark`.
+pub(crate) fn find_token(text: &str, needle: &str) -> Option<usize> {
+    if needle.is_empty() {
+        return None;
+    }
+    let is_word = |c: char| c.is_alphanumeric() || matches!(c, '_' | '$' | '-');
+    text.match_indices(needle).map(|(offset, _)| offset).find(|&offset| {
+        let before = text[..offset].chars().next_back();
+        let after = text[offset + needle.len()..].chars().next();
+        !before.is_some_and(is_word) && !after.is_some_and(is_word)
+    })
+}
+
 impl Default for Theme {
     fn default() -> Self {
         let mut theme = Self::new("default");
@@ -303,6 +750,34 @@ impl Default for Theme {
     }
 }
 
+/// Whether a theme is meant for a dark or a light background
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Appearance {
+    /// Light text on a dark background
+    Dark,
+    /// Dark text on a light background
+    Light,
+}
+
+impl Appearance {
+    /// Classify a background color
+    pub fn of(background: Color) -> Self {
+        if background.is_dark() {
+            Appearance::Dark
+        } else {
+            Appearance::Light
+        }
+    }
+
+    /// Name of the built-in theme for this appearance
+    pub fn theme_name(&self) -> &'static str {
+        match self {
+            Appearance::Dark => "dark",
+            Appearance::Light => "light",
+        }
+    }
+}
+
 /// Theme manager for managing multiple themes
 #[derive(Debug, Clone, Default)]
 pub struct ThemeManager {
@@ -357,6 +832,66 @@ impl ThemeManager {
         }
     }
 
+    /// Make the built-in theme matching `appearance` current
+    ///
+    /// The built-in theme is added first unless a theme with its name was
+    /// already added, which is then used instead.
+    pub fn select_appearance(&mut self, appearance: Appearance) {
+        let name = appearance.theme_name();
+        if !self.themes.contains_key(name) {
+            if let Some(theme) = builtin::get(name) {
+                self.add_theme(theme);
+            }
+        }
+        self.set_current_theme(name);
+    }
+
+    /// Replace a theme in a single step
+    ///
+    /// If the replaced theme was current, the new theme becomes current even
+    /// when its name differs.
+    pub fn replace_theme(&mut self, name: &str, theme: Theme) {
+        let was_current = self.current_theme.as_deref() == Some(name);
+        self.themes.remove(name);
+        if was_current {
+            self.current_theme = Some(theme.name.clone());
+        }
+        self.add_theme(theme);
+    }
+
+    /// Get the name and file of every loaded theme that extends `name`
+    ///
+    /// These need to be loaded again when `name` changes.
+    pub fn dependents(&self, name: &str) -> Vec<(String, PathBuf)> {
+        self.themes
+            .values()
+            .filter(|theme| theme.extends.as_deref() == Some(name))
+            .filter_map(|theme| Some((theme.name.clone(), theme.source.clone()?)))
+            .collect()
+    }
+
+    /// Load a theme file without adding it
+    ///
+    /// `extends` can name any theme in the manager or a built-in theme.
+    pub fn load_file<P: AsRef<Path>>(&self, path: P) -> Result<Theme, ThemeError> {
+        load_file(path.as_ref(), &|name| {
+            self.themes.get(name).cloned().or_else(|| builtin::get(name))
+        })
+    }
+
+    /// Load a theme file and add it, returning the theme's name
+    pub fn load_theme<P: AsRef<Path>>(&mut self, path: P) -> Result<String, ThemeError> {
+        let theme = self.load_file(path)?;
+        let name = theme.name.clone();
+        self.add_theme(theme);
+        Ok(name)
+    }
+
+    /// Iterate over all themes
+    pub fn themes(&self) -> impl Iterator<Item = &Theme> {
+        self.themes.values()
+    }
+
     /// Get all theme names
     pub fn theme_names(&self) -> Vec<&str> {
         self.themes.keys().map(|s| s.as_str()).collect()
@@ -383,6 +918,18 @@ pub mod builtin {
     use super::*;
     use crate::style::palette;
 
+    /// Get a built-in theme by name
+    pub fn get(name: &str) -> Option<Theme> {
+        match name {
+            "default" => Some(Theme::default()),
+            "dark" => Some(dark()),
+            "light" => Some(light()),
+            "high_contrast" => Some(high_contrast()),
+            "retro" => Some(retro()),
+            _ => None,
+        }
+    }
+
     /// Dark theme
     pub fn dark() -> Theme {
         let mut theme = Theme::with_description("dark", "Dark theme for VibeUI")
@@ -571,4 +1118,157 @@ mod tests {
         assert_eq!(theme.name, deserialized.name);
         assert_eq!(theme.palette.primary, deserialized.palette.primary);
     }
+
+    #[test]
+    fn test_theme_extends_and_references() {
+        let theme = Theme::from_toml(r##"
+            name = "mocha"
+            extends = "dark"
+
+            [variables]
+            mauve = "#cba6f7"
+
+            [palette]
+            primary = "$mauve"
+            accent = "$primary"
+            background = "#1e1e2e"
+
+            [styles.button]
+            bold = true
+
+            [styles.danger]
+            extends = "button"
+            bg = "$error"
+        "##).unwrap();
+
+        let dark = builtin::dark();
+        assert_eq!(theme.extends.as_deref(), Some("dark"));
+        assert_eq!(theme.palette.primary, Color::Rgb(0xcb, 0xa6, 0xf7));
+        assert_eq!(theme.palette.accent, theme.palette.primary);
+        assert_eq!(theme.palette.text, dark.palette.text);
+
+        // Generated styles follow the new palette
+        let button = theme.get_style("button").unwrap();
+        assert_eq!(button.background, Some(theme.palette.primary));
+        assert_eq!(button.bold, Some(true));
+
+        let danger = theme.get_style("danger").unwrap();
+        assert_eq!(danger.foreground, button.foreground);
+        assert_eq!(danger.background, Some(dark.palette.error));
+        assert_eq!(danger.bold, Some(true));
+    }
+
+    #[test]
+    fn test_theme_validation() {
+        let error = Theme::from_json("{\n  \"name\": \"broken\",\n  \"palette\": {\"primary\": \"$nope\"}\n}").unwrap_err();
+        assert_eq!(error.to_string(), "<theme>:3: unresolved reference `$nope` in palette.primary");
+
+        let error = Theme::from_json(r#"{"name": "x", "extends": "missing"}"#).unwrap_err();
+        assert!(error.to_string().contains("unknown base theme `missing`"));
+
+        let error = Theme::from_json(r#"{"name": "x", "palette": {"primary": "$accent", "accent": "$primary"}}"#).unwrap_err();
+        assert!(error.to_string().contains("circular reference"));
+
+        let error = Theme::from_json(r#"{"name": "x", "styles": {"a": {"extends": "b"}, "b": {"extends": "a"}}}"#).unwrap_err();
+        assert!(error.to_string().contains("circular style reference"));
+
+        assert!(matches!(Theme::from_toml("name = "), Err(ThemeError::Parse { .. })));
+    }
+
+    #[test]
+    fn test_error_location_matches_whole_tokens() {
+        let json = r#"{
+            "name": "x",
+            "variables": {"shade_dark": "black"},
+            "palette": {"secondary": "$shade_dark",
+                        "primary": "$shade"}
+        }"#;
+        let error = Theme::from_json(json).unwrap_err();
+        assert_eq!(error.to_string(), "<theme>:5: unresolved reference `$shade` in palette.primary");
+
+        assert_eq!(find_token("on_primary primary", "primary"), Some(11));
+        assert_eq!(find_token("$primary_dark", "$primary"), None);
+    }
+
+    #[test]
+    fn test_extends_keeps_customized_base_styles() {
+        let mut base = builtin::dark();
+        base.name = "base".to_string();
+        base.styles.insert("button".to_string(), Style::new().with_foreground(Color::Red));
+
+        let json = r##"{"name": "child", "extends": "base", "palette": {"primary": "#112233"}}"##;
+        let spec = ThemeSpec::parse(json, Format::Json, None).unwrap();
+        let theme = spec.resolve(json, None, &|_| Some(base.clone())).unwrap();
+
+        assert_eq!(theme.get_style("button"), Some(&Style::new().with_foreground(Color::Red)));
+        assert_eq!(theme.get_style("primary").unwrap().background, Some(Color::Rgb(0x11, 0x22, 0x33)));
+    }
+
+    #[test]
+    fn test_theme_symbols() {
+        let theme = Theme::from_toml("name = \"plain\"\nsymbols = \"ascii\"").unwrap();
+        assert_eq!(theme.symbols, Symbols::ascii());
+
+        let theme = Theme::from_toml("name = \"soft\"\n[symbols]\nborder = \"rounded\"").unwrap();
+        assert_eq!(theme.symbols, Symbols::unicode().with_border(crate::style::BorderSet::ROUNDED));
+
+        let error = Theme::from_toml("name = \"x\"\nsymbols = \"runes\"").unwrap_err();
+        assert_eq!(error.to_string(), "<theme>:2: unknown symbol set `runes`");
+
+        // Errors inside a table point at the bad value
+        let error = Theme::from_toml("name = \"x\"\n[symbols]\nbullet = \"too long\"").unwrap_err();
+        assert_eq!(error.to_string(), "<theme>:3: invalid value: string \"too long\", expected a character");
+        let error = Theme::from_json(r#"{"name": "x", "symbols": 3}"#).unwrap_err();
+        assert!(error.to_string().contains("a symbol set name or a table of symbols"), "{}", error);
+    }
+
+    #[test]
+    fn test_palette_from_seed() {
+        let seed = Color::Rgb(30, 100, 200);
+        for appearance in [Appearance::Dark, Appearance::Light] {
+            let palette = ColorPalette::from_seed(seed, appearance);
+            assert_eq!(palette.primary, seed);
+            assert_eq!(Appearance::of(palette.background), appearance);
+            assert!(palette.text.contrast_ratio(&palette.background) >= 7.0);
+            assert!(palette.on_primary.contrast_ratio(&palette.primary) >= 4.5);
+            assert!(palette.on_surface.contrast_ratio(&palette.surface) >= 4.5);
+            for status in [palette.error, palette.warning, palette.success, palette.info] {
+                assert!(status.contrast_ratio(&palette.background) >= 3.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_select_appearance() {
+        assert_eq!(Appearance::of(Color::Rgb(30, 30, 46)), Appearance::Dark);
+        assert_eq!(Appearance::of(Color::BrightWhite), Appearance::Light);
+
+        let mut manager = ThemeManager::new();
+        manager.select_appearance(Appearance::Light);
+        assert_eq!(manager.current_theme_name(), Some("light"));
+
+        let mut custom = Theme::new("dark");
+        custom.author = Some("me".to_string());
+        manager.add_theme(custom);
+        manager.select_appearance(Appearance::Dark);
+        assert_eq!(manager.current_theme().unwrap().author.as_deref(), Some("me"));
+    }
+
+    #[test]
+    fn test_theme_manager_load_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("custom.json");
+        std::fs::write(&path, r#"{"name": "custom", "extends": "base", "palette": {"accent": "red"}}"#).unwrap();
+
+        let mut manager = ThemeManager::new();
+        assert!(manager.load_file(&path).is_err());
+
+        manager.add_theme(Theme::new("base"));
+        assert_eq!(manager.load_theme(&path).unwrap(), "custom");
+        let theme = manager.get_theme("custom").unwrap();
+        assert_eq!(theme.palette.accent, Color::Red);
+        assert_eq!(theme.source.as_deref(), Some(path.as_path()));
+        assert_eq!(manager.dependents("base"), vec![("custom".to_string(), path.clone())]);
+        assert!(manager.dependents("custom").is_empty());
+    }
 }
\ No newline at end of file
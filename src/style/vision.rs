@@ -0,0 +1,128 @@
+//! Color vision deficiency simulation
+//!
+//! Transforms colors to approximate how they appear with protanopia,
+//! deuteranopia or tritanopia, using the full-severity matrices of Machado,
+//! Oliveira and Fernandes (2009) applied in linear RGB.
+
+use crate::style::{Color, Style, Theme};
+
+/// A color vision deficiency to simulate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorVision {
+    /// No red cones
+    Protanopia,
+    /// No green cones
+    Deuteranopia,
+    /// No blue cones
+    Tritanopia,
+}
+
+impl ColorVision {
+    /// All simulated deficiencies
+    pub const ALL: [ColorVision; 3] = [ColorVision::Protanopia, ColorVision::Deuteranopia, ColorVision::Tritanopia];
+
+    /// Get the deficiency's name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorVision::Protanopia => "protanopia",
+            ColorVision::Deuteranopia => "deuteranopia",
+            ColorVision::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// Get a color as it appears with this deficiency
+    pub fn simulate(&self, color: Color) -> Color {
+        let matrix = self.matrix();
+        let rgb = color.as_linear_rgb();
+        Color::from_linear_rgb(matrix.map(|row| row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]))
+    }
+
+    /// Get a style with both colors simulated
+    pub fn simulate_style(&self, style: &Style) -> Style {
+        Style {
+            foreground: style.foreground.map(|color| self.simulate(color)),
+            background: style.background.map(|color| self.simulate(color)),
+            ..*style
+        }
+    }
+
+    /// Get a copy of a theme with its palette and styles simulated
+    ///
+    /// Auditing the result shows which styles lose contrast for viewers
+    /// with this deficiency.
+    pub fn simulate_theme(&self, theme: &Theme) -> Theme {
+        let mut simulated = theme.clone();
+        let p = &mut simulated.palette;
+        for color in [
+            &mut p.primary, &mut p.secondary, &mut p.accent, &mut p.background,
+            &mut p.surface, &mut p.text, &mut p.on_primary, &mut p.on_secondary,
+            &mut p.on_accent, &mut p.on_surface, &mut p.error, &mut p.warning,
+            &mut p.success, &mut p.info, &mut p.border, &mut p.disabled,
+        ] {
+            *color = self.simulate(*color);
+        }
+        for style in simulated.styles.values_mut() {
+            *style = self.simulate_style(style);
+        }
+        simulated
+    }
+
+    /// Linear RGB transform for this deficiency
+    fn matrix(&self) -> [[f32; 3]; 3] {
+        match self {
+            ColorVision::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            ColorVision::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            ColorVision::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulate() {
+        let red = Color::Rgb(255, 0, 0);
+        let green = Color::Rgb(0, 255, 0);
+        for vision in ColorVision::ALL {
+            // Grays look the same to everyone
+            assert_eq!(vision.simulate(Color::Rgb(255, 255, 255)), Color::Rgb(255, 255, 255));
+            assert_eq!(vision.simulate(Color::Rgb(0, 0, 0)), Color::Rgb(0, 0, 0));
+        }
+
+        // Without red or green cones, red and green both fall on the
+        // yellow-blue axis, with similar red and green components
+        for vision in [ColorVision::Protanopia, ColorVision::Deuteranopia] {
+            for color in [red, green] {
+                let (r, g, _) = vision.simulate(color).as_rgb();
+                assert!((r as i32 - g as i32).abs() < 40, "{} {:?}", vision.name(), color);
+            }
+        }
+        assert_ne!(ColorVision::Tritanopia.simulate(red), red);
+    }
+
+    #[test]
+    fn test_simulate_theme() {
+        let theme = Theme::default();
+        let simulated = ColorVision::Deuteranopia.simulate_theme(&theme);
+        assert_eq!(simulated.palette.error, ColorVision::Deuteranopia.simulate(theme.palette.error));
+
+        let style = simulated.get_style("default").unwrap();
+        let original = theme.get_style("default").unwrap();
+        assert_eq!(style.foreground, original.foreground.map(|color| ColorVision::Deuteranopia.simulate(color)));
+        assert_eq!(style.bold, original.bold);
+    }
+}
@@ -0,0 +1,88 @@
+//! Block widget drawing a border and title around an area
+
+use crate::render::{Rect, RenderBuffer, Result};
+use crate::style::Style;
+use crate::widgets::Widget;
+
+/// Widget framing an area with an optional border and title
+#[derive(Debug, Clone, Default)]
+pub struct Block {
+    title: Option<String>,
+    borders: bool,
+    style: Style,
+    border_style: Style,
+}
+
+impl Block {
+    /// Create a block without a border
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a block with a border
+    pub fn bordered() -> Self {
+        Self {
+            borders: true,
+            ..Self::default()
+        }
+    }
+
+    /// Set the title shown in the top border
+    pub fn with_title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the style filling the block
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the border style
+    pub fn with_border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Get the area inside the border
+    pub fn inner(&self, area: Rect) -> Rect {
+        if self.borders {
+            area.inner(1)
+        } else {
+            area
+        }
+    }
+}
+
+impl Widget for Block {
+    fn render(self, area: Rect, buffer: &mut RenderBuffer) -> Result<()> {
+        if self.style != Style::default() {
+            buffer.fill_area(area.x, area.y, area.width, area.height, ' ', &self.style)?;
+        }
+        if self.borders {
+            buffer.draw_border(area.x, area.y, area.width, area.height, &self.border_style)?;
+        }
+        if let Some(title) = &self.title {
+            let max_width = area.width.saturating_sub(2) as usize;
+            let title: String = title.chars().take(max_width).collect();
+            buffer.draw_text(area.x + 1, area.y, &title, &self.border_style)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_render() {
+        let mut buffer = RenderBuffer::new(8, 3);
+        let block = Block::bordered().with_title("Logs");
+        assert_eq!(block.inner(Rect::new(0, 0, 8, 3)), Rect::new(1, 1, 6, 1));
+
+        block.render(Rect::new(0, 0, 8, 3), &mut buffer).unwrap();
+        assert_eq!(buffer.as_string(), "┌Logs──┐\n│      │\n└──────┘");
+    }
+}
@@ -0,0 +1,82 @@
+//! Gauge widget showing progress as a filled bar
+
+use crate::render::{Rect, RenderBuffer, Result};
+use crate::style::{Color, Style};
+use crate::widgets::Widget;
+
+/// Widget showing a ratio as a horizontal bar with a centered label
+#[derive(Debug, Clone)]
+pub struct Gauge {
+    ratio: f64,
+    label: Option<String>,
+    style: Style,
+    filled_style: Style,
+}
+
+impl Gauge {
+    /// Create a gauge showing `ratio` (clamped to 0.0 to 1.0)
+    pub fn new(ratio: f64) -> Self {
+        Self {
+            ratio: ratio.clamp(0.0, 1.0),
+            label: None,
+            style: Style::default(),
+            filled_style: Style::default()
+                .with_foreground(Color::Black)
+                .with_background(Color::Green),
+        }
+    }
+
+    /// Set the label, which defaults to the percentage
+    pub fn with_label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the style of the unfilled part
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the filled part
+    pub fn with_filled_style(mut self, style: Style) -> Self {
+        self.filled_style = style;
+        self
+    }
+}
+
+impl Widget for Gauge {
+    fn render(self, area: Rect, buffer: &mut RenderBuffer) -> Result<()> {
+        let filled = (area.width as f64 * self.ratio).round() as u16;
+        buffer.fill_area(area.x, area.y, filled, area.height, ' ', &self.filled_style)?;
+        buffer.fill_area(area.x + filled, area.y, area.width - filled, area.height, ' ', &self.style)?;
+
+        let label = self
+            .label
+            .unwrap_or_else(|| format!("{:.0}%", self.ratio * 100.0));
+        let label: String = label.chars().take(area.width as usize).collect();
+        let start = area.x + (area.width - label.chars().count() as u16) / 2;
+        let row = area.y + area.height / 2;
+        for (i, ch) in label.chars().enumerate() {
+            let x = start + i as u16;
+            let style = if x < area.x + filled { &self.filled_style } else { &self.style };
+            buffer.draw_char(x, row, ch, style)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gauge_render() {
+        let mut buffer = RenderBuffer::new(10, 1);
+        Gauge::new(0.5).render(Rect::new(0, 0, 10, 1), &mut buffer).unwrap();
+
+        assert_eq!(buffer.as_string(), "   50%    ");
+        assert_eq!(buffer.get_cell(4, 0).unwrap().style.background, Some(Color::Green));
+        assert_eq!(buffer.get_cell(5, 0).unwrap().style.background, None);
+    }
+}
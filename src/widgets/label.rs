@@ -0,0 +1,75 @@
+//! Label widget drawing aligned lines of text
+
+use crate::components::layout::Alignment;
+use crate::render::{Rect, RenderBuffer, Result};
+use crate::style::Style;
+use crate::widgets::Widget;
+
+/// Widget drawing text, one line per row
+///
+/// Lines longer than the area are truncated and lines below the area are
+/// not drawn.
+#[derive(Debug, Clone)]
+pub struct Label {
+    text: String,
+    style: Style,
+    alignment: Alignment,
+}
+
+impl Label {
+    /// Create a new label
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            style: Style::default(),
+            alignment: Alignment::Start,
+        }
+    }
+
+    /// Set the text style
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the horizontal alignment
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl Widget for Label {
+    fn render(self, area: Rect, buffer: &mut RenderBuffer) -> Result<()> {
+        for (row, line) in self.text.lines().take(area.height as usize).enumerate() {
+            let line: String = line.chars().take(area.width as usize).collect();
+            let free = area.width - line.chars().count() as u16;
+            let offset = match self.alignment {
+                Alignment::Start | Alignment::Stretch | Alignment::Justify => 0,
+                Alignment::Center => free / 2,
+                Alignment::End => free,
+            };
+            buffer.draw_text(area.x + offset, area.y + row as u16, &line, &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_alignment() {
+        let mut buffer = RenderBuffer::new(7, 3);
+        Label::new("ab\ncd\nlong line")
+            .with_alignment(Alignment::Center)
+            .render(Rect::new(0, 0, 7, 2), &mut buffer)
+            .unwrap();
+        Label::new("end")
+            .with_alignment(Alignment::End)
+            .render(Rect::new(0, 2, 7, 1), &mut buffer)
+            .unwrap();
+        assert_eq!(buffer.as_string(), "  ab   \n  cd   \n    end");
+    }
+}
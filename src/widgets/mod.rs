@@ -0,0 +1,21 @@
+//! Stateless widgets module
+//!
+//! This module contains widgets for immediate-mode drawing. Widgets are
+//! built for a single frame, rendered into an area and then dropped, so all
+//! state lives in the application.
+
+pub mod block;
+pub mod gauge;
+pub mod label;
+
+pub use block::Block;
+pub use gauge::Gauge;
+pub use label::Label;
+
+use crate::render::{Rect, RenderBuffer, Result};
+
+/// Widget that renders itself into an area of a buffer
+pub trait Widget {
+    /// Render the widget into `area`
+    fn render(self, area: Rect, buffer: &mut RenderBuffer) -> Result<()>;
+}
@@ -0,0 +1,127 @@
+//! Derive macros for VibeUI
+//!
+//! Use these through the `vibeui` crate rather than depending on this crate
+//! directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Member};
+
+/// Derive `ComponentBase` by delegating to a `BaseComponent` field
+///
+/// The field is marked with `#[base]`. The generated impl forwards `id`,
+/// `bounds`, `set_position`, `set_size`, `is_visible`, `set_visible`,
+/// `is_dirty`, `clear_dirty`, `classes`, `state` and `set_state` to it, so
+/// only `Component` itself is written by hand:
+///
+/// ```ignore
+/// use vibeui::components::{BaseComponent, Component};
+///
+/// #[derive(Component)]
+/// struct Clock {
+///     #[base]
+///     base: BaseComponent,
+///     time: String,
+/// }
+///
+/// impl Component for Clock {
+///     // render, handle_event and update
+/// }
+/// ```
+#[proc_macro_derive(Component, attributes(base))]
+pub fn derive_component(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_component(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Generate the `ComponentBase` impl for a struct
+fn expand_component(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let base = base_field(&input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::vibeui::components::ComponentBase for #name #ty_generics #where_clause {
+            fn bounds(&self) -> (u16, u16, u16, u16) {
+                let (x, y) = self.#base.position();
+                let (width, height) = self.#base.size();
+                (x, y, width, height)
+            }
+
+            fn set_position(&mut self, x: u16, y: u16) {
+                self.#base.set_position(x, y);
+            }
+
+            fn set_size(&mut self, width: u16, height: u16) {
+                self.#base.set_size(width, height);
+            }
+
+            fn is_visible(&self) -> bool {
+                self.#base.is_visible()
+            }
+
+            fn set_visible(&mut self, visible: bool) {
+                self.#base.set_visible(visible);
+            }
+
+            fn id(&self) -> &str {
+                self.#base.id()
+            }
+
+            fn is_dirty(&self) -> bool {
+                self.#base.is_dirty()
+            }
+
+            fn clear_dirty(&mut self) {
+                self.#base.clear_dirty();
+            }
+
+            fn classes(&self) -> &[::std::string::String] {
+                self.#base.classes()
+            }
+
+            fn state(&self) -> ::vibeui::components::InteractionState {
+                self.#base.state()
+            }
+
+            fn set_state(&mut self, state: ::vibeui::components::InteractionState) {
+                self.#base.set_state(state);
+            }
+        }
+    })
+}
+
+/// Find the single field marked `#[base]`
+fn base_field(input: &DeriveInput) -> syn::Result<Member> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "#[derive(Component)] is only supported on structs",
+            ))
+        }
+    };
+
+    let mut marked = fields.iter().enumerate().filter(|(_, field)| {
+        field.attrs.iter().any(|attr| attr.path().is_ident("base"))
+    });
+
+    let (index, field) = marked.next().ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "#[derive(Component)] needs a `BaseComponent` field marked #[base]",
+        )
+    })?;
+    if let Some((_, duplicate)) = marked.next() {
+        return Err(Error::new_spanned(duplicate, "only one field can be marked #[base]"));
+    }
+
+    Ok(match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(index.into()),
+    })
+}